@@ -6,7 +6,13 @@ import {
   MethodOptions,
   Spec as ContractSpec,
 } from "@stellar/stellar-sdk/contract";
-import type { u32, u64, i128, Option } from "@stellar/stellar-sdk/contract";
+import type {
+  u32,
+  u64,
+  i128,
+  Option,
+  Result,
+} from "@stellar/stellar-sdk/contract";
 import { CONFIG } from ".";
 export * from "@stellar/stellar-sdk";
 export * as contract from "@stellar/stellar-sdk/contract";
@@ -23,20 +29,139 @@ export const networks = {
   },
 } as const;
 
-export enum InvestmentStatus {
-  BuyingPhase = 0,
-  BeforeFirstRelease = 1,
-  Release1 = 2,
-  Release2 = 3,
-  Release3 = 4,
-  Release4 = 5,
-  Release5 = 6,
-  Release6 = 7,
-  Release7 = 8,
-  Release8 = 9,
-  Release9 = 10,
-  Release10 = 11,
-  Ended = 12,
+/**
+ * Lifecycle of a Minah offering.
+ * `Distributing(stage)` carries the index of the next release stage, so `Distributing(0)` is the
+ * period between `start_chronometer` and the first release, and the contract moves to `Ended`
+ * once the last stage of the stored schedule has been released.
+ * `Cancelled` is reached when the soft cap is not met by the refund deadline.
+ */
+export type InvestmentStatus =
+  | { tag: "BuyingPhase"; values: void }
+  | { tag: "Distributing"; values: readonly [u32] }
+  | { tag: "Ended"; values: void }
+  | { tag: "Cancelled"; values: void };
+
+/**
+ * Progress of a stage released page by page with `release_distribution_batch`.
+ * Stored while the stage is in progress and removed once its last page completes.
+ */
+export interface DistributionProgress {
+  distributed_amount: i128;
+  distributed_nfts: i128;
+  investors_count: u32;
+  next_investor: u32;
+  stage: u32;
+}
+
+/**
+ * Entry points that can be paused one by one with `pause_function`.
+ * Pausing the whole contract with `pause` blocks all of them.
+ */
+export type PausableFunction =
+  | { tag: "Mint"; values: void }
+  | { tag: "BuyTokens"; values: void }
+  | { tag: "SellTokens"; values: void }
+  | { tag: "ReleaseDistribution"; values: void }
+  | { tag: "Transfer"; values: void }
+  | { tag: "BuyFromInventory"; values: void };
+
+/**
+ * Addresses and settings that can only be changed through the timelock.
+ * The stablecoin is frozen once the chronometer starts, the price and release schedule
+ * as soon as the first NFT is sold.
+ */
+export type ConfigParameter =
+  | { tag: "StableCoin"; values: void }
+  | { tag: "Receiver"; values: void }
+  | { tag: "Payer"; values: void }
+  | { tag: "ChangeDelay"; values: void }
+  | { tag: "Price"; values: void }
+  | { tag: "ReleaseSchedule"; values: void }
+  | { tag: "TradingFee"; values: void }
+  | { tag: "FeeRecipient"; values: void }
+  | { tag: "AccruedRoiPricing"; values: void }
+  | { tag: "LockUpPeriod"; values: void };
+
+/**
+ * A change proposed with `propose_change`, carrying the new value of its parameter.
+ */
+export type ConfigChange =
+  | { tag: "StableCoin"; values: readonly [string] }
+  | { tag: "Receiver"; values: readonly [string] }
+  | { tag: "Payer"; values: readonly [string] }
+  | { tag: "ChangeDelay"; values: readonly [u64] }
+  | { tag: "Price"; values: readonly [i128] }
+  | { tag: "ReleaseSchedule"; values: readonly [Array<u64>, Array<i128>] }
+  | { tag: "TradingFee"; values: readonly [u32] }
+  | { tag: "FeeRecipient"; values: readonly [Option<string>] }
+  | { tag: "AccruedRoiPricing"; values: readonly [boolean] }
+  | { tag: "LockUpPeriod"; values: readonly [u64] };
+
+/**
+ * A proposed change waiting for its timelock to elapse.
+ */
+export interface PendingChange {
+  change: ConfigChange;
+  executable_at: u64;
+}
+
+/**
+ * Investor tiers, each with its own maximum holding (`MaxNFTsPerInvestor` until the admin sets one).
+ */
+export type InvestorTier =
+  | { tag: "Retail"; values: void }
+  | { tag: "Accredited"; values: void }
+  | { tag: "Institutional"; values: void };
+
+/**
+ * KYC record of an investor. Investors without one are `Retail` and never expire.
+ */
+export interface InvestorProfile {
+  kyc_expires_at: u64;
+  tier: InvestorTier;
+}
+
+/**
+ * Tokens listed by a holder at their own price, in stablecoin units per NFT.
+ * They stay in the seller's account, locked until sold, or the listing is cancelled.
+ */
+export interface Listing {
+  expiry: u64;
+  seller: string;
+  token_ids: Array<u32>;
+  unit_price: i128;
+}
+
+/**
+ * Stablecoin escrowed by a buyer for `quantity` tokens at `unit_price`, any tokens when
+ * `token_ids` is empty, only those otherwise. `fee` is the trading fee escrowed with it.
+ */
+export interface Offer {
+  buyer: string;
+  expiry: u64;
+  fee: i128;
+  quantity: u32;
+  token_ids: Array<u32>;
+  unit_price: i128;
+}
+
+/**
+ * Terms on which investors buy the unsold NFTs minted to the owner by `start_chronometer`.
+ * `unit_price` is in stablecoin units per NFT, lowered by the ROI already paid per NFT when
+ * `deduct_paid_roi` is set.
+ */
+export interface ContinuousOffering {
+  deduct_paid_roi: boolean;
+  unit_price: i128;
+}
+
+/**
+ * Consecutive token ids `start..end` held by an account, as kept by the token index.
+ */
+export interface TokenRange {
+  end: u32;
+  start: u32;
 }
 
 export type DataKey =
@@ -46,7 +171,6 @@ export type DataKey =
   | { tag: "AmountToReleaseForCurrentStage"; values: void }
   | { tag: "Receiver"; values: void }
   | { tag: "Payer"; values: void }
-  | { tag: "InvestorsArray"; values: void }
   | { tag: "CountdownStart"; values: void }
   | { tag: "State"; values: void }
   | { tag: "Investor"; values: readonly [string] }
@@ -57,7 +181,181 @@ export type DataKey =
   | { tag: "MinNFTsToMint"; values: void }
   | { tag: "MaxNFTsPerInvestor"; values: void }
   | { tag: "TotalSupply"; values: void }
-  | { tag: "NFTBuyingPhaseSupply"; values: void };
+  | { tag: "NFTBuyingPhaseSupply"; values: void }
+  | { tag: "SoftCap"; values: void }
+  | { tag: "RefundDeadline"; values: void }
+  | { tag: "SoftCapReached"; values: void }
+  | { tag: "EscrowedAmount"; values: void }
+  | { tag: "PaidAmount"; values: readonly [string] }
+  | { tag: "SaleStart"; values: void }
+  | { tag: "SaleEnd"; values: void }
+  | { tag: "RedemptionFunded"; values: void }
+  | { tag: "RedeemedSupply"; values: void }
+  | { tag: "RedeemedAmount"; values: readonly [string] }
+  | { tag: "ClaimableAmount"; values: readonly [string] }
+  | { tag: "StageAmountPerNFT"; values: readonly [u32] }
+  | { tag: "DistributionProgress"; values: void }
+  | { tag: "BalanceCheckpoints"; values: readonly [string] }
+  | { tag: "StageRemainder"; values: readonly [u32] }
+  | { tag: "DustBalance"; values: void }
+  | { tag: "RolledDust"; values: void }
+  | { tag: "InvestorsCount"; values: void }
+  | { tag: "InvestorAt"; values: readonly [u32] }
+  | { tag: "InvestorIndex"; values: readonly [string] }
+  | { tag: "StorageVersion"; values: void }
+  | { tag: "FunctionPaused"; values: readonly [PausableFunction] }
+  | { tag: "ChangeDelay"; values: void }
+  | { tag: "PendingChange"; values: readonly [ConfigParameter] }
+  | { tag: "InvestorFrozen"; values: readonly [string] }
+  | { tag: "FrozenAmount"; values: readonly [string] }
+  | { tag: "InvestorProfile"; values: readonly [string] }
+  | { tag: "TierCap"; values: readonly [InvestorTier] }
+  | { tag: "InvestorLockUp"; values: readonly [string] }
+  | { tag: "LockedUntil"; values: readonly [string] }
+  | { tag: "LockUpPeriod"; values: void };
+
+/**
+ * Storage keys of the secondary market.
+ * Kept apart from `DataKey`, which is at the size limit of the contract spec.
+ */
+export type MarketplaceDataKey =
+  | { tag: "NextListingId"; values: void }
+  | { tag: "NextOfferId"; values: void }
+  | { tag: "TradingFeeBps"; values: void }
+  | { tag: "FeeRecipient"; values: void }
+  | { tag: "ContinuousOffering"; values: void }
+  | { tag: "AccruedRoiPricing"; values: void }
+  | { tag: "InventoryCursor"; values: void }
+  | { tag: "InventorySupply"; values: void }
+  | { tag: "Listing"; values: readonly [u32] }
+  | { tag: "ListedToken"; values: readonly [u32] }
+  | { tag: "Offer"; values: readonly [u32] }
+  | { tag: "OwnedTokens"; values: readonly [string] };
+
+/**
+ * Errors returned by the Minah contract.
+ * Codes are part of the contract interface: never renumber a variant, only append new ones.
+ * The contract spec allows at most 50 variants, prefer reusing a code when a new check fits an existing one.
+ */
+export const MinahError = {
+  1: { message: "NotInitialized" },
+  2: { message: "InvalidReleaseSchedule" },
+  3: { message: "InvestmentNotInBuyingPhase" },
+  4: { message: "ConfigLockedAfterFirstMint" },
+  5: { message: "SoftCapExceedsTotalSupply" },
+  6: { message: "InvalidRefundDeadline" },
+  7: { message: "InvalidSaleWindow" },
+  8: { message: "InvestorAlreadyExists" },
+  9: { message: "UserNotAnInvestor" },
+  10: { message: "SaleNotOpen" },
+  11: { message: "SaleClosed" },
+  12: { message: "MinimumInvestmentNotMet" },
+  13: { message: "MaximumNftsPerInvestorExceeded" },
+  14: { message: "MaximumSupplyExceeded" },
+  15: { message: "InsufficientBalance" },
+  16: { message: "InsufficientAllowance" },
+  17: { message: "ChronometerAlreadyStarted" },
+  18: { message: "OfferingCancelled" },
+  19: { message: "SoftCapNotReached" },
+  20: { message: "SoftCapAlreadyReached" },
+  21: { message: "RefundDeadlineNotReached" },
+  22: { message: "OfferingNotCancelled" },
+  23: { message: "NothingToRefund" },
+  24: { message: "RefundMustBurnAllNfts" },
+  25: { message: "CountdownNotStarted" },
+  26: { message: "InvalidLedgerTime" },
+  27: { message: "DistributionAlreadyEnded" },
+  28: { message: "DistributionNotReadyYet" },
+  29: { message: "DistributionBatchInProgress" },
+  30: { message: "InvalidBatchLimit" },
+  31: { message: "DistributionRemainderMismatch" },
+  32: { message: "NothingToClaim" },
+  33: { message: "NoDust" },
+  34: { message: "InvestmentNotEnded" },
+  35: { message: "RedemptionAlreadyFunded" },
+  36: { message: "RedemptionNotFunded" },
+  37: { message: "OwnerCannotRedeem" },
+  38: { message: "NoTokensToRedeem" },
+  39: { message: "NftTransfersNotAllowedDuringBuyingPhase" },
+  40: { message: "TradingClosedForRedemption" },
+  41: { message: "NotInvestorOrOwner" },
+  42: { message: "InsufficientFromNftBalance" },
+  43: { message: "SpenderNotApprovedForAll" },
+  46: { message: "UnsupportedStorageVersion" },
+  47: { message: "FunctionPaused" },
+  100: { message: "InvestorFrozen" },
+  103: { message: "KycExpired" },
+  105: { message: "LockedUp" },
+  304: { message: "TokenListed" },
+};
+
+/**
+ * Errors of the investor compliance entry points, added once `MinahError` got close to the 50 variants the spec allows.
+ * Every entry point only fails with the error enum it returns. A failure reported by several areas has a variant
+ * in each of their enums under the same code, so a code means the same failure whichever enum reports it.
+ * Codes of the compliance failures start at 100.
+ */
+export const ComplianceError = {
+  1: { message: "NotInitialized" },
+  9: { message: "UserNotAnInvestor" },
+  29: { message: "DistributionBatchInProgress" },
+  100: { message: "InvestorFrozen" },
+  101: { message: "InvestorNotFrozen" },
+  102: { message: "InvestorHasBalance" },
+  103: { message: "KycExpired" },
+  104: { message: "TransferRestricted" },
+  105: { message: "LockedUp" },
+  106: { message: "ReleasePending" },
+};
+
+/**
+ * Errors of the secondary market entry points, following the same rules as `ComplianceError`.
+ * Codes of the marketplace failures start at 300 as 200 to 212 are used by the NFT errors of `stellar-tokens`.
+ */
+export const MarketplaceError = {
+  1: { message: "NotInitialized" },
+  9: { message: "UserNotAnInvestor" },
+  13: { message: "MaximumNftsPerInvestorExceeded" },
+  15: { message: "InsufficientBalance" },
+  16: { message: "InsufficientAllowance" },
+  18: { message: "OfferingCancelled" },
+  29: { message: "DistributionBatchInProgress" },
+  39: { message: "NftTransfersNotAllowedDuringBuyingPhase" },
+  40: { message: "TradingClosedForRedemption" },
+  41: { message: "NotInvestorOrOwner" },
+  42: { message: "InsufficientFromNftBalance" },
+  43: { message: "SpenderNotApprovedForAll" },
+  47: { message: "FunctionPaused" },
+  100: { message: "InvestorFrozen" },
+  103: { message: "KycExpired" },
+  105: { message: "LockedUp" },
+  201: { message: "IncorrectOwner" },
+  300: { message: "ListingNotFound" },
+  301: { message: "ListingExpired" },
+  302: { message: "InvalidListing" },
+  303: { message: "InvalidQuantity" },
+  304: { message: "TokenListed" },
+  306: { message: "OfferNotFound" },
+  307: { message: "OfferExpired" },
+  308: { message: "InvalidOffer" },
+  309: { message: "ContinuousOfferingClosed" },
+  310: { message: "PriceAboveLimit" },
+  311: { message: "InvalidPrice" },
+};
+
+/**
+ * Errors of the timelocked configuration changes, split from `MinahError` to make room in it.
+ * Failures shared with another enum keep their code there, as in `ComplianceError`.
+ */
+export const ConfigError = {
+  1: { message: "NotInitialized" },
+  2: { message: "InvalidReleaseSchedule" },
+  3: { message: "InvestmentNotInBuyingPhase" },
+  4: { message: "ConfigLockedAfterFirstMint" },
+  48: { message: "NoPendingChange" },
+  49: { message: "ChangeNotReady" },
+  305: { message: "TradingFeeTooHigh" },
+};
 
 /**
  * Storage key for enumeration of accounts per role.
@@ -361,11 +659,15 @@ export const NonFungibleTokenError = {
 
 export interface Client {
   /**
-   * Construct and simulate a set_stablecoin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Sets a new stablecoin address. Only the contract owner can call this function.
+   * Construct and simulate a propose_change transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Proposes a new value for a `ConfigParameter`. Only the admin can call this function.
+   * The change can be executed once the current change delay has elapsed, giving investors time to
+   * react. Proposing again for the same parameter replaces the pending change and restarts the delay.
+   * # Arguments
+   * * `change` : the parameter to change and its new value
    */
-  set_stablecoin: (
-    { stablecoin }: { stablecoin: string },
+  propose_change: (
+    { change }: { change: ConfigChange },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -382,17 +684,14 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<u64>>>;
 
   /**
-   * Construct and simulate a create_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Creates a new investor.
-   * Function called from the backend when a user creates a profile on the Minah platform
-   * # Arguments
-   * * `newInvestor` : the fireblocks address generated for the new user. To store in the backend.
+   * Construct and simulate a execute_change transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Applies the pending change of a parameter once its delay has elapsed. Can be called by anyone.
    */
-  create_investor: (
-    { new_investor }: { new_investor: string },
+  execute_change: (
+    { parameter }: { parameter: ConfigParameter },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -409,14 +708,14 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
 
   /**
-   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Mints a new NFT to the specified address.
+   * Construct and simulate a cancel_change transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Drops the pending change of a parameter. Only the admin can call this function.
    */
-  mint: (
-    { user, amount }: { user: string; amount: u32 },
+  cancel_change: (
+    { parameter }: { parameter: ConfigParameter },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -433,38 +732,49 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
 
   /**
-   * Construct and simulate a start_chronometer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start the chronometer for ROI distribution
+   * Construct and simulate a set_soft_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets the soft cap of the offering. Only the admin can call this function.
+   * Mint proceeds are escrowed in the contract until `soft_cap` NFTs are sold. If the soft cap is not
+   * reached by `refund_deadline` the offering can be cancelled and investors refunded.
+   * Must be called before the first mint.
+   * # Arguments
+   * * `soft_cap` : minimum number of NFTs to sell during the buying phase (0 disables the soft cap)
+   * * `refund_deadline` : ledger timestamp after which the offering can be cancelled if the soft cap is not reached
    */
-  start_chronometer: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  set_soft_cap: (
+    { soft_cap, refund_deadline }: { soft_cap: u32; refund_deadline: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
 
   /**
-   * Construct and simulate a calculate_amount_to_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Calculate amount to release for a given percentage
-   * Function to know how much to approve() on the STABLECOIN smart contract before releasing the amount to all investors.
-   * Arguments:
-   * * `percentage`: the percentage of ROI to be released for the current stage.(Scaled by 10_000_000 to handle decimal percentages)
+   * Construct and simulate a set_sale_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets the buying phase window. Only the admin can call this function.
+   * `mint` is only allowed between `sale_start` (inclusive) and `sale_end` (exclusive), and anyone can start
+   * the chronometer once `sale_end` has passed. Must be called before the first mint.
+   * # Arguments
+   * * `sale_start` : ledger timestamp at which the sale opens
+   * * `sale_end` : ledger timestamp at which the sale closes
    */
-  calculate_amount_to_release: (
-    { percent }: { percent: i128 },
+  set_sale_window: (
+    { sale_start, sale_end }: { sale_start: u64; sale_end: u64 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -481,36 +791,58 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<i128>>;
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
 
   /**
-   * Construct and simulate a release_distribution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Releases the distribution for the current stage.
-   * This function needs to be called by the owner at the end of every distribution period/stage to trigger the current release and next stage.
+   * Construct and simulate a create_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Creates a new investor.
+   * Function called from the backend when a user creates a profile on the Minah platform
+   * # Arguments
+   * * `caller` : an account holding the compliance role
+   * * `newInvestor` : the fireblocks address generated for the new user. To store in the backend.
    */
-  release_distribution: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  create_investor: (
+    { caller, new_investor }: { caller: string; new_investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
 
   /**
-   * Construct and simulate a is_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Check if an address is an investor
+   * Construct and simulate a set_investor_kyc transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Records the tier and KYC expiry of an investor, also used to renew an expired KYC.
+   * Once `kyc_expires_at` has passed the investor can no longer mint or receive NFTs on the marketplace.
+   * # Arguments
+   * * `caller` : an account holding the compliance role
+   * * `investor` : the investor whose KYC was checked
+   * * `tier` : the tier setting the maximum holding of the investor
+   * * `kyc_expires_at` : ledger timestamp from which the KYC has to be renewed
    */
-  is_investor: (
-    { investor }: { investor: string },
+  set_investor_kyc: (
+    {
+      caller,
+      investor,
+      tier,
+      kyc_expires_at,
+    }: {
+      caller: string;
+      investor: string;
+      tier: InvestorTier;
+      kyc_expires_at: u64;
+    },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -527,97 +859,194 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<boolean>>;
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
 
   /**
-   * Construct and simulate a get_investors_array_length transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get investors array length
+   * Construct and simulate a set_investor_lock_up transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets the lock-up of an investor, applied from each of their mints on top of the offering lock-up.
+   * Their NFTs cannot be sold or transferred until `period` seconds after their latest mint.
+   * # Arguments
+   * * `caller` : an account holding the compliance role
+   * * `investor` : the investor to lock up
+   * * `period` : lock-up in seconds, `0` to stop locking up later mints
    */
-  get_investors_array_length: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  set_investor_lock_up: (
+    {
+      caller,
+      investor,
+      period,
+    }: { caller: string; investor: string; period: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
 
   /**
-   * Construct and simulate a get_stablecoin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Returns the address of the stablecoin used for investments.
+   * Construct and simulate a set_tier_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets the maximum number of NFTs an investor of a tier can hold. Only the admin can call this function.
    */
-  get_stablecoin: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  set_tier_cap: (
+    { tier, max_nfts }: { tier: InvestorTier; max_nfts: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a get_receiver transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Returns the address of the receiver.
+   * Construct and simulate a freeze_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Freezes an investor, for instance for AML reasons.
+   * A frozen investor cannot mint, trade, claim, refund or redeem, and the ROI released to them
+   * is held in escrow by the contract until they are unfrozen.
+   * # Arguments
+   * * `caller` : an account holding the compliance role
+   * * `investor` : the investor to freeze
    */
-  get_receiver: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  freeze_investor: (
+    { caller, investor }: { caller: string; investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-  /**
-   * Construct and simulate a get_begin_date transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Returns the start time of the chronometer.
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a unfreeze_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Unfreezes an investor and releases the ROI held in escrow while they were frozen to their claimable amount.
+   * Also works for an investor removed while frozen.
+   * # Arguments
+   * * `caller` : an account holding the compliance role
+   * * `investor` : the investor to unfreeze
    */
-  get_begin_date: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  unfreeze_investor: (
+    { caller, investor }: { caller: string; investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<i128>>>;
 
   /**
-   * Construct and simulate a is_chronometer_started transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Returns whether the chronometer has started.
+   * Construct and simulate a remove_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Removes an investor from the registry so they no longer take part in distributions.
+   * The investor must not hold any NFT once `token_ids` have been moved to `custodian`.
+   * Their claimed, claimable and escrowed amounts are kept: a frozen investor stays frozen.
+   * Fails with `ComplianceError::ReleasePending` while a stage is due but not released yet.
+   * # Arguments
+   * * `caller` : an account holding the compliance role
+   * * `investor` : the investor to remove
+   * * `custodian` : a registered investor receiving `token_ids`, required when the investor still holds NFTs
+   * * `token_ids` : the tokens of the investor to move to the custodian
    */
-  is_chronometer_started: (options?: {
+  remove_investor: (
+    {
+      caller,
+      investor,
+      custodian,
+      token_ids,
+    }: {
+      caller: string;
+      investor: string;
+      custodian: Option<string>;
+      token_ids: Array<u32>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mints a new NFT to the specified address.
+   */
+  mint: (
+    { user, amount }: { user: string; amount: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a start_chronometer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start the chronometer for ROI distribution
+   * The admin can start it at any time, anyone else can start it once the sale window is closed or the supply is sold out.
+   */
+  start_chronometer: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -632,13 +1061,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>;
+  }) => Promise<AssembledTransaction<Result<readonly []>>>;
 
   /**
-   * Construct and simulate a get_payer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Returns the address of the payer.
+   * Construct and simulate a cancel_offering transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancels the offering when the soft cap was not reached by the refund deadline.
+   * Anyone can call this function once the deadline has passed, investors then get their stablecoin back through `refund`.
    */
-  get_payer: (options?: {
+  cancel_offering: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -653,13 +1083,127 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>;
+  }) => Promise<AssembledTransaction<Result<readonly []>>>;
 
   /**
-   * Construct and simulate a see_claimed_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get claimed amount for an investor
+   * Construct and simulate a refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Refunds an investor of a cancelled offering.
+   * The investor gets back everything paid during the buying phase and all their NFTs are burned.
+   * # Arguments
+   * * `investor` : the investor to refund
+   * * `token_ids` : all the token ids owned by the investor
    */
-  see_claimed_amount: (
+  refund: (
+    { investor, token_ids }: { investor: string; token_ids: Array<u32> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a calculate_amount_to_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Calculate amount to release for a given percentage
+   * Function to know how much to approve() on the STABLECOIN smart contract before releasing the amount to all investors.
+   * Arguments:
+   * * `percentage`: the percentage of ROI to be released for the current stage.(Scaled by 10_000_000 to handle decimal percentages)
+   */
+  calculate_amount_to_release: (
+    { percent }: { percent: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+
+  /**
+   * Construct and simulate a release_distribution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Releases the distribution for the current stage.
+   * This function needs to be called by the treasury at the end of every distribution period/stage to trigger the current release and next stage.
+   */
+  release_distribution: (
+    { caller }: { caller: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a release_distribution_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Releases the next ready stage for at most `limit` investors.
+   * Progress is persisted between calls so large investor sets can be processed over several transactions,
+   * the stage is funded and the state advanced only when the last page completes.
+   * Returns true once the stage has been fully released.
+   * # Arguments
+   * * `caller` : an account holding the treasury role
+   * * `limit` : maximum number of investors to process in this call
+   */
+  release_distribution_batch: (
+    { caller, limit }: { caller: string; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<boolean>>>;
+
+  /**
+   * Construct and simulate a claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claims all the ROI released to an investor across completed stages.
+   * Sends the whole claimable amount to the investor and adds it to their claimed amount.
+   * Fails while a stage is released page by page with `release_distribution_batch`.
+   * # Arguments
+   * * `investor` : the investor claiming their ROI
+   */
+  claim: (
     { investor }: { investor: string },
     options?: {
       /**
@@ -677,160 +1221,2122 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<i128>>;
-
-  /**
-   * Construct and simulate a get_current_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get current supply
-   */
-  get_current_supply: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>;
-
-  /**
-   * Construct and simulate a get_current_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get current state
-   */
-  get_current_state: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<InvestmentStatus>>;
-
-  /**
-   * Construct and simulate a get_nft_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get NFT PRICE
-   */
-  get_nft_price: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>;
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+
+  /**
+   * Construct and simulate a sweep_dust transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sends the distribution dust (rounding remainders kept by the contract) to an address.
+   * # Arguments
+   * * `caller` : an account holding the treasury role
+   * * `to` : the address receiving the dust, usually the payer
+   */
+  sweep_dust: (
+    { caller, to }: { caller: string; to: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a roll_dust transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rolls the distribution dust into the next stage.
+   * The dust is used to fund the next released stage, so the payer is charged that much less.
+   * Only an account holding the treasury role can call this function.
+   */
+  roll_dust: (
+    { caller }: { caller: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a fund_redemption transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Funds the principal redemption at the end of the term.
+   * Pulls `price * STABLECOIN_SCALE` per NFT held by investors from the payer into the contract so holders can `redeem` them.
+   * Only an account holding the treasury role can call this function.
+   * NOTE: The payer must have approved the contract to spend the stablecoin on their behalf
+   */
+  fund_redemption: (
+    { caller }: { caller: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a redeem transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Redeems NFTs for their principal once the term has ended and the redemption has been funded.
+   * The NFTs are burned and the holder receives `price * STABLECOIN_SCALE` per NFT.
+   * # Arguments
+   * * `holder` : the NFT holder redeeming the tokens
+   * * `token_ids` : the token ids to redeem
+   */
+  redeem: (
+    { holder, token_ids }: { holder: string; token_ids: Array<u32> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a pause_function transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pauses a single entry point. Only an account holding the pauser role can call this function.
+   * Use `pause` to stop every pausable entry point at once.
+   */
+  pause_function: (
+    { caller, function }: { caller: string; function: PausableFunction },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a unpause_function transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Unpauses an entry point paused with `pause_function`. Only an account holding the pauser role can call this function.
+   * The entry point stays blocked while the whole contract is paused.
+   */
+  unpause_function: (
+    { caller, function }: { caller: string; function: PausableFunction },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replaces the contract code, keeping its address and storage. Only the contract owner can call this function.
+   * Call `migrate` afterwards when the new release changes the storage layout.
+   * # Arguments
+   * * `new_wasm_hash` : hash of the new WASM, already installed on the network
+   */
+  upgrade: (
+    { new_wasm_hash }: { new_wasm_hash: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Brings the storage layout up to the version of the running code. Only the contract owner can call this function.
+   * Releases that change the layout bump `STORAGE_VERSION` and add a step here, so a deployment can skip releases.
+   * Calling it once up to date does nothing. Returns the storage version.
+   */
+  migrate: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_investor_profile transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Tier and KYC expiry of an investor
+   */
+  get_investor_profile: (
+    { investor }: { investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<InvestorProfile>>;
+
+  /**
+   * Construct and simulate a get_investor_lock_up transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lock-up applied from each mint of an investor, in seconds
+   */
+  get_investor_lock_up: (
+    { investor }: { investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a get_lock_up_period transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Seconds after the begin date during which NFTs cannot change hands
+   */
+  get_lock_up_period: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a transferable_after transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Timestamp from which an account can sell or transfer its NFTs: the end of the offering lock-up,
+   * or of its own lock-up when later. `u64::MAX` until the chronometer starts.
+   */
+  transferable_after: (
+    { account }: { account: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a get_tier_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Maximum number of NFTs an investor of a tier can hold
+   */
+  get_tier_cap: (
+    { tier }: { tier: InvestorTier },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a is_function_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether an entry point is blocked, either on its own or because the whole contract is paused
+   */
+  is_function_paused: (
+    { function }: { function: PausableFunction },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a get_change_delay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Seconds between proposing a change and being able to execute it
+   */
+  get_change_delay: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a get_pending_change transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The change waiting to be executed for a parameter, if any
+   */
+  get_pending_change: (
+    { parameter }: { parameter: ConfigParameter },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Option<PendingChange>>>;
+
+  /**
+   * Construct and simulate a get_locked_parameters transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Parameters that can no longer be changed
+   */
+  get_locked_parameters: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<ConfigParameter>>>;
+
+  /**
+   * Construct and simulate a get_storage_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Version of the storage layout, deployments made before it was recorded are version 1
+   */
+  get_storage_version: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a is_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if an address is an investor
+   */
+  is_investor: (
+    { investor }: { investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a is_investor_frozen transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if an investor is frozen
+   */
+  is_investor_frozen: (
+    { investor }: { investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a get_frozen_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * ROI released to a frozen investor and held in escrow until they are unfrozen
+   */
+  get_frozen_amount: (
+    { investor }: { investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_investors_array_length transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get investors array length
+   */
+  get_investors_array_length: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_stablecoin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns the address of the stablecoin used for investments.
+   */
+  get_stablecoin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>;
+
+  /**
+   * Construct and simulate a get_receiver transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns the address of the receiver.
+   */
+  get_receiver: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>;
+
+  /**
+   * Construct and simulate a get_begin_date transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns the start time of the chronometer.
+   */
+  get_begin_date: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a is_chronometer_started transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns whether the chronometer has started.
+   */
+  is_chronometer_started: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<boolean>>>;
+
+  /**
+   * Construct and simulate a get_payer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns the address of the payer.
+   */
+  get_payer: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>;
+
+  /**
+   * Construct and simulate a see_claimable_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get ROI released to an investor and not claimed yet
+   */
+  see_claimable_amount: (
+    { investor }: { investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_stage_remainder transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the rounding remainder recorded for a released stage
+   */
+  get_stage_remainder: (
+    { stage }: { stage: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_dust_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the distribution dust held by the contract that can be swept or rolled
+   */
+  get_dust_balance: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_rolled_dust transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the dust rolled into the next stage funding
+   */
+  get_rolled_dust: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_stage_amount_per_nft transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ROI amount per NFT (rounded down) recorded for a released stage
+   */
+  get_stage_amount_per_nft: (
+    { stage }: { stage: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a balance_at_stage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the balance of an investor at the due date of a stage.
+   * For a stage not due yet this is the current balance.
+   */
+  balance_at_stage: (
+    { investor, stage }: { investor: string; stage: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_distribution_progress transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the progress of the stage being released page by page, if any
+   */
+  get_distribution_progress: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<DistributionProgress>>>;
+
+  /**
+   * Construct and simulate a see_claimed_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get claimed amount for an investor (ROI already sent to the investor)
+   */
+  see_claimed_amount: (
+    { investor }: { investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a see_redeemed_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get redeemed principal amount for an investor
+   */
+  see_redeemed_amount: (
+    { investor }: { investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a is_redemption_funded transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns whether the principal redemption has been funded by the payer
+   */
+  is_redemption_funded: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a get_redeemed_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of NFTs redeemed and burned
+   */
+  get_redeemed_supply: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_current_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get current supply
+   */
+  get_current_supply: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_current_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get current state
+   */
+  get_current_state: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<InvestmentStatus>>>;
+
+  /**
+   * Construct and simulate a get_nft_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get NFT PRICE
+   */
+  get_nft_price: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>;
+
+  /**
+   * Construct and simulate a get_total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get total supply
+   */
+  get_total_supply: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_min_nfts_to_mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get minimum nfts to mint
+   */
+  get_min_nfts_to_mint: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_max_nfts_per_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get maximum nfts per investor
+   */
+  get_max_nfts_per_investor: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_nft_buying_phase_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get nft buying phase supply
+   */
+  get_nft_buying_phase_supply: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_distribution_intervals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get distribution intervals array
+   */
+  get_distribution_intervals: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<u64>>>>;
+
+  /**
+   * Construct and simulate a get_roi_percentages transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get ROI percentages array
+   */
+  get_roi_percentages: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<i128>>>>;
+
+  /**
+   * Construct and simulate a get_stage_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of release stages in the distribution schedule
+   */
+  get_stage_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_buying_phase_nft_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get buying phase nft supply
+   */
+  get_buying_phase_nft_supply: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_soft_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the soft cap (minimum number of NFTs to sell during the buying phase)
+   */
+  get_soft_cap: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_refund_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the refund deadline
+   */
+  get_refund_deadline: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a is_soft_cap_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns whether the soft cap has been reached
+   */
+  is_soft_cap_reached: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<boolean>>>;
+
+  /**
+   * Construct and simulate a get_sale_start transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the timestamp at which the sale opens
+   */
+  get_sale_start: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a get_sale_end transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the timestamp at which the sale closes
+   */
+  get_sale_end: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a get_escrowed_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the amount of stablecoin escrowed in the contract until the soft cap is reached
+   */
+  get_escrowed_amount: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_paid_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the amount of stablecoin paid by an investor during the buying phase
+   */
+  get_paid_amount: (
+    { investor }: { investor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_trading_fee_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Fee charged to buyers on secondary trades, in basis points of the trade price
+   */
+  get_trading_fee_bps: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_fee_recipient transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Address collecting the trading fees, the receiver unless another one was set
+   */
+  get_fee_recipient: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>;
+
+  /**
+   * Construct and simulate a get_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * A listing still open for sale, if any
+   */
+  get_listing: (
+    { listing_id }: { listing_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Option<Listing>>>;
+
+  /**
+   * Construct and simulate a get_token_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The listing locking a token, if any
+   */
+  get_token_listing: (
+    { token_id }: { token_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Option<u32>>>;
+
+  /**
+   * Construct and simulate a get_token_ranges transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Tokens held by an account as sorted ranges of consecutive ids
+   */
+  get_token_ranges: (
+    { account }: { account: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Array<TokenRange>>>;
+
+  /**
+   * Construct and simulate a get_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Open offer with the stablecoin still escrowed for it
+   */
+  get_offer: (
+    { offer_id }: { offer_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Option<Offer>>>;
+
+  /**
+   * Construct and simulate a get_continuous_offering transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Terms of the continuous offering, `None` while it is closed
+   */
+  get_continuous_offering: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<ContinuousOffering>>>;
+
+  /**
+   * Construct and simulate a get_inventory_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Current price of one NFT of the owner inventory, in stablecoin units
+   */
+  get_inventory_price: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>;
+
+  /**
+   * Construct and simulate a is_accrued_roi_pricing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether `buy_tokens` and `sell_tokens` settle at `quote_price` instead of the original price
+   */
+  is_accrued_roi_pricing: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a quote_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Fair value of `token_count` NFTs in stablecoin units: the principal plus the ROI accrued,
+   * pro rata of the elapsed time, in the stage whose due date has not passed yet.
+   * A stage past its due date is paid from the balances at that date, so it stays with the seller,
+   * while the buyer receives the whole accruing stage and pays the seller for their share.
+   */
+  quote_price: (
+    { token_count }: { token_count: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+
+  /**
+   * Construct and simulate a get_inventory_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Number of unsold NFTs still held by the owner, apart from what was sold during the buying phase
+   */
+  get_inventory_supply: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a buy_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  buy_tokens: (
+    {
+      from,
+      to,
+      token_ids,
+    }: { from: string; to: string; token_ids: Array<u32> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a buy_quantity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Buys `quantity` tokens of `from` without naming them, on the terms of `buy_tokens`.
+   * The tokens are taken in id order from the token index of the seller, skipping listed ones.
+   * Returns the ids of the tokens bought.
+   * # Arguments
+   * * `from` : the seller, must have approved the contract for all its tokens
+   * * `to` : the buyer, must have approved the contract for the total price and the fee
+   * * `quantity` : number of tokens to buy
+   * * `max_unit_price` : highest price per NFT the buyer accepts
+   */
+  buy_quantity: (
+    {
+      from,
+      to,
+      quantity,
+      max_unit_price,
+    }: { from: string; to: string; quantity: u32; max_unit_price: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<Array<u32>>>>;
+
+  /**
+   * Construct and simulate a sell_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  sell_tokens: (
+    {
+      from,
+      to,
+      token_ids,
+    }: { from: string; to: string; token_ids: Array<u32> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a list transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lists tokens for sale at a price chosen by the seller.
+   * The tokens stay with the seller, and keep earning ROI, but are locked until sold or the listing is cancelled.
+   * Returns the id of the listing.
+   * # Arguments
+   * * `seller` : an investor or the owner holding the tokens
+   * * `token_ids` : the tokens to sell
+   * * `unit_price` : price of one NFT in stablecoin units (with the 7 decimals)
+   * * `expiry` : ledger timestamp from which the listing can no longer be filled
+   */
+  list: (
+    {
+      seller,
+      token_ids,
+      unit_price,
+      expiry,
+    }: { seller: string; token_ids: Array<u32>; unit_price: i128; expiry: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a cancel_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Removes a listing and unlocks its remaining tokens, also once it has expired.
+   * Only the seller can call this function.
+   */
+  cancel_listing: (
+    { listing_id }: { listing_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a fill_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Buys `quantity` tokens of a listing at its unit price, paid straight to the seller.
+   * The listing is removed once all its tokens are sold.
+   * # Arguments
+   * * `buyer` : an investor or the owner, must have approved the contract for the total price
+   * * `listing_id` : the listing to buy from
+   * * `quantity` : number of tokens to buy, taken in the order they were listed
+   */
+  fill_listing: (
+    {
+      buyer,
+      listing_id,
+      quantity,
+    }: { buyer: string; listing_id: u32; quantity: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a make_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Escrows the price of `quantity` tokens, plus the trading fee, until a seller accepts the offer
+   * or the buyer reclaims it. Returns the id of the offer.
+   * # Arguments
+   * * `buyer` : an investor or the owner, must have approved the contract for the escrowed amount
+   * * `token_ids` : the tokens wanted, or empty to take any tokens
+   * * `quantity` : number of tokens wanted, must match `token_ids` when they are given
+   * * `unit_price` : price of one NFT in stablecoin units (with the 7 decimals)
+   * * `expiry` : ledger timestamp from which the offer can no longer be accepted
+   */
+  make_offer: (
+    {
+      buyer,
+      token_ids,
+      quantity,
+      unit_price,
+      expiry,
+    }: {
+      buyer: string;
+      token_ids: Array<u32>;
+      quantity: u32;
+      unit_price: i128;
+      expiry: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a accept_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sells tokens to an offer before its expiry, the seller is paid from the escrow.
+   * An offer can be filled by several sellers, it is removed once its whole quantity is sold.
+   * # Arguments
+   * * `seller` : an investor or the owner holding the tokens, no approval is needed
+   * * `offer_id` : the offer to sell to
+   * * `token_ids` : the tokens sold, among those of the offer when it names some
+   */
+  accept_offer: (
+    {
+      seller,
+      offer_id,
+      token_ids,
+    }: { seller: string; offer_id: u32; token_ids: Array<u32> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a reclaim_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Refunds what is left of an offer to its buyer and removes it.
+   * The buyer can withdraw an offer at any time, anyone can return it once it has expired.
+   * Returns the refunded amount.
+   */
+  reclaim_offer: (
+    { offer_id }: { offer_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+
+  /**
+   * Construct and simulate a set_continuous_offering transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Opens, updates or closes (`None`) the sale of the owner inventory to investors.
+   * Can be changed at any time, buyers protect themselves with `max_unit_price`.
+   */
+  set_continuous_offering: (
+    { offering }: { offering: Option<ContinuousOffering> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a buy_from_inventory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Buys `quantity` NFTs of the owner inventory at the continuous offering price, paid to the receiver.
+   * Inventory tokens are sold in order, skipping those the owner listed, which stay in the inventory.
+   * # Arguments
+   * * `buyer` : an investor, must have approved the contract for the total price
+   * * `quantity` : number of NFTs to buy
+   * * `max_unit_price` : highest price per NFT the buyer accepts
+   */
+  buy_from_inventory: (
+    {
+      buyer,
+      quantity,
+      max_unit_price,
+    }: { buyer: string; quantity: u32; max_unit_price: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Result<readonly []>>>;
+
+  /**
+   * Construct and simulate a hello transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  hello: (
+    { to }: { to: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Array<string>>>;
+
+  /**
+   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Peer-to-peer transfer, free of charge, between accounts allowed to trade.
+   * Fails with `ComplianceError::TransferRestricted` when a trading rule blocks it.
+   */
+  transfer: (
+    { from, to, token_id }: { from: string; to: string; token_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  transfer_from: (
+    {
+      spender,
+      from,
+      to,
+      token_id,
+    }: { spender: string; from: string; to: string; token_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  balance: (
+    { account }: { account: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  owner_of: (
+    { token_id }: { token_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approve: (
+    {
+      approver,
+      approved,
+      token_id,
+      live_until_ledger,
+    }: {
+      approver: string;
+      approved: string;
+      token_id: u32;
+      live_until_ledger: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a get_total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get total supply
+   * Construct and simulate a approve_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_total_supply: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  approve_for_all: (
+    {
+      owner,
+      operator,
+      live_until_ledger,
+    }: { owner: string; operator: string; live_until_ledger: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a get_min_nfts_to_mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get minimum nfts to mint
+   * Construct and simulate a get_approved transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_min_nfts_to_mint: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_approved: (
+    { token_id }: { token_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<Option<string>>>;
 
   /**
-   * Construct and simulate a get_max_nfts_per_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get maximum nfts per investor
+   * Construct and simulate a is_approved_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_max_nfts_per_investor: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  is_approved_for_all: (
+    { owner, operator }: { owner: string; operator: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<boolean>>;
 
   /**
-   * Construct and simulate a get_nft_buying_phase_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get nft buying phase supply
+   * Construct and simulate a token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_nft_buying_phase_supply: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  token_uri: (
+    { token_id }: { token_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<string>>;
 
   /**
-   * Construct and simulate a get_distribution_intervals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get distribution intervals array
+   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_distribution_intervals: (options?: {
+  name: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -845,13 +3351,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<u64>>>;
+  }) => Promise<AssembledTransaction<string>>;
 
   /**
-   * Construct and simulate a get_roi_percentages transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get ROI percentages array
+   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_roi_percentages: (options?: {
+  symbol: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -866,13 +3371,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<i128>>>;
+  }) => Promise<AssembledTransaction<string>>;
 
   /**
-   * Construct and simulate a get_buying_phase_nft_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get buying phase nft supply
+   * Construct and simulate a get_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_buying_phase_nft_supply: (options?: {
+  get_owner: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -887,44 +3391,16 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>;
-
-  /**
-   * Construct and simulate a buy_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  buy_tokens: (
-    {
-      from,
-      to,
-      token_ids,
-    }: { from: string; to: string; token_ids: Array<u32> },
-    options?: {
-      /**
-       * The fee to pay for the transaction. Default: BASE_FEE
-       */
-      fee?: number;
-
-      /**
-       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-       */
-      timeoutInSeconds?: number;
-
-      /**
-       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-       */
-      simulate?: boolean;
-    }
-  ) => Promise<AssembledTransaction<null>>;
+  }) => Promise<AssembledTransaction<Option<string>>>;
 
   /**
-   * Construct and simulate a sell_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a transfer_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  sell_tokens: (
+  transfer_ownership: (
     {
-      from,
-      to,
-      token_ids,
-    }: { from: string; to: string; token_ids: Array<u32> },
+      new_owner,
+      live_until_ledger,
+    }: { new_owner: string; live_until_ledger: u32 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -944,58 +3420,50 @@ export interface Client {
   ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a set_receiver transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Sets a new receiver address. Only the contract owner can call this function.
+   * Construct and simulate a accept_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_receiver: (
-    { receiver }: { receiver: string },
-    options?: {
-      /**
-       * The fee to pay for the transaction. Default: BASE_FEE
-       */
-      fee?: number;
+  accept_ownership: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
 
-      /**
-       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-       */
-      timeoutInSeconds?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
 
-      /**
-       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-       */
-      simulate?: boolean;
-    }
-  ) => Promise<AssembledTransaction<null>>;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a set_payer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Sets a new payer address. Only the contract owner can call this function.
+   * Construct and simulate a renounce_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_payer: (
-    { payer }: { payer: string },
-    options?: {
-      /**
-       * The fee to pay for the transaction. Default: BASE_FEE
-       */
-      fee?: number;
+  renounce_ownership: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
 
-      /**
-       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-       */
-      timeoutInSeconds?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
 
-      /**
-       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-       */
-      simulate?: boolean;
-    }
-  ) => Promise<AssembledTransaction<null>>;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a hello transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  hello: (
-    { to }: { to: string },
+  has_role: (
+    { account, role }: { account: string; role: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -1012,13 +3480,13 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<Array<string>>>;
+  ) => Promise<AssembledTransaction<Option<u32>>>;
 
   /**
-   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_role_member_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  transfer: (
-    { _from, _to, _token_id }: { _from: string; _to: string; _token_id: u32 },
+  get_role_member_count: (
+    { role }: { role: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -1035,18 +3503,13 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<u32>>;
 
   /**
-   * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_role_member transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  transfer_from: (
-    {
-      _spender,
-      _from,
-      _to,
-      _token_id,
-    }: { _spender: string; _from: string; _to: string; _token_id: u32 },
+  get_role_member: (
+    { role, index }: { role: string; index: u32 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -1063,13 +3526,13 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<string>>;
 
   /**
-   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_role_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  balance: (
-    { account }: { account: string },
+  get_role_admin: (
+    { role }: { role: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -1086,46 +3549,37 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<u32>>;
+  ) => Promise<AssembledTransaction<Option<string>>>;
 
   /**
-   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  owner_of: (
-    { token_id }: { token_id: u32 },
-    options?: {
-      /**
-       * The fee to pay for the transaction. Default: BASE_FEE
-       */
-      fee?: number;
+  get_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
 
-      /**
-       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-       */
-      timeoutInSeconds?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
 
-      /**
-       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-       */
-      simulate?: boolean;
-    }
-  ) => Promise<AssembledTransaction<string>>;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>;
 
   /**
-   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  approve: (
+  grant_role: (
     {
-      approver,
-      approved,
-      token_id,
-      live_until_ledger,
-    }: {
-      approver: string;
-      approved: string;
-      token_id: u32;
-      live_until_ledger: u32;
-    },
+      caller,
+      account,
+      role,
+    }: { caller: string; account: string; role: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -1145,14 +3599,14 @@ export interface Client {
   ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a approve_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  approve_for_all: (
+  revoke_role: (
     {
-      owner,
-      operator,
-      live_until_ledger,
-    }: { owner: string; operator: string; live_until_ledger: u32 },
+      caller,
+      account,
+      role,
+    }: { caller: string; account: string; role: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -1172,10 +3626,10 @@ export interface Client {
   ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a get_approved transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a renounce_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_approved: (
-    { token_id }: { token_id: u32 },
+  renounce_role: (
+    { caller, role }: { caller: string; role: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -1192,13 +3646,16 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<Option<string>>>;
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a is_approved_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a transfer_admin_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  is_approved_for_all: (
-    { owner, operator }: { owner: string; operator: string },
+  transfer_admin_role: (
+    {
+      new_admin,
+      live_until_ledger,
+    }: { new_admin: string; live_until_ledger: u32 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -1215,13 +3672,33 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<boolean>>;
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a accept_admin_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  token_uri: (
-    { token_id }: { token_id: u32 },
+  accept_admin_transfer: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_role_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_role_admin: (
+    { role, admin_role }: { role: string; admin_role: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -1238,32 +3715,12 @@ export interface Client {
        */
       simulate?: boolean;
     }
-  ) => Promise<AssembledTransaction<string>>;
-
-  /**
-   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  name: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>;
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a renounce_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  symbol: (options?: {
+  renounce_admin: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1278,12 +3735,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>;
+  }) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a get_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_owner: (options?: {
+  paused: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1298,16 +3755,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<string>>>;
+  }) => Promise<AssembledTransaction<boolean>>;
 
   /**
-   * Construct and simulate a transfer_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  transfer_ownership: (
-    {
-      new_owner,
-      live_until_ledger,
-    }: { new_owner: string; live_until_ledger: u32 },
+  pause: (
+    { caller }: { caller: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -1327,44 +3781,27 @@ export interface Client {
   ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a accept_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  accept_ownership: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>;
-
-  /**
-   * Construct and simulate a renounce_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  renounce_ownership: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  unpause: (
+    { caller }: { caller: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    }
+  ) => Promise<AssembledTransaction<null>>;
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
@@ -1422,40 +3859,126 @@ export class Client extends ContractClient {
   constructor(public readonly options: ContractClientOptions) {
     super(
       new ContractSpec([
-        "AAAAAwAAAAAAAAAAAAAAEEludmVzdG1lbnRTdGF0dXMAAAANAAAAAAAAAAtCdXlpbmdQaGFzZQAAAAAAAAAAAAAAABJCZWZvcmVGaXJzdFJlbGVhc2UAAAAAAAEAAAAAAAAACFJlbGVhc2UxAAAAAgAAAAAAAAAIUmVsZWFzZTIAAAADAAAAAAAAAAhSZWxlYXNlMwAAAAQAAAAAAAAACFJlbGVhc2U0AAAABQAAAAAAAAAIUmVsZWFzZTUAAAAGAAAAAAAAAAhSZWxlYXNlNgAAAAcAAAAAAAAACFJlbGVhc2U3AAAACAAAAAAAAAAIUmVsZWFzZTgAAAAJAAAAAAAAAAhSZWxlYXNlOQAAAAoAAAAAAAAACVJlbGVhc2UxMAAAAAAAAAsAAAAAAAAABUVuZGVkAAAAAAAADA==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAEgAAAAAAAAAAAAAAClN0YWJsZUNvaW4AAAAAAAAAAAAAAAAADUN1cnJlbnRTdXBwbHkAAAAAAAAAAAAAAAAAAAlCZWdpbkRhdGUAAAAAAAAAAAAAAAAAAB5BbW91bnRUb1JlbGVhc2VGb3JDdXJyZW50U3RhZ2UAAAAAAAAAAAAAAAAACFJlY2VpdmVyAAAAAAAAAAAAAAAFUGF5ZXIAAAAAAAAAAAAAAAAAAA5JbnZlc3RvcnNBcnJheQAAAAAAAAAAAAAAAAAOQ291bnRkb3duU3RhcnQAAAAAAAAAAAAAAAAABVN0YXRlAAAAAAAAAQAAAAAAAAAISW52ZXN0b3IAAAABAAAAEwAAAAEAAAAAAAAADUNsYWltZWRBbW91bnQAAAAAAAABAAAAEwAAAAAAAAAAAAAAFURpc3RyaWJ1dGlvbkludGVydmFscwAAAAAAAAAAAAAAAAAADlJPSVBlcmNlbnRhZ2VzAAAAAAAAAAAAAAAAAAVQcmljZQAAAAAAAAAAAAAAAAAADU1pbk5GVHNUb01pbnQAAAAAAAAAAAAAAAAAABJNYXhORlRzUGVySW52ZXN0b3IAAAAAAAAAAAAAAAAAC1RvdGFsU3VwcGx5AAAAAAAAAAAAAAAAFE5GVEJ1eWluZ1BoYXNlU3VwcGx5",
-        "AAAAAAAAAF5UaGVyZSBpcyBhIGxpbWl0YXRpb24gb2YgbWF4IDEwIHBhcmFtcyBieSB0aGUgc29yb2JhbiBjb250cmFjdApJbml0aWFsaXplcyB0aGUgTWluYWggY29udHJhY3QuAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAoAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAKc3RhYmxlY29pbgAAAAAAEwAAAAAAAAAIcmVjZWl2ZXIAAAATAAAAAAAAAAVwYXllcgAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAMdG90YWxfc3VwcGx5AAAABAAAAAAAAAAQbWluX25mdHNfdG9fbWludAAAAAQAAAAAAAAAFW1heF9uZnRzX3Blcl9pbnZlc3RvcgAAAAAAAAQAAAAAAAAAFmRpc3RyaWJ1dGlvbl9pbnRlcnZhbHMAAAAAA+oAAAAGAAAAAAAAAA9yb2lfcGVyY2VudGFnZXMAAAAD6gAAAAsAAAAA",
-        "AAAAAAAAAE5TZXRzIGEgbmV3IHN0YWJsZWNvaW4gYWRkcmVzcy4gT25seSB0aGUgY29udHJhY3Qgb3duZXIgY2FuIGNhbGwgdGhpcyBmdW5jdGlvbi4AAAAAAA5zZXRfc3RhYmxlY29pbgAAAAAAAQAAAAAAAAAKc3RhYmxlY29pbgAAAAAAEwAAAAA=",
-        "AAAAAAAAANZDcmVhdGVzIGEgbmV3IGludmVzdG9yLgpGdW5jdGlvbiBjYWxsZWQgZnJvbSB0aGUgYmFja2VuZCB3aGVuIGEgdXNlciBjcmVhdGVzIGEgcHJvZmlsZSBvbiB0aGUgTWluYWggcGxhdGZvcm0KIyBBcmd1bWVudHMKKiBgbmV3SW52ZXN0b3JgIDogdGhlIGZpcmVibG9ja3MgYWRkcmVzcyBnZW5lcmF0ZWQgZm9yIHRoZSBuZXcgdXNlci4gVG8gc3RvcmUgaW4gdGhlIGJhY2tlbmQuAAAAAAAPY3JlYXRlX2ludmVzdG9yAAAAAAEAAAAAAAAADG5ld19pbnZlc3RvcgAAABMAAAAA",
-        "AAAAAAAAAClNaW50cyBhIG5ldyBORlQgdG8gdGhlIHNwZWNpZmllZCBhZGRyZXNzLgAAAAAAAARtaW50AAAAAgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABmFtb3VudAAAAAAABAAAAAA=",
-        "AAAAAAAAACpTdGFydCB0aGUgY2hyb25vbWV0ZXIgZm9yIFJPSSBkaXN0cmlidXRpb24AAAAAABFzdGFydF9jaHJvbm9tZXRlcgAAAAAAAAAAAAAA",
-        "AAAAAAAAATNDYWxjdWxhdGUgYW1vdW50IHRvIHJlbGVhc2UgZm9yIGEgZ2l2ZW4gcGVyY2VudGFnZQpGdW5jdGlvbiB0byBrbm93IGhvdyBtdWNoIHRvIGFwcHJvdmUoKSBvbiB0aGUgU1RBQkxFQ09JTiBzbWFydCBjb250cmFjdCBiZWZvcmUgcmVsZWFzaW5nIHRoZSBhbW91bnQgdG8gYWxsIGludmVzdG9ycy4KQXJndW1lbnRzOgoqIGBwZXJjZW50YWdlYDogdGhlIHBlcmNlbnRhZ2Ugb2YgUk9JIHRvIGJlIHJlbGVhc2VkIGZvciB0aGUgY3VycmVudCBzdGFnZS4oU2NhbGVkIGJ5IDEwXzAwMF8wMDAgdG8gaGFuZGxlIGRlY2ltYWwgcGVyY2VudGFnZXMpAAAAABtjYWxjdWxhdGVfYW1vdW50X3RvX3JlbGVhc2UAAAAAAQAAAAAAAAAHcGVyY2VudAAAAAALAAAAAQAAAAs=",
-        "AAAAAAAAALtSZWxlYXNlcyB0aGUgZGlzdHJpYnV0aW9uIGZvciB0aGUgY3VycmVudCBzdGFnZS4KVGhpcyBmdW5jdGlvbiBuZWVkcyB0byBiZSBjYWxsZWQgYnkgdGhlIG93bmVyIGF0IHRoZSBlbmQgb2YgZXZlcnkgZGlzdHJpYnV0aW9uIHBlcmlvZC9zdGFnZSB0byB0cmlnZ2VyIHRoZSBjdXJyZW50IHJlbGVhc2UgYW5kIG5leHQgc3RhZ2UuAAAAABRyZWxlYXNlX2Rpc3RyaWJ1dGlvbgAAAAAAAAAA",
+        "AAAAAgAAAWNMaWZlY3ljbGUgb2YgYSBNaW5haCBvZmZlcmluZy4KYERpc3RyaWJ1dGluZyhzdGFnZSlgIGNhcnJpZXMgdGhlIGluZGV4IG9mIHRoZSBuZXh0IHJlbGVhc2Ugc3RhZ2UsIHNvIGBEaXN0cmlidXRpbmcoMClgIGlzIHRoZQpwZXJpb2QgYmV0d2VlbiBgc3RhcnRfY2hyb25vbWV0ZXJgIGFuZCB0aGUgZmlyc3QgcmVsZWFzZSwgYW5kIHRoZSBjb250cmFjdCBtb3ZlcyB0byBgRW5kZWRgCm9uY2UgdGhlIGxhc3Qgc3RhZ2Ugb2YgdGhlIHN0b3JlZCBzY2hlZHVsZSBoYXMgYmVlbiByZWxlYXNlZC4KYENhbmNlbGxlZGAgaXMgcmVhY2hlZCB3aGVuIHRoZSBzb2Z0IGNhcCBpcyBub3QgbWV0IGJ5IHRoZSByZWZ1bmQgZGVhZGxpbmUuAAAAAAAAAAAQSW52ZXN0bWVudFN0YXR1cwAAAAQAAAAAAAAAAAAAAAtCdXlpbmdQaGFzZQAAAAABAAAAAAAAAAxEaXN0cmlidXRpbmcAAAABAAAABAAAAAAAAAAAAAAABUVuZGVkAAAAAAAAAAAAAAAAAAAJQ2FuY2VsbGVkAAAA",
+        "AAAAAQAAAJxQcm9ncmVzcyBvZiBhIHN0YWdlIHJlbGVhc2VkIHBhZ2UgYnkgcGFnZSB3aXRoIGByZWxlYXNlX2Rpc3RyaWJ1dGlvbl9iYXRjaGAuClN0b3JlZCB3aGlsZSB0aGUgc3RhZ2UgaXMgaW4gcHJvZ3Jlc3MgYW5kIHJlbW92ZWQgb25jZSBpdHMgbGFzdCBwYWdlIGNvbXBsZXRlcy4AAAAAAAAAFERpc3RyaWJ1dGlvblByb2dyZXNzAAAABQAAAAAAAAASZGlzdHJpYnV0ZWRfYW1vdW50AAAAAAALAAAAAAAAABBkaXN0cmlidXRlZF9uZnRzAAAACwAAAAAAAAAPaW52ZXN0b3JzX2NvdW50AAAAAAQAAAAAAAAADW5leHRfaW52ZXN0b3IAAAAAAAAEAAAAAAAAAAVzdGFnZQAAAAAAAAQ=",
+        "AAAAAgAAAH1FbnRyeSBwb2ludHMgdGhhdCBjYW4gYmUgcGF1c2VkIG9uZSBieSBvbmUgd2l0aCBgcGF1c2VfZnVuY3Rpb25gLgpQYXVzaW5nIHRoZSB3aG9sZSBjb250cmFjdCB3aXRoIGBwYXVzZWAgYmxvY2tzIGFsbCBvZiB0aGVtLgAAAAAAAAAAAAAQUGF1c2FibGVGdW5jdGlvbgAAAAYAAAAAAAAAAAAAAARNaW50AAAAAAAAAAAAAAAJQnV5VG9rZW5zAAAAAAAAAAAAAAAAAAAKU2VsbFRva2VucwAAAAAAAAAAAAAAAAATUmVsZWFzZURpc3RyaWJ1dGlvbgAAAAAAAAAAAAAAAAhUcmFuc2ZlcgAAAAAAAAAAAAAAEEJ1eUZyb21JbnZlbnRvcnk=",
+        "AAAAAgAAALxBZGRyZXNzZXMgYW5kIHNldHRpbmdzIHRoYXQgY2FuIG9ubHkgYmUgY2hhbmdlZCB0aHJvdWdoIHRoZSB0aW1lbG9jay4KVGhlIHN0YWJsZWNvaW4gaXMgZnJvemVuIG9uY2UgdGhlIGNocm9ub21ldGVyIHN0YXJ0cywgdGhlIHByaWNlIGFuZCByZWxlYXNlIHNjaGVkdWxlCmFzIHNvb24gYXMgdGhlIGZpcnN0IE5GVCBpcyBzb2xkLgAAAAAAAAAPQ29uZmlnUGFyYW1ldGVyAAAAAAoAAAAAAAAAAAAAAApTdGFibGVDb2luAAAAAAAAAAAAAAAAAAhSZWNlaXZlcgAAAAAAAAAAAAAABVBheWVyAAAAAAAAAAAAAAAAAAALQ2hhbmdlRGVsYXkAAAAAAAAAAAAAAAAFUHJpY2UAAAAAAAAAAAAAAAAAAA9SZWxlYXNlU2NoZWR1bGUAAAAAAAAAAAAAAAAKVHJhZGluZ0ZlZQAAAAAAAAAAAAAAAAAMRmVlUmVjaXBpZW50AAAAAAAAAAAAAAARQWNjcnVlZFJvaVByaWNpbmcAAAAAAAAAAAAAAAAAAAxMb2NrVXBQZXJpb2Q=",
+        "AAAAAgAAAFFBIGNoYW5nZSBwcm9wb3NlZCB3aXRoIGBwcm9wb3NlX2NoYW5nZWAsIGNhcnJ5aW5nIHRoZSBuZXcgdmFsdWUgb2YgaXRzIHBhcmFtZXRlci4AAAAAAAAAAAAADENvbmZpZ0NoYW5nZQAAAAoAAAABAAAAAAAAAApTdGFibGVDb2luAAAAAAABAAAAEwAAAAEAAAAAAAAACFJlY2VpdmVyAAAAAQAAABMAAAABAAAAAAAAAAVQYXllcgAAAAAAAAEAAAATAAAAAQAAAAAAAAALQ2hhbmdlRGVsYXkAAAAAAQAAAAYAAAABAAAAAAAAAAVQcmljZQAAAAAAAAEAAAALAAAAAQAAAAAAAAAPUmVsZWFzZVNjaGVkdWxlAAAAAAIAAAPqAAAABgAAA+oAAAALAAAAAQAAAAAAAAAKVHJhZGluZ0ZlZQAAAAAAAQAAAAQAAAABAAAAAAAAAAxGZWVSZWNpcGllbnQAAAABAAAD6AAAABMAAAABAAAAAAAAABFBY2NydWVkUm9pUHJpY2luZwAAAAAAAAEAAAABAAAAAQAAAAAAAAAMTG9ja1VwUGVyaW9kAAAAAQAAAAY=",
+        "AAAAAQAAADVBIHByb3Bvc2VkIGNoYW5nZSB3YWl0aW5nIGZvciBpdHMgdGltZWxvY2sgdG8gZWxhcHNlLgAAAAAAAAAAAAANUGVuZGluZ0NoYW5nZQAAAAAAAAIAAAAAAAAABmNoYW5nZQAAAAAH0AAAAAxDb25maWdDaGFuZ2UAAAAAAAAADWV4ZWN1dGFibGVfYXQAAAAAAAAG",
+        "AAAAAgAAAGJJbnZlc3RvciB0aWVycywgZWFjaCB3aXRoIGl0cyBvd24gbWF4aW11bSBob2xkaW5nIChgTWF4TkZUc1BlckludmVzdG9yYCB1bnRpbCB0aGUgYWRtaW4gc2V0cyBvbmUpLgAAAAAAAAAAAAxJbnZlc3RvclRpZXIAAAADAAAAAAAAAAAAAAAGUmV0YWlsAAAAAAAAAAAAAAAAAApBY2NyZWRpdGVkAAAAAAAAAAAAAAAAAA1JbnN0aXR1dGlvbmFsAAAA",
+        "AAAAAQAAAE9LWUMgcmVjb3JkIG9mIGFuIGludmVzdG9yLiBJbnZlc3RvcnMgd2l0aG91dCBvbmUgYXJlIGBSZXRhaWxgIGFuZCBuZXZlciBleHBpcmUuAAAAAAAAAAAPSW52ZXN0b3JQcm9maWxlAAAAAAIAAAAAAAAADmt5Y19leHBpcmVzX2F0AAAAAAAGAAAAAAAAAAR0aWVyAAAH0AAAAAxJbnZlc3RvclRpZXI=",
+        "AAAAAQAAAJ1Ub2tlbnMgbGlzdGVkIGJ5IGEgaG9sZGVyIGF0IHRoZWlyIG93biBwcmljZSwgaW4gc3RhYmxlY29pbiB1bml0cyBwZXIgTkZULgpUaGV5IHN0YXkgaW4gdGhlIHNlbGxlcidzIGFjY291bnQsIGxvY2tlZCB1bnRpbCBzb2xkLCBvciB0aGUgbGlzdGluZyBpcyBjYW5jZWxsZWQuAAAAAAAAAAAAAAdMaXN0aW5nAAAAAAQAAAAAAAAABmV4cGlyeQAAAAAABgAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAl0b2tlbl9pZHMAAAAAAAPqAAAABAAAAAAAAAAKdW5pdF9wcmljZQAAAAAACw==",
+        "AAAAAQAAAKxTdGFibGVjb2luIGVzY3Jvd2VkIGJ5IGEgYnV5ZXIgZm9yIGBxdWFudGl0eWAgdG9rZW5zIGF0IGB1bml0X3ByaWNlYCwgYW55IHRva2VucyB3aGVuCmB0b2tlbl9pZHNgIGlzIGVtcHR5LCBvbmx5IHRob3NlIG90aGVyd2lzZS4gYGZlZWAgaXMgdGhlIHRyYWRpbmcgZmVlIGVzY3Jvd2VkIHdpdGggaXQuAAAAAAAAAAVPZmZlcgAAAAAAAAYAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAGZXhwaXJ5AAAAAAAGAAAAAAAAAANmZWUAAAAACwAAAAAAAAAIcXVhbnRpdHkAAAAEAAAAAAAAAAl0b2tlbl9pZHMAAAAAAAPqAAAABAAAAAAAAAAKdW5pdF9wcmljZQAAAAAACw==",
+        "AAAAAQAAAMxUZXJtcyBvbiB3aGljaCBpbnZlc3RvcnMgYnV5IHRoZSB1bnNvbGQgTkZUcyBtaW50ZWQgdG8gdGhlIG93bmVyIGJ5IGBzdGFydF9jaHJvbm9tZXRlcmAuCmB1bml0X3ByaWNlYCBpcyBpbiBzdGFibGVjb2luIHVuaXRzIHBlciBORlQsIGxvd2VyZWQgYnkgdGhlIFJPSSBhbHJlYWR5IHBhaWQgcGVyIE5GVCB3aGVuCmBkZWR1Y3RfcGFpZF9yb2lgIGlzIHNldC4AAAAAAAAAEkNvbnRpbnVvdXNPZmZlcmluZwAAAAAAAgAAAAAAAAAPZGVkdWN0X3BhaWRfcm9pAAAAAAEAAAAAAAAACnVuaXRfcHJpY2UAAAAAAAs=",
+        "AAAAAQAAAFJDb25zZWN1dGl2ZSB0b2tlbiBpZHMgYHN0YXJ0Li5lbmRgIGhlbGQgYnkgYW4gYWNjb3VudCwgYXMga2VwdCBieSB0aGUgdG9rZW4gaW5kZXguAAAAAAAAAAAAClRva2VuUmFuZ2UAAAAAAAIAAAAAAAAAA2VuZAAAAAAEAAAAAAAAAAVzdGFydAAAAAAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAMAAAAAAAAAAAAAAAClN0YWJsZUNvaW4AAAAAAAAAAAAAAAAADUN1cnJlbnRTdXBwbHkAAAAAAAAAAAAAAAAAAAlCZWdpbkRhdGUAAAAAAAAAAAAAAAAAAB5BbW91bnRUb1JlbGVhc2VGb3JDdXJyZW50U3RhZ2UAAAAAAAAAAAAAAAAACFJlY2VpdmVyAAAAAAAAAAAAAAAFUGF5ZXIAAAAAAAAAAAAAAAAAAA5Db3VudGRvd25TdGFydAAAAAAAAAAAAAAAAAAFU3RhdGUAAAAAAAABAAAAAAAAAAhJbnZlc3RvcgAAAAEAAAATAAAAAQAAAAAAAAANQ2xhaW1lZEFtb3VudAAAAAAAAAEAAAATAAAAAAAAAAAAAAAVRGlzdHJpYnV0aW9uSW50ZXJ2YWxzAAAAAAAAAAAAAAAAAAAOUk9JUGVyY2VudGFnZXMAAAAAAAAAAAAAAAAABVByaWNlAAAAAAAAAAAAAAAAAAANTWluTkZUc1RvTWludAAAAAAAAAAAAAAAAAAAEk1heE5GVHNQZXJJbnZlc3RvcgAAAAAAAAAAAAAAAAALVG90YWxTdXBwbHkAAAAAAAAAAAAAAAAUTkZUQnV5aW5nUGhhc2VTdXBwbHkAAAAAAAAAAAAAAAdTb2Z0Q2FwAAAAAAAAAAAAAAAADlJlZnVuZERlYWRsaW5lAAAAAAAAAAAAAAAAAA5Tb2Z0Q2FwUmVhY2hlZAAAAAAAAAAAAAAAAAAORXNjcm93ZWRBbW91bnQAAAAAAAEAAAAAAAAAClBhaWRBbW91bnQAAAAAAAEAAAATAAAAAAAAAAAAAAAJU2FsZVN0YXJ0AAAAAAAAAAAAAAAAAAAHU2FsZUVuZAAAAAAAAAAAAAAAABBSZWRlbXB0aW9uRnVuZGVkAAAAAAAAAAAAAAAOUmVkZWVtZWRTdXBwbHkAAAAAAAEAAAAAAAAADlJlZGVlbWVkQW1vdW50AAAAAAABAAAAEwAAAAEAAAAAAAAAD0NsYWltYWJsZUFtb3VudAAAAAABAAAAEwAAAAEAAAAAAAAAEVN0YWdlQW1vdW50UGVyTkZUAAAAAAAAAQAAAAQAAAAAAAAAAAAAABREaXN0cmlidXRpb25Qcm9ncmVzcwAAAAEAAAAAAAAAEkJhbGFuY2VDaGVja3BvaW50cwAAAAAAAQAAABMAAAABAAAAAAAAAA5TdGFnZVJlbWFpbmRlcgAAAAAAAQAAAAQAAAAAAAAAAAAAAAtEdXN0QmFsYW5jZQAAAAAAAAAAAAAAAApSb2xsZWREdXN0AAAAAAAAAAAAAAAAAA5JbnZlc3RvcnNDb3VudAAAAAAAAQAAAAAAAAAKSW52ZXN0b3JBdAAAAAAAAQAAAAQAAAABAAAAAAAAAA1JbnZlc3RvckluZGV4AAAAAAAAAQAAABMAAAAAAAAAAAAAAA5TdG9yYWdlVmVyc2lvbgAAAAAAAQAAAAAAAAAORnVuY3Rpb25QYXVzZWQAAAAAAAEAAAfQAAAAEFBhdXNhYmxlRnVuY3Rpb24AAAAAAAAAAAAAAAtDaGFuZ2VEZWxheQAAAAABAAAAAAAAAA1QZW5kaW5nQ2hhbmdlAAAAAAAAAQAAB9AAAAAPQ29uZmlnUGFyYW1ldGVyAAAAAAEAAAAAAAAADkludmVzdG9yRnJvemVuAAAAAAABAAAAEwAAAAEAAAAAAAAADEZyb3plbkFtb3VudAAAAAEAAAATAAAAAQAAAAAAAAAPSW52ZXN0b3JQcm9maWxlAAAAAAEAAAATAAAAAQAAAAAAAAAHVGllckNhcAAAAAABAAAH0AAAAAxJbnZlc3RvclRpZXIAAAABAAAAAAAAAA5JbnZlc3RvckxvY2tVcAAAAAAAAQAAABMAAAABAAAAAAAAAAtMb2NrZWRVbnRpbAAAAAABAAAAEwAAAAAAAAAAAAAADExvY2tVcFBlcmlvZA==",
+        "AAAAAgAAAHFTdG9yYWdlIGtleXMgb2YgdGhlIHNlY29uZGFyeSBtYXJrZXQuCktlcHQgYXBhcnQgZnJvbSBgRGF0YUtleWAsIHdoaWNoIGlzIGF0IHRoZSBzaXplIGxpbWl0IG9mIHRoZSBjb250cmFjdCBzcGVjLgAAAAAAAAAAAAASTWFya2V0cGxhY2VEYXRhS2V5AAAAAAAMAAAAAAAAAAAAAAANTmV4dExpc3RpbmdJZAAAAAAAAAAAAAAAAAAAC05leHRPZmZlcklkAAAAAAAAAAAAAAAADVRyYWRpbmdGZWVCcHMAAAAAAAAAAAAAAAAAAAxGZWVSZWNpcGllbnQAAAAAAAAAAAAAABJDb250aW51b3VzT2ZmZXJpbmcAAAAAAAAAAAAAAAAAEUFjY3J1ZWRSb2lQcmljaW5nAAAAAAAAAAAAAAAAAAAPSW52ZW50b3J5Q3Vyc29yAAAAAAAAAAAAAAAAD0ludmVudG9yeVN1cHBseQAAAAABAAAAAAAAAAdMaXN0aW5nAAAAAAEAAAAEAAAAAQAAAAAAAAALTGlzdGVkVG9rZW4AAAAAAQAAAAQAAAABAAAAAAAAAAVPZmZlcgAAAAAAAAEAAAAEAAAAAQAAAAAAAAALT3duZWRUb2tlbnMAAAAAAQAAABM=",
+        "AAAABAAAAOtFcnJvcnMgcmV0dXJuZWQgYnkgdGhlIE1pbmFoIGNvbnRyYWN0LgpDb2RlcyBhcmUgcGFydCBvZiB0aGUgY29udHJhY3QgaW50ZXJmYWNlOiBuZXZlciByZW51bWJlciBhIHZhcmlhbnQsIG9ubHkgYXBwZW5kIG5ldyBvbmVzLgpUaGUgY29udHJhY3Qgc3BlYyBhbGxvd3MgYXQgbW9zdCA1MCB2YXJpYW50cywgcHJlZmVyIHJldXNpbmcgYSBjb2RlIHdoZW4gYSBuZXcgY2hlY2sgZml0cyBhbiBleGlzdGluZyBvbmUuAAAAAAAAAAAKTWluYWhFcnJvcgAAAAAAMQAAAAAAAAAOTm90SW5pdGlhbGl6ZWQAAAAAAAEAAAAAAAAAFkludmFsaWRSZWxlYXNlU2NoZWR1bGUAAAAAAAIAAAAAAAAAGkludmVzdG1lbnROb3RJbkJ1eWluZ1BoYXNlAAAAAAADAAAAAAAAABpDb25maWdMb2NrZWRBZnRlckZpcnN0TWludAAAAAAABAAAAAAAAAAZU29mdENhcEV4Y2VlZHNUb3RhbFN1cHBseQAAAAAAAAUAAAAAAAAAFUludmFsaWRSZWZ1bmREZWFkbGluZQAAAAAAAAYAAAAAAAAAEUludmFsaWRTYWxlV2luZG93AAAAAAAABwAAAAAAAAAVSW52ZXN0b3JBbHJlYWR5RXhpc3RzAAAAAAAACAAAAAAAAAARVXNlck5vdEFuSW52ZXN0b3IAAAAAAAAJAAAAAAAAAAtTYWxlTm90T3BlbgAAAAAKAAAAAAAAAApTYWxlQ2xvc2VkAAAAAAALAAAAAAAAABdNaW5pbXVtSW52ZXN0bWVudE5vdE1ldAAAAAAMAAAAAAAAAB5NYXhpbXVtTmZ0c1BlckludmVzdG9yRXhjZWVkZWQAAAAAAA0AAAAAAAAAFU1heGltdW1TdXBwbHlFeGNlZWRlZAAAAAAAAA4AAAAAAAAAE0luc3VmZmljaWVudEJhbGFuY2UAAAAADwAAAAAAAAAVSW5zdWZmaWNpZW50QWxsb3dhbmNlAAAAAAAAEAAAAAAAAAAZQ2hyb25vbWV0ZXJBbHJlYWR5U3RhcnRlZAAAAAAAABEAAAAAAAAAEU9mZmVyaW5nQ2FuY2VsbGVkAAAAAAAAEgAAAAAAAAARU29mdENhcE5vdFJlYWNoZWQAAAAAAAATAAAAAAAAABVTb2Z0Q2FwQWxyZWFkeVJlYWNoZWQAAAAAAAAUAAAAAAAAABhSZWZ1bmREZWFkbGluZU5vdFJlYWNoZWQAAAAVAAAAAAAAABRPZmZlcmluZ05vdENhbmNlbGxlZAAAABYAAAAAAAAAD05vdGhpbmdUb1JlZnVuZAAAAAAXAAAAAAAAABVSZWZ1bmRNdXN0QnVybkFsbE5mdHMAAAAAAAAYAAAAAAAAABNDb3VudGRvd25Ob3RTdGFydGVkAAAAABkAAAAAAAAAEUludmFsaWRMZWRnZXJUaW1lAAAAAAAAGgAAAAAAAAAYRGlzdHJpYnV0aW9uQWxyZWFkeUVuZGVkAAAAGwAAAAAAAAAXRGlzdHJpYnV0aW9uTm90UmVhZHlZZXQAAAAAHAAAAAAAAAAbRGlzdHJpYnV0aW9uQmF0Y2hJblByb2dyZXNzAAAAAB0AAAAAAAAAEUludmFsaWRCYXRjaExpbWl0AAAAAAAAHgAAAAAAAAAdRGlzdHJpYnV0aW9uUmVtYWluZGVyTWlzbWF0Y2gAAAAAAAAfAAAAAAAAAA5Ob3RoaW5nVG9DbGFpbQAAAAAAIAAAAAAAAAAGTm9EdXN0AAAAAAAhAAAAAAAAABJJbnZlc3RtZW50Tm90RW5kZWQAAAAAACIAAAAAAAAAF1JlZGVtcHRpb25BbHJlYWR5RnVuZGVkAAAAACMAAAAAAAAAE1JlZGVtcHRpb25Ob3RGdW5kZWQAAAAAJAAAAAAAAAART3duZXJDYW5ub3RSZWRlZW0AAAAAAAAlAAAAAAAAABBOb1Rva2Vuc1RvUmVkZWVtAAAAJgAAAAAAAAAnTmZ0VHJhbnNmZXJzTm90QWxsb3dlZER1cmluZ0J1eWluZ1BoYXNlAAAAACcAAAAAAAAAGlRyYWRpbmdDbG9zZWRGb3JSZWRlbXB0aW9uAAAAAAAoAAAAAAAAABJOb3RJbnZlc3Rvck9yT3duZXIAAAAAACkAAAAAAAAAGkluc3VmZmljaWVudEZyb21OZnRCYWxhbmNlAAAAAAAqAAAAAAAAABhTcGVuZGVyTm90QXBwcm92ZWRGb3JBbGwAAAArAAAAAAAAABlVbnN1cHBvcnRlZFN0b3JhZ2VWZXJzaW9uAAAAAAAALgAAAAAAAAAORnVuY3Rpb25QYXVzZWQAAAAAAC8AAAAAAAAADkludmVzdG9yRnJvemVuAAAAAABkAAAAAAAAAApLeWNFeHBpcmVkAAAAAABnAAAAAAAAAAhMb2NrZWRVcAAAAGkAAAAAAAAAC1Rva2VuTGlzdGVkAAAAATA=",
+        "AAAABAAAAXtFcnJvcnMgb2YgdGhlIGludmVzdG9yIGNvbXBsaWFuY2UgZW50cnkgcG9pbnRzLCBhZGRlZCBvbmNlIGBNaW5haEVycm9yYCBnb3QgY2xvc2UgdG8gdGhlIDUwIHZhcmlhbnRzIHRoZSBzcGVjIGFsbG93cy4KRXZlcnkgZW50cnkgcG9pbnQgb25seSBmYWlscyB3aXRoIHRoZSBlcnJvciBlbnVtIGl0IHJldHVybnMuIEEgZmFpbHVyZSByZXBvcnRlZCBieSBzZXZlcmFsIGFyZWFzIGhhcyBhIHZhcmlhbnQKaW4gZWFjaCBvZiB0aGVpciBlbnVtcyB1bmRlciB0aGUgc2FtZSBjb2RlLCBzbyBhIGNvZGUgbWVhbnMgdGhlIHNhbWUgZmFpbHVyZSB3aGljaGV2ZXIgZW51bSByZXBvcnRzIGl0LgpDb2RlcyBvZiB0aGUgY29tcGxpYW5jZSBmYWlsdXJlcyBzdGFydCBhdCAxMDAuAAAAAAAAAAAPQ29tcGxpYW5jZUVycm9yAAAAAAoAAAAAAAAADk5vdEluaXRpYWxpemVkAAAAAAABAAAAAAAAABFVc2VyTm90QW5JbnZlc3RvcgAAAAAAAAkAAAAAAAAAG0Rpc3RyaWJ1dGlvbkJhdGNoSW5Qcm9ncmVzcwAAAAAdAAAAAAAAAA5JbnZlc3RvckZyb3plbgAAAAAAZAAAAAAAAAARSW52ZXN0b3JOb3RGcm96ZW4AAAAAAABlAAAAAAAAABJJbnZlc3Rvckhhc0JhbGFuY2UAAAAAAGYAAAAAAAAACkt5Y0V4cGlyZWQAAAAAAGcAAAAAAAAAElRyYW5zZmVyUmVzdHJpY3RlZAAAAAAAaAAAAAAAAAAITG9ja2VkVXAAAABpAAAAAAAAAA5SZWxlYXNlUGVuZGluZwAAAAAAag==",
+        "AAAABAAAAMhFcnJvcnMgb2YgdGhlIHNlY29uZGFyeSBtYXJrZXQgZW50cnkgcG9pbnRzLCBmb2xsb3dpbmcgdGhlIHNhbWUgcnVsZXMgYXMgYENvbXBsaWFuY2VFcnJvcmAuCkNvZGVzIG9mIHRoZSBtYXJrZXRwbGFjZSBmYWlsdXJlcyBzdGFydCBhdCAzMDAgYXMgMjAwIHRvIDIxMiBhcmUgdXNlZCBieSB0aGUgTkZUIGVycm9ycyBvZiBgc3RlbGxhci10b2tlbnNgLgAAAAAAAAAQTWFya2V0cGxhY2VFcnJvcgAAABwAAAAAAAAADk5vdEluaXRpYWxpemVkAAAAAAABAAAAAAAAABFVc2VyTm90QW5JbnZlc3RvcgAAAAAAAAkAAAAAAAAAHk1heGltdW1OZnRzUGVySW52ZXN0b3JFeGNlZWRlZAAAAAAADQAAAAAAAAATSW5zdWZmaWNpZW50QmFsYW5jZQAAAAAPAAAAAAAAABVJbnN1ZmZpY2llbnRBbGxvd2FuY2UAAAAAAAAQAAAAAAAAABFPZmZlcmluZ0NhbmNlbGxlZAAAAAAAABIAAAAAAAAAG0Rpc3RyaWJ1dGlvbkJhdGNoSW5Qcm9ncmVzcwAAAAAdAAAAAAAAACdOZnRUcmFuc2ZlcnNOb3RBbGxvd2VkRHVyaW5nQnV5aW5nUGhhc2UAAAAAJwAAAAAAAAAaVHJhZGluZ0Nsb3NlZEZvclJlZGVtcHRpb24AAAAAACgAAAAAAAAAEk5vdEludmVzdG9yT3JPd25lcgAAAAAAKQAAAAAAAAAaSW5zdWZmaWNpZW50RnJvbU5mdEJhbGFuY2UAAAAAACoAAAAAAAAAGFNwZW5kZXJOb3RBcHByb3ZlZEZvckFsbAAAACsAAAAAAAAADkZ1bmN0aW9uUGF1c2VkAAAAAAAvAAAAAAAAAA5JbnZlc3RvckZyb3plbgAAAAAAZAAAAAAAAAAKS3ljRXhwaXJlZAAAAAAAZwAAAAAAAAAITG9ja2VkVXAAAABpAAAAAAAAAA5JbmNvcnJlY3RPd25lcgAAAAAAyQAAAAAAAAAPTGlzdGluZ05vdEZvdW5kAAAAASwAAAAAAAAADkxpc3RpbmdFeHBpcmVkAAAAAAEtAAAAAAAAAA5JbnZhbGlkTGlzdGluZwAAAAABLgAAAAAAAAAPSW52YWxpZFF1YW50aXR5AAAAAS8AAAAAAAAAC1Rva2VuTGlzdGVkAAAAATAAAAAAAAAADU9mZmVyTm90Rm91bmQAAAAAAAEyAAAAAAAAAAxPZmZlckV4cGlyZWQAAAEzAAAAAAAAAAxJbnZhbGlkT2ZmZXIAAAE0AAAAAAAAABhDb250aW51b3VzT2ZmZXJpbmdDbG9zZWQAAAE1AAAAAAAAAA9QcmljZUFib3ZlTGltaXQAAAABNgAAAAAAAAAMSW52YWxpZFByaWNlAAABNw==",
+        "AAAABAAAAK1FcnJvcnMgb2YgdGhlIHRpbWVsb2NrZWQgY29uZmlndXJhdGlvbiBjaGFuZ2VzLCBzcGxpdCBmcm9tIGBNaW5haEVycm9yYCB0byBtYWtlIHJvb20gaW4gaXQuCkZhaWx1cmVzIHNoYXJlZCB3aXRoIGFub3RoZXIgZW51bSBrZWVwIHRoZWlyIGNvZGUgdGhlcmUsIGFzIGluIGBDb21wbGlhbmNlRXJyb3JgLgAAAAAAAAAAAAALQ29uZmlnRXJyb3IAAAAABwAAAAAAAAAOTm90SW5pdGlhbGl6ZWQAAAAAAAEAAAAAAAAAFkludmFsaWRSZWxlYXNlU2NoZWR1bGUAAAAAAAIAAAAAAAAAGkludmVzdG1lbnROb3RJbkJ1eWluZ1BoYXNlAAAAAAADAAAAAAAAABpDb25maWdMb2NrZWRBZnRlckZpcnN0TWludAAAAAAABAAAAAAAAAAPTm9QZW5kaW5nQ2hhbmdlAAAAADAAAAAAAAAADkNoYW5nZU5vdFJlYWR5AAAAAAAxAAAAAAAAABFUcmFkaW5nRmVlVG9vSGlnaAAAAAAAATE=",
+        "AAAAAAAAAF5UaGVyZSBpcyBhIGxpbWl0YXRpb24gb2YgbWF4IDEwIHBhcmFtcyBieSB0aGUgc29yb2JhbiBjb250cmFjdApJbml0aWFsaXplcyB0aGUgTWluYWggY29udHJhY3QuAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAoAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAKc3RhYmxlY29pbgAAAAAAEwAAAAAAAAAIcmVjZWl2ZXIAAAATAAAAAAAAAAVwYXllcgAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAMdG90YWxfc3VwcGx5AAAABAAAAAAAAAAQbWluX25mdHNfdG9fbWludAAAAAQAAAAAAAAAFW1heF9uZnRzX3Blcl9pbnZlc3RvcgAAAAAAAAQAAAAAAAAAFmRpc3RyaWJ1dGlvbl9pbnRlcnZhbHMAAAAAA+oAAAAGAAAAAAAAAA9yb2lfcGVyY2VudGFnZXMAAAAD6gAAAAsAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAVhQcm9wb3NlcyBhIG5ldyB2YWx1ZSBmb3IgYSBgQ29uZmlnUGFyYW1ldGVyYC4gT25seSB0aGUgYWRtaW4gY2FuIGNhbGwgdGhpcyBmdW5jdGlvbi4KVGhlIGNoYW5nZSBjYW4gYmUgZXhlY3V0ZWQgb25jZSB0aGUgY3VycmVudCBjaGFuZ2UgZGVsYXkgaGFzIGVsYXBzZWQsIGdpdmluZyBpbnZlc3RvcnMgdGltZSB0bwpyZWFjdC4gUHJvcG9zaW5nIGFnYWluIGZvciB0aGUgc2FtZSBwYXJhbWV0ZXIgcmVwbGFjZXMgdGhlIHBlbmRpbmcgY2hhbmdlIGFuZCByZXN0YXJ0cyB0aGUgZGVsYXkuCiMgQXJndW1lbnRzCiogYGNoYW5nZWAgOiB0aGUgcGFyYW1ldGVyIHRvIGNoYW5nZSBhbmQgaXRzIG5ldyB2YWx1ZQAAAA5wcm9wb3NlX2NoYW5nZQAAAAAAAQAAAAAAAAAGY2hhbmdlAAAAAAfQAAAADENvbmZpZ0NoYW5nZQAAAAEAAAPpAAAABgAAB9AAAAALQ29uZmlnRXJyb3IA",
+        "AAAAAAAAAF5BcHBsaWVzIHRoZSBwZW5kaW5nIGNoYW5nZSBvZiBhIHBhcmFtZXRlciBvbmNlIGl0cyBkZWxheSBoYXMgZWxhcHNlZC4gQ2FuIGJlIGNhbGxlZCBieSBhbnlvbmUuAAAAAAAOZXhlY3V0ZV9jaGFuZ2UAAAAAAAEAAAAAAAAACXBhcmFtZXRlcgAAAAAAB9AAAAAPQ29uZmlnUGFyYW1ldGVyAAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAAC0NvbmZpZ0Vycm9yAA==",
+        "AAAAAAAAAE9Ecm9wcyB0aGUgcGVuZGluZyBjaGFuZ2Ugb2YgYSBwYXJhbWV0ZXIuIE9ubHkgdGhlIGFkbWluIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24uAAAAAA1jYW5jZWxfY2hhbmdlAAAAAAAAAQAAAAAAAAAJcGFyYW1ldGVyAAAAAAAH0AAAAA9Db25maWdQYXJhbWV0ZXIAAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAALQ29uZmlnRXJyb3IA",
+        "AAAAAAAAAgBTZXRzIHRoZSBzb2Z0IGNhcCBvZiB0aGUgb2ZmZXJpbmcuIE9ubHkgdGhlIGFkbWluIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24uCk1pbnQgcHJvY2VlZHMgYXJlIGVzY3Jvd2VkIGluIHRoZSBjb250cmFjdCB1bnRpbCBgc29mdF9jYXBgIE5GVHMgYXJlIHNvbGQuIElmIHRoZSBzb2Z0IGNhcCBpcyBub3QKcmVhY2hlZCBieSBgcmVmdW5kX2RlYWRsaW5lYCB0aGUgb2ZmZXJpbmcgY2FuIGJlIGNhbmNlbGxlZCBhbmQgaW52ZXN0b3JzIHJlZnVuZGVkLgpNdXN0IGJlIGNhbGxlZCBiZWZvcmUgdGhlIGZpcnN0IG1pbnQuCiMgQXJndW1lbnRzCiogYHNvZnRfY2FwYCA6IG1pbmltdW0gbnVtYmVyIG9mIE5GVHMgdG8gc2VsbCBkdXJpbmcgdGhlIGJ1eWluZyBwaGFzZSAoMCBkaXNhYmxlcyB0aGUgc29mdCBjYXApCiogYHJlZnVuZF9kZWFkbGluZWAgOiBsZWRnZXIgdGltZXN0YW1wIGFmdGVyIHdoaWNoIHRoZSBvZmZlcmluZyBjYW4gYmUgY2FuY2VsbGVkIGlmIHRoZSBzb2Z0IGNhcCBpcyBub3QgcmVhY2hlZAAAAAxzZXRfc29mdF9jYXAAAAACAAAAAAAAAAhzb2Z0X2NhcAAAAAQAAAAAAAAAD3JlZnVuZF9kZWFkbGluZQAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAAX5TZXRzIHRoZSBidXlpbmcgcGhhc2Ugd2luZG93LiBPbmx5IHRoZSBhZG1pbiBjYW4gY2FsbCB0aGlzIGZ1bmN0aW9uLgpgbWludGAgaXMgb25seSBhbGxvd2VkIGJldHdlZW4gYHNhbGVfc3RhcnRgIChpbmNsdXNpdmUpIGFuZCBgc2FsZV9lbmRgIChleGNsdXNpdmUpLCBhbmQgYW55b25lIGNhbiBzdGFydAp0aGUgY2hyb25vbWV0ZXIgb25jZSBgc2FsZV9lbmRgIGhhcyBwYXNzZWQuIE11c3QgYmUgY2FsbGVkIGJlZm9yZSB0aGUgZmlyc3QgbWludC4KIyBBcmd1bWVudHMKKiBgc2FsZV9zdGFydGAgOiBsZWRnZXIgdGltZXN0YW1wIGF0IHdoaWNoIHRoZSBzYWxlIG9wZW5zCiogYHNhbGVfZW5kYCA6IGxlZGdlciB0aW1lc3RhbXAgYXQgd2hpY2ggdGhlIHNhbGUgY2xvc2VzAAAAAAAPc2V0X3NhbGVfd2luZG93AAAAAAIAAAAAAAAACnNhbGVfc3RhcnQAAAAAAAYAAAAAAAAACHNhbGVfZW5kAAAABgAAAAEAAAPpAAAD7QAAAAAAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAAQpDcmVhdGVzIGEgbmV3IGludmVzdG9yLgpGdW5jdGlvbiBjYWxsZWQgZnJvbSB0aGUgYmFja2VuZCB3aGVuIGEgdXNlciBjcmVhdGVzIGEgcHJvZmlsZSBvbiB0aGUgTWluYWggcGxhdGZvcm0KIyBBcmd1bWVudHMKKiBgY2FsbGVyYCA6IGFuIGFjY291bnQgaG9sZGluZyB0aGUgY29tcGxpYW5jZSByb2xlCiogYG5ld0ludmVzdG9yYCA6IHRoZSBmaXJlYmxvY2tzIGFkZHJlc3MgZ2VuZXJhdGVkIGZvciB0aGUgbmV3IHVzZXIuIFRvIHN0b3JlIGluIHRoZSBiYWNrZW5kLgAAAAAAD2NyZWF0ZV9pbnZlc3RvcgAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAADG5ld19pbnZlc3RvcgAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAbRSZWNvcmRzIHRoZSB0aWVyIGFuZCBLWUMgZXhwaXJ5IG9mIGFuIGludmVzdG9yLCBhbHNvIHVzZWQgdG8gcmVuZXcgYW4gZXhwaXJlZCBLWUMuCk9uY2UgYGt5Y19leHBpcmVzX2F0YCBoYXMgcGFzc2VkIHRoZSBpbnZlc3RvciBjYW4gbm8gbG9uZ2VyIG1pbnQgb3IgcmVjZWl2ZSBORlRzIG9uIHRoZSBtYXJrZXRwbGFjZS4KIyBBcmd1bWVudHMKKiBgY2FsbGVyYCA6IGFuIGFjY291bnQgaG9sZGluZyB0aGUgY29tcGxpYW5jZSByb2xlCiogYGludmVzdG9yYCA6IHRoZSBpbnZlc3RvciB3aG9zZSBLWUMgd2FzIGNoZWNrZWQKKiBgdGllcmAgOiB0aGUgdGllciBzZXR0aW5nIHRoZSBtYXhpbXVtIGhvbGRpbmcgb2YgdGhlIGludmVzdG9yCiogYGt5Y19leHBpcmVzX2F0YCA6IGxlZGdlciB0aW1lc3RhbXAgZnJvbSB3aGljaCB0aGUgS1lDIGhhcyB0byBiZSByZW5ld2VkAAAAEHNldF9pbnZlc3Rvcl9reWMAAAAEAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGludmVzdG9yAAAAEwAAAAAAAAAEdGllcgAAB9AAAAAMSW52ZXN0b3JUaWVyAAAAAAAAAA5reWNfZXhwaXJlc19hdAAAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAfQAAAAD0NvbXBsaWFuY2VFcnJvcgA=",
+        "AAAAAAAAAWVTZXRzIHRoZSBsb2NrLXVwIG9mIGFuIGludmVzdG9yLCBhcHBsaWVkIGZyb20gZWFjaCBvZiB0aGVpciBtaW50cyBvbiB0b3Agb2YgdGhlIG9mZmVyaW5nIGxvY2stdXAuClRoZWlyIE5GVHMgY2Fubm90IGJlIHNvbGQgb3IgdHJhbnNmZXJyZWQgdW50aWwgYHBlcmlvZGAgc2Vjb25kcyBhZnRlciB0aGVpciBsYXRlc3QgbWludC4KIyBBcmd1bWVudHMKKiBgY2FsbGVyYCA6IGFuIGFjY291bnQgaG9sZGluZyB0aGUgY29tcGxpYW5jZSByb2xlCiogYGludmVzdG9yYCA6IHRoZSBpbnZlc3RvciB0byBsb2NrIHVwCiogYHBlcmlvZGAgOiBsb2NrLXVwIGluIHNlY29uZHMsIGAwYCB0byBzdG9wIGxvY2tpbmcgdXAgbGF0ZXIgbWludHMAAAAAAAAUc2V0X2ludmVzdG9yX2xvY2tfdXAAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGludmVzdG9yAAAAEwAAAAAAAAAGcGVyaW9kAAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAPQ29tcGxpYW5jZUVycm9yAA==",
+        "AAAAAAAAAGZTZXRzIHRoZSBtYXhpbXVtIG51bWJlciBvZiBORlRzIGFuIGludmVzdG9yIG9mIGEgdGllciBjYW4gaG9sZC4gT25seSB0aGUgYWRtaW4gY2FuIGNhbGwgdGhpcyBmdW5jdGlvbi4AAAAAAAxzZXRfdGllcl9jYXAAAAACAAAAAAAAAAR0aWVyAAAH0AAAAAxJbnZlc3RvclRpZXIAAAAAAAAACG1heF9uZnRzAAAABAAAAAA=",
+        "AAAAAAAAAS9GcmVlemVzIGFuIGludmVzdG9yLCBmb3IgaW5zdGFuY2UgZm9yIEFNTCByZWFzb25zLgpBIGZyb3plbiBpbnZlc3RvciBjYW5ub3QgbWludCwgdHJhZGUsIGNsYWltLCByZWZ1bmQgb3IgcmVkZWVtLCBhbmQgdGhlIFJPSSByZWxlYXNlZCB0byB0aGVtCmlzIGhlbGQgaW4gZXNjcm93IGJ5IHRoZSBjb250cmFjdCB1bnRpbCB0aGV5IGFyZSB1bmZyb3plbi4KIyBBcmd1bWVudHMKKiBgY2FsbGVyYCA6IGFuIGFjY291bnQgaG9sZGluZyB0aGUgY29tcGxpYW5jZSByb2xlCiogYGludmVzdG9yYCA6IHRoZSBpbnZlc3RvciB0byBmcmVlemUAAAAAD2ZyZWV6ZV9pbnZlc3RvcgAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGludmVzdG9yAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAAD0NvbXBsaWFuY2VFcnJvcgA=",
+        "AAAAAAAAAQRVbmZyZWV6ZXMgYW4gaW52ZXN0b3IgYW5kIHJlbGVhc2VzIHRoZSBST0kgaGVsZCBpbiBlc2Nyb3cgd2hpbGUgdGhleSB3ZXJlIGZyb3plbiB0byB0aGVpciBjbGFpbWFibGUgYW1vdW50LgpBbHNvIHdvcmtzIGZvciBhbiBpbnZlc3RvciByZW1vdmVkIHdoaWxlIGZyb3plbi4KIyBBcmd1bWVudHMKKiBgY2FsbGVyYCA6IGFuIGFjY291bnQgaG9sZGluZyB0aGUgY29tcGxpYW5jZSByb2xlCiogYGludmVzdG9yYCA6IHRoZSBpbnZlc3RvciB0byB1bmZyZWV6ZQAAABF1bmZyZWV6ZV9pbnZlc3RvcgAAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAIaW52ZXN0b3IAAAATAAAAAQAAA+kAAAALAAAH0AAAAA9Db21wbGlhbmNlRXJyb3IA",
+        "AAAAAAAAAmpSZW1vdmVzIGFuIGludmVzdG9yIGZyb20gdGhlIHJlZ2lzdHJ5IHNvIHRoZXkgbm8gbG9uZ2VyIHRha2UgcGFydCBpbiBkaXN0cmlidXRpb25zLgpUaGUgaW52ZXN0b3IgbXVzdCBub3QgaG9sZCBhbnkgTkZUIG9uY2UgYHRva2VuX2lkc2AgaGF2ZSBiZWVuIG1vdmVkIHRvIGBjdXN0b2RpYW5gLgpUaGVpciBjbGFpbWVkLCBjbGFpbWFibGUgYW5kIGVzY3Jvd2VkIGFtb3VudHMgYXJlIGtlcHQ6IGEgZnJvemVuIGludmVzdG9yIHN0YXlzIGZyb3plbi4KRmFpbHMgd2l0aCBgQ29tcGxpYW5jZUVycm9yOjpSZWxlYXNlUGVuZGluZ2Agd2hpbGUgYSBzdGFnZSBpcyBkdWUgYnV0IG5vdCByZWxlYXNlZCB5ZXQuCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgOiBhbiBhY2NvdW50IGhvbGRpbmcgdGhlIGNvbXBsaWFuY2Ugcm9sZQoqIGBpbnZlc3RvcmAgOiB0aGUgaW52ZXN0b3IgdG8gcmVtb3ZlCiogYGN1c3RvZGlhbmAgOiBhIHJlZ2lzdGVyZWQgaW52ZXN0b3IgcmVjZWl2aW5nIGB0b2tlbl9pZHNgLCByZXF1aXJlZCB3aGVuIHRoZSBpbnZlc3RvciBzdGlsbCBob2xkcyBORlRzCiogYHRva2VuX2lkc2AgOiB0aGUgdG9rZW5zIG9mIHRoZSBpbnZlc3RvciB0byBtb3ZlIHRvIHRoZSBjdXN0b2RpYW4AAAAAAA9yZW1vdmVfaW52ZXN0b3IAAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAACWN1c3RvZGlhbgAAAAAAA+gAAAATAAAAAAAAAAl0b2tlbl9pZHMAAAAAAAPqAAAABAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAAD0NvbXBsaWFuY2VFcnJvcgA=",
+        "AAAAAAAAAClNaW50cyBhIG5ldyBORlQgdG8gdGhlIHNwZWNpZmllZCBhZGRyZXNzLgAAAAAAAARtaW50AAAAAgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABmFtb3VudAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAAKFTdGFydCB0aGUgY2hyb25vbWV0ZXIgZm9yIFJPSSBkaXN0cmlidXRpb24KVGhlIGFkbWluIGNhbiBzdGFydCBpdCBhdCBhbnkgdGltZSwgYW55b25lIGVsc2UgY2FuIHN0YXJ0IGl0IG9uY2UgdGhlIHNhbGUgd2luZG93IGlzIGNsb3NlZCBvciB0aGUgc3VwcGx5IGlzIHNvbGQgb3V0LgAAAAAAABFzdGFydF9jaHJvbm9tZXRlcgAAAAAAAAAAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAMVDYW5jZWxzIHRoZSBvZmZlcmluZyB3aGVuIHRoZSBzb2Z0IGNhcCB3YXMgbm90IHJlYWNoZWQgYnkgdGhlIHJlZnVuZCBkZWFkbGluZS4KQW55b25lIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24gb25jZSB0aGUgZGVhZGxpbmUgaGFzIHBhc3NlZCwgaW52ZXN0b3JzIHRoZW4gZ2V0IHRoZWlyIHN0YWJsZWNvaW4gYmFjayB0aHJvdWdoIGByZWZ1bmRgLgAAAAAAAA9jYW5jZWxfb2ZmZXJpbmcAAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAAPRSZWZ1bmRzIGFuIGludmVzdG9yIG9mIGEgY2FuY2VsbGVkIG9mZmVyaW5nLgpUaGUgaW52ZXN0b3IgZ2V0cyBiYWNrIGV2ZXJ5dGhpbmcgcGFpZCBkdXJpbmcgdGhlIGJ1eWluZyBwaGFzZSBhbmQgYWxsIHRoZWlyIE5GVHMgYXJlIGJ1cm5lZC4KIyBBcmd1bWVudHMKKiBgaW52ZXN0b3JgIDogdGhlIGludmVzdG9yIHRvIHJlZnVuZAoqIGB0b2tlbl9pZHNgIDogYWxsIHRoZSB0b2tlbiBpZHMgb3duZWQgYnkgdGhlIGludmVzdG9yAAAABnJlZnVuZAAAAAAAAgAAAAAAAAAIaW52ZXN0b3IAAAATAAAAAAAAAAl0b2tlbl9pZHMAAAAAAAPqAAAABAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAATNDYWxjdWxhdGUgYW1vdW50IHRvIHJlbGVhc2UgZm9yIGEgZ2l2ZW4gcGVyY2VudGFnZQpGdW5jdGlvbiB0byBrbm93IGhvdyBtdWNoIHRvIGFwcHJvdmUoKSBvbiB0aGUgU1RBQkxFQ09JTiBzbWFydCBjb250cmFjdCBiZWZvcmUgcmVsZWFzaW5nIHRoZSBhbW91bnQgdG8gYWxsIGludmVzdG9ycy4KQXJndW1lbnRzOgoqIGBwZXJjZW50YWdlYDogdGhlIHBlcmNlbnRhZ2Ugb2YgUk9JIHRvIGJlIHJlbGVhc2VkIGZvciB0aGUgY3VycmVudCBzdGFnZS4oU2NhbGVkIGJ5IDEwXzAwMF8wMDAgdG8gaGFuZGxlIGRlY2ltYWwgcGVyY2VudGFnZXMpAAAAABtjYWxjdWxhdGVfYW1vdW50X3RvX3JlbGVhc2UAAAAAAQAAAAAAAAAHcGVyY2VudAAAAAALAAAAAQAAA+kAAAALAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAL5SZWxlYXNlcyB0aGUgZGlzdHJpYnV0aW9uIGZvciB0aGUgY3VycmVudCBzdGFnZS4KVGhpcyBmdW5jdGlvbiBuZWVkcyB0byBiZSBjYWxsZWQgYnkgdGhlIHRyZWFzdXJ5IGF0IHRoZSBlbmQgb2YgZXZlcnkgZGlzdHJpYnV0aW9uIHBlcmlvZC9zdGFnZSB0byB0cmlnZ2VyIHRoZSBjdXJyZW50IHJlbGVhc2UgYW5kIG5leHQgc3RhZ2UuAAAAAAAUcmVsZWFzZV9kaXN0cmlidXRpb24AAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAaRSZWxlYXNlcyB0aGUgbmV4dCByZWFkeSBzdGFnZSBmb3IgYXQgbW9zdCBgbGltaXRgIGludmVzdG9ycy4KUHJvZ3Jlc3MgaXMgcGVyc2lzdGVkIGJldHdlZW4gY2FsbHMgc28gbGFyZ2UgaW52ZXN0b3Igc2V0cyBjYW4gYmUgcHJvY2Vzc2VkIG92ZXIgc2V2ZXJhbCB0cmFuc2FjdGlvbnMsCnRoZSBzdGFnZSBpcyBmdW5kZWQgYW5kIHRoZSBzdGF0ZSBhZHZhbmNlZCBvbmx5IHdoZW4gdGhlIGxhc3QgcGFnZSBjb21wbGV0ZXMuClJldHVybnMgdHJ1ZSBvbmNlIHRoZSBzdGFnZSBoYXMgYmVlbiBmdWxseSByZWxlYXNlZC4KIyBBcmd1bWVudHMKKiBgY2FsbGVyYCA6IGFuIGFjY291bnQgaG9sZGluZyB0aGUgdHJlYXN1cnkgcm9sZQoqIGBsaW1pdGAgOiBtYXhpbXVtIG51bWJlciBvZiBpbnZlc3RvcnMgdG8gcHJvY2VzcyBpbiB0aGlzIGNhbGwAAAAacmVsZWFzZV9kaXN0cmlidXRpb25fYmF0Y2gAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+kAAAABAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAASRDbGFpbXMgYWxsIHRoZSBST0kgcmVsZWFzZWQgdG8gYW4gaW52ZXN0b3IgYWNyb3NzIGNvbXBsZXRlZCBzdGFnZXMuClNlbmRzIHRoZSB3aG9sZSBjbGFpbWFibGUgYW1vdW50IHRvIHRoZSBpbnZlc3RvciBhbmQgYWRkcyBpdCB0byB0aGVpciBjbGFpbWVkIGFtb3VudC4KRmFpbHMgd2hpbGUgYSBzdGFnZSBpcyByZWxlYXNlZCBwYWdlIGJ5IHBhZ2Ugd2l0aCBgcmVsZWFzZV9kaXN0cmlidXRpb25fYmF0Y2hgLgojIEFyZ3VtZW50cwoqIGBpbnZlc3RvcmAgOiB0aGUgaW52ZXN0b3IgY2xhaW1pbmcgdGhlaXIgUk9JAAAABWNsYWltAAAAAAAAAQAAAAAAAAAIaW52ZXN0b3IAAAATAAAAAQAAA+kAAAALAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAM5TZW5kcyB0aGUgZGlzdHJpYnV0aW9uIGR1c3QgKHJvdW5kaW5nIHJlbWFpbmRlcnMga2VwdCBieSB0aGUgY29udHJhY3QpIHRvIGFuIGFkZHJlc3MuCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgOiBhbiBhY2NvdW50IGhvbGRpbmcgdGhlIHRyZWFzdXJ5IHJvbGUKKiBgdG9gIDogdGhlIGFkZHJlc3MgcmVjZWl2aW5nIHRoZSBkdXN0LCB1c3VhbGx5IHRoZSBwYXllcgAAAAAACnN3ZWVwX2R1c3QAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAACdG8AAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAMxSb2xscyB0aGUgZGlzdHJpYnV0aW9uIGR1c3QgaW50byB0aGUgbmV4dCBzdGFnZS4KVGhlIGR1c3QgaXMgdXNlZCB0byBmdW5kIHRoZSBuZXh0IHJlbGVhc2VkIHN0YWdlLCBzbyB0aGUgcGF5ZXIgaXMgY2hhcmdlZCB0aGF0IG11Y2ggbGVzcy4KT25seSBhbiBhY2NvdW50IGhvbGRpbmcgdGhlIHRyZWFzdXJ5IHJvbGUgY2FuIGNhbGwgdGhpcyBmdW5jdGlvbi4AAAAJcm9sbF9kdXN0AAAAAAAAAQAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAAUpGdW5kcyB0aGUgcHJpbmNpcGFsIHJlZGVtcHRpb24gYXQgdGhlIGVuZCBvZiB0aGUgdGVybS4KUHVsbHMgYHByaWNlICogU1RBQkxFQ09JTl9TQ0FMRWAgcGVyIE5GVCBoZWxkIGJ5IGludmVzdG9ycyBmcm9tIHRoZSBwYXllciBpbnRvIHRoZSBjb250cmFjdCBzbyBob2xkZXJzIGNhbiBgcmVkZWVtYCB0aGVtLgpPbmx5IGFuIGFjY291bnQgaG9sZGluZyB0aGUgdHJlYXN1cnkgcm9sZSBjYW4gY2FsbCB0aGlzIGZ1bmN0aW9uLgpOT1RFOiBUaGUgcGF5ZXIgbXVzdCBoYXZlIGFwcHJvdmVkIHRoZSBjb250cmFjdCB0byBzcGVuZCB0aGUgc3RhYmxlY29pbiBvbiB0aGVpciBiZWhhbGYAAAAAAA9mdW5kX3JlZGVtcHRpb24AAAAAAQAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAARFSZWRlZW1zIE5GVHMgZm9yIHRoZWlyIHByaW5jaXBhbCBvbmNlIHRoZSB0ZXJtIGhhcyBlbmRlZCBhbmQgdGhlIHJlZGVtcHRpb24gaGFzIGJlZW4gZnVuZGVkLgpUaGUgTkZUcyBhcmUgYnVybmVkIGFuZCB0aGUgaG9sZGVyIHJlY2VpdmVzIGBwcmljZSAqIFNUQUJMRUNPSU5fU0NBTEVgIHBlciBORlQuCiMgQXJndW1lbnRzCiogYGhvbGRlcmAgOiB0aGUgTkZUIGhvbGRlciByZWRlZW1pbmcgdGhlIHRva2VucwoqIGB0b2tlbl9pZHNgIDogdGhlIHRva2VuIGlkcyB0byByZWRlZW0AAAAAAAAGcmVkZWVtAAAAAAACAAAAAAAAAAZob2xkZXIAAAAAABMAAAAAAAAACXRva2VuX2lkcwAAAAAAA+oAAAAEAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAAJRQYXVzZXMgYSBzaW5nbGUgZW50cnkgcG9pbnQuIE9ubHkgYW4gYWNjb3VudCBob2xkaW5nIHRoZSBwYXVzZXIgcm9sZSBjYW4gY2FsbCB0aGlzIGZ1bmN0aW9uLgpVc2UgYHBhdXNlYCB0byBzdG9wIGV2ZXJ5IHBhdXNhYmxlIGVudHJ5IHBvaW50IGF0IG9uY2UuAAAADnBhdXNlX2Z1bmN0aW9uAAAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGZ1bmN0aW9uAAAH0AAAABBQYXVzYWJsZUZ1bmN0aW9uAAAAAA==",
+        "AAAAAAAAALdVbnBhdXNlcyBhbiBlbnRyeSBwb2ludCBwYXVzZWQgd2l0aCBgcGF1c2VfZnVuY3Rpb25gLiBPbmx5IGFuIGFjY291bnQgaG9sZGluZyB0aGUgcGF1c2VyIHJvbGUgY2FuIGNhbGwgdGhpcyBmdW5jdGlvbi4KVGhlIGVudHJ5IHBvaW50IHN0YXlzIGJsb2NrZWQgd2hpbGUgdGhlIHdob2xlIGNvbnRyYWN0IGlzIHBhdXNlZC4AAAAAEHVucGF1c2VfZnVuY3Rpb24AAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACGZ1bmN0aW9uAAAH0AAAABBQYXVzYWJsZUZ1bmN0aW9uAAAAAA==",
+        "AAAAAAAAAQ5SZXBsYWNlcyB0aGUgY29udHJhY3QgY29kZSwga2VlcGluZyBpdHMgYWRkcmVzcyBhbmQgc3RvcmFnZS4gT25seSB0aGUgY29udHJhY3Qgb3duZXIgY2FuIGNhbGwgdGhpcyBmdW5jdGlvbi4KQ2FsbCBgbWlncmF0ZWAgYWZ0ZXJ3YXJkcyB3aGVuIHRoZSBuZXcgcmVsZWFzZSBjaGFuZ2VzIHRoZSBzdG9yYWdlIGxheW91dC4KIyBBcmd1bWVudHMKKiBgbmV3X3dhc21faGFzaGAgOiBoYXNoIG9mIHRoZSBuZXcgV0FTTSwgYWxyZWFkeSBpbnN0YWxsZWQgb24gdGhlIG5ldHdvcmsAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAASVCcmluZ3MgdGhlIHN0b3JhZ2UgbGF5b3V0IHVwIHRvIHRoZSB2ZXJzaW9uIG9mIHRoZSBydW5uaW5nIGNvZGUuIE9ubHkgdGhlIGNvbnRyYWN0IG93bmVyIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24uClJlbGVhc2VzIHRoYXQgY2hhbmdlIHRoZSBsYXlvdXQgYnVtcCBgU1RPUkFHRV9WRVJTSU9OYCBhbmQgYWRkIGEgc3RlcCBoZXJlLCBzbyBhIGRlcGxveW1lbnQgY2FuIHNraXAgcmVsZWFzZXMuCkNhbGxpbmcgaXQgb25jZSB1cCB0byBkYXRlIGRvZXMgbm90aGluZy4gUmV0dXJucyB0aGUgc3RvcmFnZSB2ZXJzaW9uLgAAAAAAAAdtaWdyYXRlAAAAAAAAAAABAAAD6QAAAAQAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAACJUaWVyIGFuZCBLWUMgZXhwaXJ5IG9mIGFuIGludmVzdG9yAAAAAAAUZ2V0X2ludmVzdG9yX3Byb2ZpbGUAAAABAAAAAAAAAAhpbnZlc3RvcgAAABMAAAABAAAH0AAAAA9JbnZlc3RvclByb2ZpbGUA",
+        "AAAAAAAAADlMb2NrLXVwIGFwcGxpZWQgZnJvbSBlYWNoIG1pbnQgb2YgYW4gaW52ZXN0b3IsIGluIHNlY29uZHMAAAAAAAAUZ2V0X2ludmVzdG9yX2xvY2tfdXAAAAABAAAAAAAAAAhpbnZlc3RvcgAAABMAAAABAAAABg==",
+        "AAAAAAAAAEJTZWNvbmRzIGFmdGVyIHRoZSBiZWdpbiBkYXRlIGR1cmluZyB3aGljaCBORlRzIGNhbm5vdCBjaGFuZ2UgaGFuZHMAAAAAABJnZXRfbG9ja191cF9wZXJpb2QAAAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAKpUaW1lc3RhbXAgZnJvbSB3aGljaCBhbiBhY2NvdW50IGNhbiBzZWxsIG9yIHRyYW5zZmVyIGl0cyBORlRzOiB0aGUgZW5kIG9mIHRoZSBvZmZlcmluZyBsb2NrLXVwLApvciBvZiBpdHMgb3duIGxvY2stdXAgd2hlbiBsYXRlci4gYHU2NDo6TUFYYCB1bnRpbCB0aGUgY2hyb25vbWV0ZXIgc3RhcnRzLgAAAAAAEnRyYW5zZmVyYWJsZV9hZnRlcgAAAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAY=",
+        "AAAAAAAAADVNYXhpbXVtIG51bWJlciBvZiBORlRzIGFuIGludmVzdG9yIG9mIGEgdGllciBjYW4gaG9sZAAAAAAAAAxnZXRfdGllcl9jYXAAAAABAAAAAAAAAAR0aWVyAAAH0AAAAAxJbnZlc3RvclRpZXIAAAABAAAD6QAAAAQAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAAFxXaGV0aGVyIGFuIGVudHJ5IHBvaW50IGlzIGJsb2NrZWQsIGVpdGhlciBvbiBpdHMgb3duIG9yIGJlY2F1c2UgdGhlIHdob2xlIGNvbnRyYWN0IGlzIHBhdXNlZAAAABJpc19mdW5jdGlvbl9wYXVzZWQAAAAAAAEAAAAAAAAACGZ1bmN0aW9uAAAH0AAAABBQYXVzYWJsZUZ1bmN0aW9uAAAAAQAAAAE=",
+        "AAAAAAAAAD9TZWNvbmRzIGJldHdlZW4gcHJvcG9zaW5nIGEgY2hhbmdlIGFuZCBiZWluZyBhYmxlIHRvIGV4ZWN1dGUgaXQAAAAAEGdldF9jaGFuZ2VfZGVsYXkAAAAAAAAAAQAAAAY=",
+        "AAAAAAAAADlUaGUgY2hhbmdlIHdhaXRpbmcgdG8gYmUgZXhlY3V0ZWQgZm9yIGEgcGFyYW1ldGVyLCBpZiBhbnkAAAAAAAASZ2V0X3BlbmRpbmdfY2hhbmdlAAAAAAABAAAAAAAAAAlwYXJhbWV0ZXIAAAAAAAfQAAAAD0NvbmZpZ1BhcmFtZXRlcgAAAAABAAAD6AAAB9AAAAANUGVuZGluZ0NoYW5nZQAAAA==",
+        "AAAAAAAAAChQYXJhbWV0ZXJzIHRoYXQgY2FuIG5vIGxvbmdlciBiZSBjaGFuZ2VkAAAAFWdldF9sb2NrZWRfcGFyYW1ldGVycwAAAAAAAAAAAAABAAAD6gAAB9AAAAAPQ29uZmlnUGFyYW1ldGVyAA==",
+        "AAAAAAAAAFRWZXJzaW9uIG9mIHRoZSBzdG9yYWdlIGxheW91dCwgZGVwbG95bWVudHMgbWFkZSBiZWZvcmUgaXQgd2FzIHJlY29yZGVkIGFyZSB2ZXJzaW9uIDEAAAATZ2V0X3N0b3JhZ2VfdmVyc2lvbgAAAAAAAAAAAQAAAAQ=",
         "AAAAAAAAACJDaGVjayBpZiBhbiBhZGRyZXNzIGlzIGFuIGludmVzdG9yAAAAAAALaXNfaW52ZXN0b3IAAAAAAQAAAAAAAAAIaW52ZXN0b3IAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAB5DaGVjayBpZiBhbiBpbnZlc3RvciBpcyBmcm96ZW4AAAAAABJpc19pbnZlc3Rvcl9mcm96ZW4AAAAAAAEAAAAAAAAACGludmVzdG9yAAAAEwAAAAEAAAAB",
+        "AAAAAAAAAExST0kgcmVsZWFzZWQgdG8gYSBmcm96ZW4gaW52ZXN0b3IgYW5kIGhlbGQgaW4gZXNjcm93IHVudGlsIHRoZXkgYXJlIHVuZnJvemVuAAAAEWdldF9mcm96ZW5fYW1vdW50AAAAAAAAAQAAAAAAAAAIaW52ZXN0b3IAAAATAAAAAQAAAAs=",
         "AAAAAAAAABpHZXQgaW52ZXN0b3JzIGFycmF5IGxlbmd0aAAAAAAAGmdldF9pbnZlc3RvcnNfYXJyYXlfbGVuZ3RoAAAAAAAAAAAAAQAAAAQ=",
-        "AAAAAAAAADtSZXR1cm5zIHRoZSBhZGRyZXNzIG9mIHRoZSBzdGFibGVjb2luIHVzZWQgZm9yIGludmVzdG1lbnRzLgAAAAAOZ2V0X3N0YWJsZWNvaW4AAAAAAAAAAAABAAAAEw==",
-        "AAAAAAAAACRSZXR1cm5zIHRoZSBhZGRyZXNzIG9mIHRoZSByZWNlaXZlci4AAAAMZ2V0X3JlY2VpdmVyAAAAAAAAAAEAAAAT",
-        "AAAAAAAAACpSZXR1cm5zIHRoZSBzdGFydCB0aW1lIG9mIHRoZSBjaHJvbm9tZXRlci4AAAAAAA5nZXRfYmVnaW5fZGF0ZQAAAAAAAAAAAAEAAAAG",
-        "AAAAAAAAACxSZXR1cm5zIHdoZXRoZXIgdGhlIGNocm9ub21ldGVyIGhhcyBzdGFydGVkLgAAABZpc19jaHJvbm9tZXRlcl9zdGFydGVkAAAAAAAAAAAAAQAAAAE=",
-        "AAAAAAAAACFSZXR1cm5zIHRoZSBhZGRyZXNzIG9mIHRoZSBwYXllci4AAAAAAAAJZ2V0X3BheWVyAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAACJHZXQgY2xhaW1lZCBhbW91bnQgZm9yIGFuIGludmVzdG9yAAAAAAASc2VlX2NsYWltZWRfYW1vdW50AAAAAAABAAAAAAAAAAhpbnZlc3RvcgAAABMAAAABAAAACw==",
+        "AAAAAAAAADtSZXR1cm5zIHRoZSBhZGRyZXNzIG9mIHRoZSBzdGFibGVjb2luIHVzZWQgZm9yIGludmVzdG1lbnRzLgAAAAAOZ2V0X3N0YWJsZWNvaW4AAAAAAAAAAAABAAAD6QAAABMAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAACRSZXR1cm5zIHRoZSBhZGRyZXNzIG9mIHRoZSByZWNlaXZlci4AAAAMZ2V0X3JlY2VpdmVyAAAAAAAAAAEAAAPpAAAAEwAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAACpSZXR1cm5zIHRoZSBzdGFydCB0aW1lIG9mIHRoZSBjaHJvbm9tZXRlci4AAAAAAA5nZXRfYmVnaW5fZGF0ZQAAAAAAAAAAAAEAAAPpAAAABgAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAACxSZXR1cm5zIHdoZXRoZXIgdGhlIGNocm9ub21ldGVyIGhhcyBzdGFydGVkLgAAABZpc19jaHJvbm9tZXRlcl9zdGFydGVkAAAAAAAAAAAAAQAAA+kAAAABAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAACFSZXR1cm5zIHRoZSBhZGRyZXNzIG9mIHRoZSBwYXllci4AAAAAAAAJZ2V0X3BheWVyAAAAAAAAAAAAAAEAAAPpAAAAEwAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAADNHZXQgUk9JIHJlbGVhc2VkIHRvIGFuIGludmVzdG9yIGFuZCBub3QgY2xhaW1lZCB5ZXQAAAAAFHNlZV9jbGFpbWFibGVfYW1vdW50AAAAAQAAAAAAAAAIaW52ZXN0b3IAAAATAAAAAQAAAAs=",
+        "AAAAAAAAADhHZXQgdGhlIHJvdW5kaW5nIHJlbWFpbmRlciByZWNvcmRlZCBmb3IgYSByZWxlYXNlZCBzdGFnZQAAABNnZXRfc3RhZ2VfcmVtYWluZGVyAAAAAAEAAAAAAAAABXN0YWdlAAAAAAAABAAAAAEAAAAL",
+        "AAAAAAAAAEpHZXQgdGhlIGRpc3RyaWJ1dGlvbiBkdXN0IGhlbGQgYnkgdGhlIGNvbnRyYWN0IHRoYXQgY2FuIGJlIHN3ZXB0IG9yIHJvbGxlZAAAAAAAEGdldF9kdXN0X2JhbGFuY2UAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAC9HZXQgdGhlIGR1c3Qgcm9sbGVkIGludG8gdGhlIG5leHQgc3RhZ2UgZnVuZGluZwAAAAAPZ2V0X3JvbGxlZF9kdXN0AAAAAAAAAAABAAAACw==",
+        "AAAAAAAAAEdHZXQgdGhlIFJPSSBhbW91bnQgcGVyIE5GVCAocm91bmRlZCBkb3duKSByZWNvcmRlZCBmb3IgYSByZWxlYXNlZCBzdGFnZQAAAAAYZ2V0X3N0YWdlX2Ftb3VudF9wZXJfbmZ0AAAAAQAAAAAAAAAFc3RhZ2UAAAAAAAAEAAAAAQAAAAs=",
+        "AAAAAAAAAG9HZXQgdGhlIGJhbGFuY2Ugb2YgYW4gaW52ZXN0b3IgYXQgdGhlIGR1ZSBkYXRlIG9mIGEgc3RhZ2UuCkZvciBhIHN0YWdlIG5vdCBkdWUgeWV0IHRoaXMgaXMgdGhlIGN1cnJlbnQgYmFsYW5jZS4AAAAAEGJhbGFuY2VfYXRfc3RhZ2UAAAACAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABXN0YWdlAAAAAAAABAAAAAEAAAAE",
+        "AAAAAAAAAEFHZXQgdGhlIHByb2dyZXNzIG9mIHRoZSBzdGFnZSBiZWluZyByZWxlYXNlZCBwYWdlIGJ5IHBhZ2UsIGlmIGFueQAAAAAAABlnZXRfZGlzdHJpYnV0aW9uX3Byb2dyZXNzAAAAAAAAAAAAAAEAAAPoAAAH0AAAABREaXN0cmlidXRpb25Qcm9ncmVzcw==",
+        "AAAAAAAAAEVHZXQgY2xhaW1lZCBhbW91bnQgZm9yIGFuIGludmVzdG9yIChST0kgYWxyZWFkeSBzZW50IHRvIHRoZSBpbnZlc3RvcikAAAAAAAASc2VlX2NsYWltZWRfYW1vdW50AAAAAAABAAAAAAAAAAhpbnZlc3RvcgAAABMAAAABAAAACw==",
+        "AAAAAAAAAC1HZXQgcmVkZWVtZWQgcHJpbmNpcGFsIGFtb3VudCBmb3IgYW4gaW52ZXN0b3IAAAAAAAATc2VlX3JlZGVlbWVkX2Ftb3VudAAAAAABAAAAAAAAAAhpbnZlc3RvcgAAABMAAAABAAAACw==",
+        "AAAAAAAAAEVSZXR1cm5zIHdoZXRoZXIgdGhlIHByaW5jaXBhbCByZWRlbXB0aW9uIGhhcyBiZWVuIGZ1bmRlZCBieSB0aGUgcGF5ZXIAAAAAAAAUaXNfcmVkZW1wdGlvbl9mdW5kZWQAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAACpHZXQgdGhlIG51bWJlciBvZiBORlRzIHJlZGVlbWVkIGFuZCBidXJuZWQAAAAAABNnZXRfcmVkZWVtZWRfc3VwcGx5AAAAAAAAAAABAAAABA==",
         "AAAAAAAAABJHZXQgY3VycmVudCBzdXBwbHkAAAAAABJnZXRfY3VycmVudF9zdXBwbHkAAAAAAAAAAAABAAAABA==",
-        "AAAAAAAAABFHZXQgY3VycmVudCBzdGF0ZQAAAAAAABFnZXRfY3VycmVudF9zdGF0ZQAAAAAAAAAAAAABAAAH0AAAABBJbnZlc3RtZW50U3RhdHVz",
-        "AAAAAAAAAA1HZXQgTkZUIFBSSUNFAAAAAAAADWdldF9uZnRfcHJpY2UAAAAAAAAAAAAAAQAAAAs=",
-        "AAAAAAAAABBHZXQgdG90YWwgc3VwcGx5AAAAEGdldF90b3RhbF9zdXBwbHkAAAAAAAAAAQAAAAQ=",
-        "AAAAAAAAABhHZXQgbWluaW11bSBuZnRzIHRvIG1pbnQAAAAUZ2V0X21pbl9uZnRzX3RvX21pbnQAAAAAAAAAAQAAAAQ=",
-        "AAAAAAAAAB1HZXQgbWF4aW11bSBuZnRzIHBlciBpbnZlc3RvcgAAAAAAABlnZXRfbWF4X25mdHNfcGVyX2ludmVzdG9yAAAAAAAAAAAAAAEAAAAE",
-        "AAAAAAAAABtHZXQgbmZ0IGJ1eWluZyBwaGFzZSBzdXBwbHkAAAAAG2dldF9uZnRfYnV5aW5nX3BoYXNlX3N1cHBseQAAAAAAAAAAAQAAAAQ=",
-        "AAAAAAAAACBHZXQgZGlzdHJpYnV0aW9uIGludGVydmFscyBhcnJheQAAABpnZXRfZGlzdHJpYnV0aW9uX2ludGVydmFscwAAAAAAAAAAAAEAAAPqAAAABg==",
-        "AAAAAAAAABlHZXQgUk9JIHBlcmNlbnRhZ2VzIGFycmF5AAAAAAAAE2dldF9yb2lfcGVyY2VudGFnZXMAAAAAAAAAAAEAAAPqAAAACw==",
-        "AAAAAAAAABtHZXQgYnV5aW5nIHBoYXNlIG5mdCBzdXBwbHkAAAAAG2dldF9idXlpbmdfcGhhc2VfbmZ0X3N1cHBseQAAAAAAAAAAAQAAAAQ=",
-        "AAAAAAAAAAAAAAAKYnV5X3Rva2VucwAAAAAAAwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAl0b2tlbl9pZHMAAAAAAAPqAAAABAAAAAA=",
-        "AAAAAAAAAAAAAAALc2VsbF90b2tlbnMAAAAAAwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAl0b2tlbl9pZHMAAAAAAAPqAAAABAAAAAA=",
-        "AAAAAAAAAExTZXRzIGEgbmV3IHJlY2VpdmVyIGFkZHJlc3MuIE9ubHkgdGhlIGNvbnRyYWN0IG93bmVyIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24uAAAADHNldF9yZWNlaXZlcgAAAAEAAAAAAAAACHJlY2VpdmVyAAAAEwAAAAA=",
-        "AAAAAAAAAElTZXRzIGEgbmV3IHBheWVyIGFkZHJlc3MuIE9ubHkgdGhlIGNvbnRyYWN0IG93bmVyIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24uAAAAAAAACXNldF9wYXllcgAAAAAAAAEAAAAAAAAABXBheWVyAAAAAAAAEwAAAAA=",
+        "AAAAAAAAABFHZXQgY3VycmVudCBzdGF0ZQAAAAAAABFnZXRfY3VycmVudF9zdGF0ZQAAAAAAAAAAAAABAAAD6QAAB9AAAAAQSW52ZXN0bWVudFN0YXR1cwAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAAA1HZXQgTkZUIFBSSUNFAAAAAAAADWdldF9uZnRfcHJpY2UAAAAAAAAAAAAAAQAAA+kAAAALAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAABBHZXQgdG90YWwgc3VwcGx5AAAAEGdldF90b3RhbF9zdXBwbHkAAAAAAAAAAQAAA+kAAAAEAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAABhHZXQgbWluaW11bSBuZnRzIHRvIG1pbnQAAAAUZ2V0X21pbl9uZnRzX3RvX21pbnQAAAAAAAAAAQAAA+kAAAAEAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAB1HZXQgbWF4aW11bSBuZnRzIHBlciBpbnZlc3RvcgAAAAAAABlnZXRfbWF4X25mdHNfcGVyX2ludmVzdG9yAAAAAAAAAAAAAAEAAAPpAAAABAAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAABtHZXQgbmZ0IGJ1eWluZyBwaGFzZSBzdXBwbHkAAAAAG2dldF9uZnRfYnV5aW5nX3BoYXNlX3N1cHBseQAAAAAAAAAAAQAAA+kAAAAEAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAACBHZXQgZGlzdHJpYnV0aW9uIGludGVydmFscyBhcnJheQAAABpnZXRfZGlzdHJpYnV0aW9uX2ludGVydmFscwAAAAAAAAAAAAEAAAPpAAAD6gAAAAYAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAABlHZXQgUk9JIHBlcmNlbnRhZ2VzIGFycmF5AAAAAAAAE2dldF9yb2lfcGVyY2VudGFnZXMAAAAAAAAAAAEAAAPpAAAD6gAAAAsAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAAD1HZXQgdGhlIG51bWJlciBvZiByZWxlYXNlIHN0YWdlcyBpbiB0aGUgZGlzdHJpYnV0aW9uIHNjaGVkdWxlAAAAAAAAD2dldF9zdGFnZV9jb3VudAAAAAAAAAAAAQAAA+kAAAAEAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAABtHZXQgYnV5aW5nIHBoYXNlIG5mdCBzdXBwbHkAAAAAG2dldF9idXlpbmdfcGhhc2VfbmZ0X3N1cHBseQAAAAAAAAAAAQAAA+kAAAAEAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAElHZXQgdGhlIHNvZnQgY2FwIChtaW5pbXVtIG51bWJlciBvZiBORlRzIHRvIHNlbGwgZHVyaW5nIHRoZSBidXlpbmcgcGhhc2UpAAAAAAAADGdldF9zb2Z0X2NhcAAAAAAAAAABAAAD6QAAAAQAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAABdHZXQgdGhlIHJlZnVuZCBkZWFkbGluZQAAAAATZ2V0X3JlZnVuZF9kZWFkbGluZQAAAAAAAAAAAQAAA+kAAAAGAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAC1SZXR1cm5zIHdoZXRoZXIgdGhlIHNvZnQgY2FwIGhhcyBiZWVuIHJlYWNoZWQAAAAAAAATaXNfc29mdF9jYXBfcmVhY2hlZAAAAAAAAAAAAQAAA+kAAAABAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAClHZXQgdGhlIHRpbWVzdGFtcCBhdCB3aGljaCB0aGUgc2FsZSBvcGVucwAAAAAAAA5nZXRfc2FsZV9zdGFydAAAAAAAAAAAAAEAAAPpAAAABgAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAACpHZXQgdGhlIHRpbWVzdGFtcCBhdCB3aGljaCB0aGUgc2FsZSBjbG9zZXMAAAAAAAxnZXRfc2FsZV9lbmQAAAAAAAAAAQAAA+kAAAAGAAAH0AAAAApNaW5haEVycm9yAAA=",
+        "AAAAAAAAAFNHZXQgdGhlIGFtb3VudCBvZiBzdGFibGVjb2luIGVzY3Jvd2VkIGluIHRoZSBjb250cmFjdCB1bnRpbCB0aGUgc29mdCBjYXAgaXMgcmVhY2hlZAAAAAATZ2V0X2VzY3Jvd2VkX2Ftb3VudAAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAEhHZXQgdGhlIGFtb3VudCBvZiBzdGFibGVjb2luIHBhaWQgYnkgYW4gaW52ZXN0b3IgZHVyaW5nIHRoZSBidXlpbmcgcGhhc2UAAAAPZ2V0X3BhaWRfYW1vdW50AAAAAAEAAAAAAAAACGludmVzdG9yAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAE1GZWUgY2hhcmdlZCB0byBidXllcnMgb24gc2Vjb25kYXJ5IHRyYWRlcywgaW4gYmFzaXMgcG9pbnRzIG9mIHRoZSB0cmFkZSBwcmljZQAAAAAAABNnZXRfdHJhZGluZ19mZWVfYnBzAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAExBZGRyZXNzIGNvbGxlY3RpbmcgdGhlIHRyYWRpbmcgZmVlcywgdGhlIHJlY2VpdmVyIHVubGVzcyBhbm90aGVyIG9uZSB3YXMgc2V0AAAAEWdldF9mZWVfcmVjaXBpZW50AAAAAAAAAAAAAAEAAAPpAAAAEwAAB9AAAAAKTWluYWhFcnJvcgAA",
+        "AAAAAAAAACVBIGxpc3Rpbmcgc3RpbGwgb3BlbiBmb3Igc2FsZSwgaWYgYW55AAAAAAAAC2dldF9saXN0aW5nAAAAAAEAAAAAAAAACmxpc3RpbmdfaWQAAAAAAAQAAAABAAAD6AAAB9AAAAAHTGlzdGluZwA=",
+        "AAAAAAAAACNUaGUgbGlzdGluZyBsb2NraW5nIGEgdG9rZW4sIGlmIGFueQAAAAARZ2V0X3Rva2VuX2xpc3RpbmcAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAQAAAABAAAD6AAAAAQ=",
+        "AAAAAAAAAD1Ub2tlbnMgaGVsZCBieSBhbiBhY2NvdW50IGFzIHNvcnRlZCByYW5nZXMgb2YgY29uc2VjdXRpdmUgaWRzAAAAAAAAEGdldF90b2tlbl9yYW5nZXMAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAD6gAAB9AAAAAKVG9rZW5SYW5nZQAA",
+        "AAAAAAAAADRPcGVuIG9mZmVyIHdpdGggdGhlIHN0YWJsZWNvaW4gc3RpbGwgZXNjcm93ZWQgZm9yIGl0AAAACWdldF9vZmZlcgAAAAAAAAEAAAAAAAAACG9mZmVyX2lkAAAABAAAAAEAAAPoAAAH0AAAAAVPZmZlcgAAAA==",
+        "AAAAAAAAADtUZXJtcyBvZiB0aGUgY29udGludW91cyBvZmZlcmluZywgYE5vbmVgIHdoaWxlIGl0IGlzIGNsb3NlZAAAAAAXZ2V0X2NvbnRpbnVvdXNfb2ZmZXJpbmcAAAAAAAAAAAEAAAPoAAAH0AAAABJDb250aW51b3VzT2ZmZXJpbmcAAA==",
+        "AAAAAAAAAERDdXJyZW50IHByaWNlIG9mIG9uZSBORlQgb2YgdGhlIG93bmVyIGludmVudG9yeSwgaW4gc3RhYmxlY29pbiB1bml0cwAAABNnZXRfaW52ZW50b3J5X3ByaWNlAAAAAAAAAAABAAAD6QAAAAsAAAfQAAAAEE1hcmtldHBsYWNlRXJyb3I=",
+        "AAAAAAAAAFxXaGV0aGVyIGBidXlfdG9rZW5zYCBhbmQgYHNlbGxfdG9rZW5zYCBzZXR0bGUgYXQgYHF1b3RlX3ByaWNlYCBpbnN0ZWFkIG9mIHRoZSBvcmlnaW5hbCBwcmljZQAAABZpc19hY2NydWVkX3JvaV9wcmljaW5nAAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAV1GYWlyIHZhbHVlIG9mIGB0b2tlbl9jb3VudGAgTkZUcyBpbiBzdGFibGVjb2luIHVuaXRzOiB0aGUgcHJpbmNpcGFsIHBsdXMgdGhlIFJPSSBhY2NydWVkLApwcm8gcmF0YSBvZiB0aGUgZWxhcHNlZCB0aW1lLCBpbiB0aGUgc3RhZ2Ugd2hvc2UgZHVlIGRhdGUgaGFzIG5vdCBwYXNzZWQgeWV0LgpBIHN0YWdlIHBhc3QgaXRzIGR1ZSBkYXRlIGlzIHBhaWQgZnJvbSB0aGUgYmFsYW5jZXMgYXQgdGhhdCBkYXRlLCBzbyBpdCBzdGF5cyB3aXRoIHRoZSBzZWxsZXIsCndoaWxlIHRoZSBidXllciByZWNlaXZlcyB0aGUgd2hvbGUgYWNjcnVpbmcgc3RhZ2UgYW5kIHBheXMgdGhlIHNlbGxlciBmb3IgdGhlaXIgc2hhcmUuAAAAAAAAC3F1b3RlX3ByaWNlAAAAAAEAAAAAAAAAC3Rva2VuX2NvdW50AAAAAAQAAAABAAAD6QAAAAsAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAAF9OdW1iZXIgb2YgdW5zb2xkIE5GVHMgc3RpbGwgaGVsZCBieSB0aGUgb3duZXIsIGFwYXJ0IGZyb20gd2hhdCB3YXMgc29sZCBkdXJpbmcgdGhlIGJ1eWluZyBwaGFzZQAAAAAUZ2V0X2ludmVudG9yeV9zdXBwbHkAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAKYnV5X3Rva2VucwAAAAAAAwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAl0b2tlbl9pZHMAAAAAAAPqAAAABAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAAeJCdXlzIGBxdWFudGl0eWAgdG9rZW5zIG9mIGBmcm9tYCB3aXRob3V0IG5hbWluZyB0aGVtLCBvbiB0aGUgdGVybXMgb2YgYGJ1eV90b2tlbnNgLgpUaGUgdG9rZW5zIGFyZSB0YWtlbiBpbiBpZCBvcmRlciBmcm9tIHRoZSB0b2tlbiBpbmRleCBvZiB0aGUgc2VsbGVyLCBza2lwcGluZyBsaXN0ZWQgb25lcy4KUmV0dXJucyB0aGUgaWRzIG9mIHRoZSB0b2tlbnMgYm91Z2h0LgojIEFyZ3VtZW50cwoqIGBmcm9tYCA6IHRoZSBzZWxsZXIsIG11c3QgaGF2ZSBhcHByb3ZlZCB0aGUgY29udHJhY3QgZm9yIGFsbCBpdHMgdG9rZW5zCiogYHRvYCA6IHRoZSBidXllciwgbXVzdCBoYXZlIGFwcHJvdmVkIHRoZSBjb250cmFjdCBmb3IgdGhlIHRvdGFsIHByaWNlIGFuZCB0aGUgZmVlCiogYHF1YW50aXR5YCA6IG51bWJlciBvZiB0b2tlbnMgdG8gYnV5CiogYG1heF91bml0X3ByaWNlYCA6IGhpZ2hlc3QgcHJpY2UgcGVyIE5GVCB0aGUgYnV5ZXIgYWNjZXB0cwAAAAAADGJ1eV9xdWFudGl0eQAAAAQAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAIcXVhbnRpdHkAAAAEAAAAAAAAAA5tYXhfdW5pdF9wcmljZQAAAAAACwAAAAEAAAPpAAAD6gAAAAQAAAfQAAAAEE1hcmtldHBsYWNlRXJyb3I=",
+        "AAAAAAAAAAAAAAALc2VsbF90b2tlbnMAAAAAAwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAl0b2tlbl9pZHMAAAAAAAPqAAAABAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAACk1pbmFoRXJyb3IAAA==",
+        "AAAAAAAAAcRMaXN0cyB0b2tlbnMgZm9yIHNhbGUgYXQgYSBwcmljZSBjaG9zZW4gYnkgdGhlIHNlbGxlci4KVGhlIHRva2VucyBzdGF5IHdpdGggdGhlIHNlbGxlciwgYW5kIGtlZXAgZWFybmluZyBST0ksIGJ1dCBhcmUgbG9ja2VkIHVudGlsIHNvbGQgb3IgdGhlIGxpc3RpbmcgaXMgY2FuY2VsbGVkLgpSZXR1cm5zIHRoZSBpZCBvZiB0aGUgbGlzdGluZy4KIyBBcmd1bWVudHMKKiBgc2VsbGVyYCA6IGFuIGludmVzdG9yIG9yIHRoZSBvd25lciBob2xkaW5nIHRoZSB0b2tlbnMKKiBgdG9rZW5faWRzYCA6IHRoZSB0b2tlbnMgdG8gc2VsbAoqIGB1bml0X3ByaWNlYCA6IHByaWNlIG9mIG9uZSBORlQgaW4gc3RhYmxlY29pbiB1bml0cyAod2l0aCB0aGUgNyBkZWNpbWFscykKKiBgZXhwaXJ5YCA6IGxlZGdlciB0aW1lc3RhbXAgZnJvbSB3aGljaCB0aGUgbGlzdGluZyBjYW4gbm8gbG9uZ2VyIGJlIGZpbGxlZAAAAARsaXN0AAAABAAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAl0b2tlbl9pZHMAAAAAAAPqAAAABAAAAAAAAAAKdW5pdF9wcmljZQAAAAAACwAAAAAAAAAGZXhwaXJ5AAAAAAAGAAAAAQAAA+kAAAAEAAAH0AAAABBNYXJrZXRwbGFjZUVycm9y",
+        "AAAAAAAAAHVSZW1vdmVzIGEgbGlzdGluZyBhbmQgdW5sb2NrcyBpdHMgcmVtYWluaW5nIHRva2VucywgYWxzbyBvbmNlIGl0IGhhcyBleHBpcmVkLgpPbmx5IHRoZSBzZWxsZXIgY2FuIGNhbGwgdGhpcyBmdW5jdGlvbi4AAAAAAAAOY2FuY2VsX2xpc3RpbmcAAAAAAAEAAAAAAAAACmxpc3RpbmdfaWQAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAH0AAAABBNYXJrZXRwbGFjZUVycm9y",
+        "AAAAAAAAAWNCdXlzIGBxdWFudGl0eWAgdG9rZW5zIG9mIGEgbGlzdGluZyBhdCBpdHMgdW5pdCBwcmljZSwgcGFpZCBzdHJhaWdodCB0byB0aGUgc2VsbGVyLgpUaGUgbGlzdGluZyBpcyByZW1vdmVkIG9uY2UgYWxsIGl0cyB0b2tlbnMgYXJlIHNvbGQuCiMgQXJndW1lbnRzCiogYGJ1eWVyYCA6IGFuIGludmVzdG9yIG9yIHRoZSBvd25lciwgbXVzdCBoYXZlIGFwcHJvdmVkIHRoZSBjb250cmFjdCBmb3IgdGhlIHRvdGFsIHByaWNlCiogYGxpc3RpbmdfaWRgIDogdGhlIGxpc3RpbmcgdG8gYnV5IGZyb20KKiBgcXVhbnRpdHlgIDogbnVtYmVyIG9mIHRva2VucyB0byBidXksIHRha2VuIGluIHRoZSBvcmRlciB0aGV5IHdlcmUgbGlzdGVkAAAAAAxmaWxsX2xpc3RpbmcAAAADAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAACmxpc3RpbmdfaWQAAAAAAAQAAAAAAAAACHF1YW50aXR5AAAABAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAAEE1hcmtldHBsYWNlRXJyb3I=",
+        "AAAAAAAAAipFc2Nyb3dzIHRoZSBwcmljZSBvZiBgcXVhbnRpdHlgIHRva2VucywgcGx1cyB0aGUgdHJhZGluZyBmZWUsIHVudGlsIGEgc2VsbGVyIGFjY2VwdHMgdGhlIG9mZmVyCm9yIHRoZSBidXllciByZWNsYWltcyBpdC4gUmV0dXJucyB0aGUgaWQgb2YgdGhlIG9mZmVyLgojIEFyZ3VtZW50cwoqIGBidXllcmAgOiBhbiBpbnZlc3RvciBvciB0aGUgb3duZXIsIG11c3QgaGF2ZSBhcHByb3ZlZCB0aGUgY29udHJhY3QgZm9yIHRoZSBlc2Nyb3dlZCBhbW91bnQKKiBgdG9rZW5faWRzYCA6IHRoZSB0b2tlbnMgd2FudGVkLCBvciBlbXB0eSB0byB0YWtlIGFueSB0b2tlbnMKKiBgcXVhbnRpdHlgIDogbnVtYmVyIG9mIHRva2VucyB3YW50ZWQsIG11c3QgbWF0Y2ggYHRva2VuX2lkc2Agd2hlbiB0aGV5IGFyZSBnaXZlbgoqIGB1bml0X3ByaWNlYCA6IHByaWNlIG9mIG9uZSBORlQgaW4gc3RhYmxlY29pbiB1bml0cyAod2l0aCB0aGUgNyBkZWNpbWFscykKKiBgZXhwaXJ5YCA6IGxlZGdlciB0aW1lc3RhbXAgZnJvbSB3aGljaCB0aGUgb2ZmZXIgY2FuIG5vIGxvbmdlciBiZSBhY2NlcHRlZAAAAAAACm1ha2Vfb2ZmZXIAAAAAAAUAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAJdG9rZW5faWRzAAAAAAAD6gAAAAQAAAAAAAAACHF1YW50aXR5AAAABAAAAAAAAAAKdW5pdF9wcmljZQAAAAAACwAAAAAAAAAGZXhwaXJ5AAAAAAAGAAAAAQAAA+kAAAAEAAAH0AAAABBNYXJrZXRwbGFjZUVycm9y",
+        "AAAAAAAAAXZTZWxscyB0b2tlbnMgdG8gYW4gb2ZmZXIgYmVmb3JlIGl0cyBleHBpcnksIHRoZSBzZWxsZXIgaXMgcGFpZCBmcm9tIHRoZSBlc2Nyb3cuCkFuIG9mZmVyIGNhbiBiZSBmaWxsZWQgYnkgc2V2ZXJhbCBzZWxsZXJzLCBpdCBpcyByZW1vdmVkIG9uY2UgaXRzIHdob2xlIHF1YW50aXR5IGlzIHNvbGQuCiMgQXJndW1lbnRzCiogYHNlbGxlcmAgOiBhbiBpbnZlc3RvciBvciB0aGUgb3duZXIgaG9sZGluZyB0aGUgdG9rZW5zLCBubyBhcHByb3ZhbCBpcyBuZWVkZWQKKiBgb2ZmZXJfaWRgIDogdGhlIG9mZmVyIHRvIHNlbGwgdG8KKiBgdG9rZW5faWRzYCA6IHRoZSB0b2tlbnMgc29sZCwgYW1vbmcgdGhvc2Ugb2YgdGhlIG9mZmVyIHdoZW4gaXQgbmFtZXMgc29tZQAAAAAADGFjY2VwdF9vZmZlcgAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAIb2ZmZXJfaWQAAAAEAAAAAAAAAAl0b2tlbl9pZHMAAAAAAAPqAAAABAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAAEE1hcmtldHBsYWNlRXJyb3I=",
+        "AAAAAAAAALFSZWZ1bmRzIHdoYXQgaXMgbGVmdCBvZiBhbiBvZmZlciB0byBpdHMgYnV5ZXIgYW5kIHJlbW92ZXMgaXQuClRoZSBidXllciBjYW4gd2l0aGRyYXcgYW4gb2ZmZXIgYXQgYW55IHRpbWUsIGFueW9uZSBjYW4gcmV0dXJuIGl0IG9uY2UgaXQgaGFzIGV4cGlyZWQuClJldHVybnMgdGhlIHJlZnVuZGVkIGFtb3VudC4AAAAAAAANcmVjbGFpbV9vZmZlcgAAAAAAAAEAAAAAAAAACG9mZmVyX2lkAAAABAAAAAEAAAPpAAAACwAAB9AAAAAQTWFya2V0cGxhY2VFcnJvcg==",
+        "AAAAAAAAAJxPcGVucywgdXBkYXRlcyBvciBjbG9zZXMgKGBOb25lYCkgdGhlIHNhbGUgb2YgdGhlIG93bmVyIGludmVudG9yeSB0byBpbnZlc3RvcnMuCkNhbiBiZSBjaGFuZ2VkIGF0IGFueSB0aW1lLCBidXllcnMgcHJvdGVjdCB0aGVtc2VsdmVzIHdpdGggYG1heF91bml0X3ByaWNlYC4AAAAXc2V0X2NvbnRpbnVvdXNfb2ZmZXJpbmcAAAAAAQAAAAAAAAAIb2ZmZXJpbmcAAAPoAAAH0AAAABJDb250aW51b3VzT2ZmZXJpbmcAAAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAAEE1hcmtldHBsYWNlRXJyb3I=",
+        "AAAAAAAAAYBCdXlzIGBxdWFudGl0eWAgTkZUcyBvZiB0aGUgb3duZXIgaW52ZW50b3J5IGF0IHRoZSBjb250aW51b3VzIG9mZmVyaW5nIHByaWNlLCBwYWlkIHRvIHRoZSByZWNlaXZlci4KSW52ZW50b3J5IHRva2VucyBhcmUgc29sZCBpbiBvcmRlciwgc2tpcHBpbmcgdGhvc2UgdGhlIG93bmVyIGxpc3RlZCwgd2hpY2ggc3RheSBpbiB0aGUgaW52ZW50b3J5LgojIEFyZ3VtZW50cwoqIGBidXllcmAgOiBhbiBpbnZlc3RvciwgbXVzdCBoYXZlIGFwcHJvdmVkIHRoZSBjb250cmFjdCBmb3IgdGhlIHRvdGFsIHByaWNlCiogYHF1YW50aXR5YCA6IG51bWJlciBvZiBORlRzIHRvIGJ1eQoqIGBtYXhfdW5pdF9wcmljZWAgOiBoaWdoZXN0IHByaWNlIHBlciBORlQgdGhlIGJ1eWVyIGFjY2VwdHMAAAASYnV5X2Zyb21faW52ZW50b3J5AAAAAAADAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAACHF1YW50aXR5AAAABAAAAAAAAAAObWF4X3VuaXRfcHJpY2UAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAH0AAAABBNYXJrZXRwbGFjZUVycm9y",
         "AAAAAAAAAAAAAAAFaGVsbG8AAAAAAAABAAAAAAAAAAJ0bwAAAAAAEAAAAAEAAAPqAAAAEA==",
-        "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAAVfZnJvbQAAAAAAABMAAAAAAAAAA190bwAAAAATAAAAAAAAAAlfdG9rZW5faWQAAAAAAAAEAAAAAA==",
-        "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAACF9zcGVuZGVyAAAAEwAAAAAAAAAFX2Zyb20AAAAAAAATAAAAAAAAAANfdG8AAAAAEwAAAAAAAAAJX3Rva2VuX2lkAAAAAAAABAAAAAA=",
+        "AAAAAAAAAJlQZWVyLXRvLXBlZXIgdHJhbnNmZXIsIGZyZWUgb2YgY2hhcmdlLCBiZXR3ZWVuIGFjY291bnRzIGFsbG93ZWQgdG8gdHJhZGUuCkZhaWxzIHdpdGggYENvbXBsaWFuY2VFcnJvcjo6VHJhbnNmZXJSZXN0cmljdGVkYCB3aGVuIGEgdHJhZGluZyBydWxlIGJsb2NrcyBpdC4AAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACHRva2VuX2lkAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAQAAAAA",
         "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAABA==",
         "AAAAAAAAAAAAAAAIb3duZXJfb2YAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAQAAAABAAAAEw==",
         "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAAEAAAAAAAAAAhhcHByb3ZlcgAAABMAAAAAAAAACGFwcHJvdmVkAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAEAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAAA",
@@ -1469,24 +3992,21 @@ export class Client extends ContractClient {
         "AAAAAAAAAAAAAAASdHJhbnNmZXJfb3duZXJzaGlwAAAAAAACAAAAAAAAAAluZXdfb3duZXIAAAAAAAATAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAAA",
         "AAAAAAAAAAAAAAAQYWNjZXB0X293bmVyc2hpcAAAAAAAAAAA",
         "AAAAAAAAAAAAAAAScmVub3VuY2Vfb3duZXJzaGlwAAAAAAAAAAAAAA==",
-        "AAAAAQAAADFTdG9yYWdlIGtleSBmb3IgZW51bWVyYXRpb24gb2YgYWNjb3VudHMgcGVyIHJvbGUuAAAAAAAAAAAAAA5Sb2xlQWNjb3VudEtleQAAAAAAAgAAAAAAAAAFaW5kZXgAAAAAAAAEAAAAAAAAAARyb2xlAAAAEQ==",
-        "AAAAAgAAADxTdG9yYWdlIGtleXMgZm9yIHRoZSBkYXRhIGFzc29jaWF0ZWQgd2l0aCB0aGUgYWNjZXNzIGNvbnRyb2wAAAAAAAAAF0FjY2Vzc0NvbnRyb2xTdG9yYWdlS2V5AAAAAAYAAAABAAAAAAAAAAxSb2xlQWNjb3VudHMAAAABAAAH0AAAAA5Sb2xlQWNjb3VudEtleQAAAAAAAQAAAAAAAAAHSGFzUm9sZQAAAAACAAAAEwAAABEAAAABAAAAAAAAABFSb2xlQWNjb3VudHNDb3VudAAAAAAAAAEAAAARAAAAAQAAAAAAAAAJUm9sZUFkbWluAAAAAAAAAQAAABEAAAAAAAAAAAAAAAVBZG1pbgAAAAAAAAAAAAAAAAAADFBlbmRpbmdBZG1pbg==",
-        "AAAABAAAAAAAAAAAAAAAEkFjY2Vzc0NvbnRyb2xFcnJvcgAAAAAACQAAAAAAAAAMVW5hdXRob3JpemVkAAAEugAAAAAAAAALQWRtaW5Ob3RTZXQAAAAEuwAAAAAAAAAQSW5kZXhPdXRPZkJvdW5kcwAABLwAAAAAAAAAEUFkbWluUm9sZU5vdEZvdW5kAAAAAAAEvQAAAAAAAAASUm9sZUNvdW50SXNOb3RaZXJvAAAAAAS+AAAAAAAAAAxSb2xlTm90Rm91bmQAAAS/AAAAAAAAAA9BZG1pbkFscmVhZHlTZXQAAAAEwAAAAAAAAAALUm9sZU5vdEhlbGQAAAAEwQAAAAAAAAALUm9sZUlzRW1wdHkAAAAEwg==",
-        "AAAAAgAAACNTdG9yYWdlIGtleXMgZm9yIGBPd25hYmxlYCB1dGlsaXR5LgAAAAAAAAAAEU93bmFibGVTdG9yYWdlS2V5AAAAAAAAAgAAAAAAAAAAAAAABU93bmVyAAAAAAAAAAAAAAAAAAAMUGVuZGluZ093bmVy",
-        "AAAABAAAAAAAAAAAAAAADE93bmFibGVFcnJvcgAAAAMAAAAAAAAAC093bmVyTm90U2V0AAAABMQAAAAAAAAAElRyYW5zZmVySW5Qcm9ncmVzcwAAAAAExQAAAAAAAAAPT3duZXJBbHJlYWR5U2V0AAAABMY=",
-        "AAAABAAAAAAAAAAAAAAAEVJvbGVUcmFuc2ZlckVycm9yAAAAAAAAAwAAAAAAAAARTm9QZW5kaW5nVHJhbnNmZXIAAAAAAASwAAAAAAAAABZJbnZhbGlkTGl2ZVVudGlsTGVkZ2VyAAAAAASxAAAAAAAAABVJbnZhbGlkUGVuZGluZ0FjY291bnQAAAAAAASy",
-        "AAAAAgAAAEFTdG9yYWdlIGtleXMgZm9yIHRoZSBkYXRhIGFzc29jaWF0ZWQgd2l0aCB0aGUgYWxsb3dsaXN0IGV4dGVuc2lvbgAAAAAAAAAAAAATQWxsb3dMaXN0U3RvcmFnZUtleQAAAAABAAAAAQAAACdTdG9yZXMgdGhlIGFsbG93ZWQgc3RhdHVzIG9mIGFuIGFjY291bnQAAAAAB0FsbG93ZWQAAAAAAQAAABM=",
-        "AAAAAgAAAEFTdG9yYWdlIGtleXMgZm9yIHRoZSBkYXRhIGFzc29jaWF0ZWQgd2l0aCB0aGUgYmxvY2tsaXN0IGV4dGVuc2lvbgAAAAAAAAAAAAATQmxvY2tMaXN0U3RvcmFnZUtleQAAAAABAAAAAQAAACdTdG9yZXMgdGhlIGJsb2NrZWQgc3RhdHVzIG9mIGFuIGFjY291bnQAAAAAB0Jsb2NrZWQAAAAAAQAAABM=",
-        "AAAAAQAAACpTdG9yYWdlIGtleSB0aGF0IG1hcHMgdG8gW2BBbGxvd2FuY2VEYXRhYF0AAAAAAAAAAAAMQWxsb3dhbmNlS2V5AAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzcGVuZGVyAAAAABM=",
-        "AAAAAQAAAINTdG9yYWdlIGNvbnRhaW5lciBmb3IgdGhlIGFtb3VudCBvZiB0b2tlbnMgZm9yIHdoaWNoIGFuIGFsbG93YW5jZSBpcyBncmFudGVkCmFuZCB0aGUgbGVkZ2VyIG51bWJlciBhdCB3aGljaCB0aGlzIGFsbG93YW5jZSBleHBpcmVzLgAAAAAAAAAADUFsbG93YW5jZURhdGEAAAAAAAACAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAEWxpdmVfdW50aWxfbGVkZ2VyAAAAAAAABA==",
-        "AAAAAgAAADlTdG9yYWdlIGtleXMgZm9yIHRoZSBkYXRhIGFzc29jaWF0ZWQgd2l0aCBgRnVuZ2libGVUb2tlbmAAAAAAAAAAAAAAClN0b3JhZ2VLZXkAAAAAAAMAAAAAAAAAAAAAAAtUb3RhbFN1cHBseQAAAAABAAAAAAAAAAdCYWxhbmNlAAAAAAEAAAATAAAAAQAAAAAAAAAJQWxsb3dhbmNlAAAAAAAAAQAAB9AAAAAMQWxsb3dhbmNlS2V5",
-        "AAAAAQAAACRTdG9yYWdlIGNvbnRhaW5lciBmb3IgdG9rZW4gbWV0YWRhdGEAAAAAAAAACE1ldGFkYXRhAAAAAwAAAAAAAAAIZGVjaW1hbHMAAAAEAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAGc3ltYm9sAAAAAAAQ",
-        "AAAAAgAAAClTdG9yYWdlIGtleSBmb3IgYWNjZXNzaW5nIHRoZSBTQUMgYWRkcmVzcwAAAAAAAAAAAAAWU0FDQWRtaW5HZW5lcmljRGF0YUtleQAAAAAAAQAAAAAAAAAAAAAAA1NhYwA=",
-        "AAAAAgAAAClTdG9yYWdlIGtleSBmb3IgYWNjZXNzaW5nIHRoZSBTQUMgYWRkcmVzcwAAAAAAAAAAAAAWU0FDQWRtaW5XcmFwcGVyRGF0YUtleQAAAAAAAQAAAAAAAAAAAAAAA1NhYwA=",
-        "AAAABAAAAAAAAAAAAAAAEkZ1bmdpYmxlVG9rZW5FcnJvcgAAAAAADwAAAG5JbmRpY2F0ZXMgYW4gZXJyb3IgcmVsYXRlZCB0byB0aGUgY3VycmVudCBiYWxhbmNlIG9mIGFjY291bnQgZnJvbSB3aGljaAp0b2tlbnMgYXJlIGV4cGVjdGVkIHRvIGJlIHRyYW5zZmVycmVkLgAAAAAAE0luc3VmZmljaWVudEJhbGFuY2UAAAAAZAAAAGRJbmRpY2F0ZXMgYSBmYWlsdXJlIHdpdGggdGhlIGFsbG93YW5jZSBtZWNoYW5pc20gd2hlbiBhIGdpdmVuIHNwZW5kZXIKZG9lc24ndCBoYXZlIGVub3VnaCBhbGxvd2FuY2UuAAAAFUluc3VmZmljaWVudEFsbG93YW5jZQAAAAAAAGUAAABNSW5kaWNhdGVzIGFuIGludmFsaWQgdmFsdWUgZm9yIGBsaXZlX3VudGlsX2xlZGdlcmAgd2hlbiBzZXR0aW5nIGFuCmFsbG93YW5jZS4AAAAAAAAWSW52YWxpZExpdmVVbnRpbExlZGdlcgAAAAAAZgAAADJJbmRpY2F0ZXMgYW4gZXJyb3Igd2hlbiBhbiBpbnB1dCB0aGF0IG11c3QgYmUgPj0gMAAAAAAADExlc3NUaGFuWmVybwAAAGcAAAApSW5kaWNhdGVzIG92ZXJmbG93IHdoZW4gYWRkaW5nIHR3byB2YWx1ZXMAAAAAAAAMTWF0aE92ZXJmbG93AAAAaAAAACpJbmRpY2F0ZXMgYWNjZXNzIHRvIHVuaW5pdGlhbGl6ZWQgbWV0YWRhdGEAAAAAAA1VbnNldE1ldGFkYXRhAAAAAAAAaQAAAFJJbmRpY2F0ZXMgdGhhdCB0aGUgb3BlcmF0aW9uIHdvdWxkIGhhdmUgY2F1c2VkIGB0b3RhbF9zdXBwbHlgIHRvIGV4Y2VlZAp0aGUgYGNhcGAuAAAAAAALRXhjZWVkZWRDYXAAAAAAagAAADZJbmRpY2F0ZXMgdGhlIHN1cHBsaWVkIGBjYXBgIGlzIG5vdCBhIHZhbGlkIGNhcCB2YWx1ZS4AAAAAAApJbnZhbGlkQ2FwAAAAAABrAAAAHkluZGljYXRlcyB0aGUgQ2FwIHdhcyBub3Qgc2V0LgAAAAAACUNhcE5vdFNldAAAAAAAAGwAAAAmSW5kaWNhdGVzIHRoZSBTQUMgYWRkcmVzcyB3YXMgbm90IHNldC4AAAAAAAlTQUNOb3RTZXQAAAAAAABtAAAAMEluZGljYXRlcyBhIFNBQyBhZGRyZXNzIGRpZmZlcmVudCB0aGFuIGV4cGVjdGVkLgAAABJTQUNBZGRyZXNzTWlzbWF0Y2gAAAAAAG4AAABDSW5kaWNhdGVzIGEgbWlzc2luZyBmdW5jdGlvbiBwYXJhbWV0ZXIgaW4gdGhlIFNBQyBjb250cmFjdCBjb250ZXh0LgAAAAARU0FDTWlzc2luZ0ZuUGFyYW0AAAAAAABvAAAAREluZGljYXRlcyBhbiBpbnZhbGlkIGZ1bmN0aW9uIHBhcmFtZXRlciBpbiB0aGUgU0FDIGNvbnRyYWN0IGNvbnRleHQuAAAAEVNBQ0ludmFsaWRGblBhcmFtAAAAAAAAcAAAADFUaGUgdXNlciBpcyBub3QgYWxsb3dlZCB0byBwZXJmb3JtIHRoaXMgb3BlcmF0aW9uAAAAAAAADlVzZXJOb3RBbGxvd2VkAAAAAABxAAAANVRoZSB1c2VyIGlzIGJsb2NrZWQgYW5kIGNhbm5vdCBwZXJmb3JtIHRoaXMgb3BlcmF0aW9uAAAAAAAAC1VzZXJCbG9ja2VkAAAAAHI=",
-        "AAAAAgAAAFlTdG9yYWdlIGtleXMgZm9yIHRoZSBkYXRhIGFzc29jaWF0ZWQgd2l0aCB0aGUgY29uc2VjdXRpdmUgZXh0ZW5zaW9uIG9mCmBOb25GdW5naWJsZVRva2VuYAAAAAAAAAAAAAAYTkZUQ29uc2VjdXRpdmVTdG9yYWdlS2V5AAAABAAAAAEAAAAAAAAACEFwcHJvdmFsAAAAAQAAAAQAAAABAAAAAAAAAAVPd25lcgAAAAAAAAEAAAAEAAAAAQAAAAAAAAAPT3duZXJzaGlwQnVja2V0AAAAAAEAAAAEAAAAAQAAAAAAAAALQnVybmVkVG9rZW4AAAAAAQAAAAQ=",
-        "AAAAAQAAAAAAAAAAAAAADk93bmVyVG9rZW5zS2V5AAAAAAACAAAAAAAAAAVpbmRleAAAAAAAAAQAAAAAAAAABW93bmVyAAAAAAAAEw==",
-        "AAAAAgAAAFhTdG9yYWdlIGtleXMgZm9yIHRoZSBkYXRhIGFzc29jaWF0ZWQgd2l0aCB0aGUgZW51bWVyYWJsZSBleHRlbnNpb24gb2YKYE5vbkZ1bmdpYmxlVG9rZW5gAAAAAAAAABdORlRFbnVtZXJhYmxlU3RvcmFnZUtleQAAAAAFAAAAAAAAAAAAAAALVG90YWxTdXBwbHkAAAAAAQAAAAAAAAALT3duZXJUb2tlbnMAAAAAAQAAB9AAAAAOT3duZXJUb2tlbnNLZXkAAAAAAAEAAAAAAAAAEE93bmVyVG9rZW5zSW5kZXgAAAABAAAABAAAAAEAAAAAAAAADEdsb2JhbFRva2VucwAAAAEAAAAEAAAAAQAAAAAAAAARR2xvYmFsVG9rZW5zSW5kZXgAAAAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAIaGFzX3JvbGUAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAABHJvbGUAAAARAAAAAQAAA+gAAAAE",
+        "AAAAAAAAAAAAAAAVZ2V0X3JvbGVfbWVtYmVyX2NvdW50AAAAAAAAAQAAAAAAAAAEcm9sZQAAABEAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAPZ2V0X3JvbGVfbWVtYmVyAAAAAAIAAAAAAAAABHJvbGUAAAARAAAAAAAAAAVpbmRleAAAAAAAAAQAAAABAAAAEw==",
+        "AAAAAAAAAAAAAAAOZ2V0X3JvbGVfYWRtaW4AAAAAAAEAAAAAAAAABHJvbGUAAAARAAAAAQAAA+gAAAAR",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAAAAAAAKZ3JhbnRfcm9sZQAAAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAABHJvbGUAAAARAAAAAA==",
+        "AAAAAAAAAAAAAAALcmV2b2tlX3JvbGUAAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAABHJvbGUAAAARAAAAAA==",
+        "AAAAAAAAAAAAAAANcmVub3VuY2Vfcm9sZQAAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAEcm9sZQAAABEAAAAA",
+        "AAAAAAAAAAAAAAATdHJhbnNmZXJfYWRtaW5fcm9sZQAAAAACAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAAVYWNjZXB0X2FkbWluX3RyYW5zZmVyAAAAAAAAAAAAAAA=",
+        "AAAAAAAAAAAAAAAOc2V0X3JvbGVfYWRtaW4AAAAAAAIAAAAAAAAABHJvbGUAAAARAAAAAAAAAAphZG1pbl9yb2xlAAAAAAARAAAAAA==",
+        "AAAAAAAAAAAAAAAOcmVub3VuY2VfYWRtaW4AAAAAAAAAAAAA",
+        "AAAAAAAAAAAAAAAGcGF1c2VkAAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAFcGF1c2UAAAAAAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAAHdW5wYXVzZQAAAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAA",
         "AAAAAQAAAClTdG9yYWdlIGNvbnRhaW5lciBmb3Igcm95YWx0eSBpbmZvcm1hdGlvbgAAAAAAAAAAAAALUm95YWx0eUluZm8AAAAAAgAAAAAAAAAMYmFzaXNfcG9pbnRzAAAABAAAAAAAAAAIcmVjZWl2ZXIAAAAT",
         "AAAAAgAAAB1TdG9yYWdlIGtleXMgZm9yIHJveWFsdHkgZGF0YQAAAAAAAAAAAAAWTkZUUm95YWx0aWVzU3RvcmFnZUtleQAAAAAAAgAAAAAAAAAAAAAADkRlZmF1bHRSb3lhbHR5AAAAAAABAAAAAAAAAAxUb2tlblJveWFsdHkAAAABAAAABA==",
         "AAAAAQAAAHZTdG9yYWdlIGNvbnRhaW5lciBmb3IgdGhlIHRva2VuIGZvciB3aGljaCBhbiBhcHByb3ZhbCBpcyBncmFudGVkCmFuZCB0aGUgbGVkZ2VyIG51bWJlciBhdCB3aGljaCB0aGlzIGFwcHJvdmFsIGV4cGlyZXMuAAAAAAAAAAAADEFwcHJvdmFsRGF0YQAAAAIAAAAAAAAACGFwcHJvdmVkAAAAEwAAAAAAAAARbGl2ZV91bnRpbF9sZWRnZXIAAAAAAAAE",
@@ -1499,34 +4019,104 @@ export class Client extends ContractClient {
     );
   }
   public readonly fromJSON = {
-    set_stablecoin: this.txFromJSON<null>,
-    create_investor: this.txFromJSON<null>,
-    mint: this.txFromJSON<null>,
-    start_chronometer: this.txFromJSON<null>,
-    calculate_amount_to_release: this.txFromJSON<i128>,
-    release_distribution: this.txFromJSON<null>,
+    propose_change: this.txFromJSON<Result<u64>>,
+    execute_change: this.txFromJSON<Result<readonly []>>,
+    cancel_change: this.txFromJSON<Result<readonly []>>,
+    set_soft_cap: this.txFromJSON<Result<readonly []>>,
+    set_sale_window: this.txFromJSON<Result<readonly []>>,
+    create_investor: this.txFromJSON<Result<readonly []>>,
+    set_investor_kyc: this.txFromJSON<Result<readonly []>>,
+    set_investor_lock_up: this.txFromJSON<Result<readonly []>>,
+    set_tier_cap: this.txFromJSON<null>,
+    freeze_investor: this.txFromJSON<Result<readonly []>>,
+    unfreeze_investor: this.txFromJSON<Result<i128>>,
+    remove_investor: this.txFromJSON<Result<readonly []>>,
+    mint: this.txFromJSON<Result<readonly []>>,
+    start_chronometer: this.txFromJSON<Result<readonly []>>,
+    cancel_offering: this.txFromJSON<Result<readonly []>>,
+    refund: this.txFromJSON<Result<readonly []>>,
+    calculate_amount_to_release: this.txFromJSON<Result<i128>>,
+    release_distribution: this.txFromJSON<Result<readonly []>>,
+    release_distribution_batch: this.txFromJSON<Result<boolean>>,
+    claim: this.txFromJSON<Result<i128>>,
+    sweep_dust: this.txFromJSON<Result<readonly []>>,
+    roll_dust: this.txFromJSON<Result<readonly []>>,
+    fund_redemption: this.txFromJSON<Result<readonly []>>,
+    redeem: this.txFromJSON<Result<readonly []>>,
+    pause_function: this.txFromJSON<null>,
+    unpause_function: this.txFromJSON<null>,
+    upgrade: this.txFromJSON<null>,
+    migrate: this.txFromJSON<Result<u32>>,
+    get_investor_profile: this.txFromJSON<InvestorProfile>,
+    get_investor_lock_up: this.txFromJSON<u64>,
+    get_lock_up_period: this.txFromJSON<u64>,
+    transferable_after: this.txFromJSON<u64>,
+    get_tier_cap: this.txFromJSON<Result<u32>>,
+    is_function_paused: this.txFromJSON<boolean>,
+    get_change_delay: this.txFromJSON<u64>,
+    get_pending_change: this.txFromJSON<Option<PendingChange>>,
+    get_locked_parameters: this.txFromJSON<Array<ConfigParameter>>,
+    get_storage_version: this.txFromJSON<u32>,
     is_investor: this.txFromJSON<boolean>,
+    is_investor_frozen: this.txFromJSON<boolean>,
+    get_frozen_amount: this.txFromJSON<i128>,
     get_investors_array_length: this.txFromJSON<u32>,
-    get_stablecoin: this.txFromJSON<string>,
-    get_receiver: this.txFromJSON<string>,
-    get_begin_date: this.txFromJSON<u64>,
-    is_chronometer_started: this.txFromJSON<boolean>,
-    get_payer: this.txFromJSON<string>,
+    get_stablecoin: this.txFromJSON<Result<string>>,
+    get_receiver: this.txFromJSON<Result<string>>,
+    get_begin_date: this.txFromJSON<Result<u64>>,
+    is_chronometer_started: this.txFromJSON<Result<boolean>>,
+    get_payer: this.txFromJSON<Result<string>>,
+    see_claimable_amount: this.txFromJSON<i128>,
+    get_stage_remainder: this.txFromJSON<i128>,
+    get_dust_balance: this.txFromJSON<i128>,
+    get_rolled_dust: this.txFromJSON<i128>,
+    get_stage_amount_per_nft: this.txFromJSON<i128>,
+    balance_at_stage: this.txFromJSON<u32>,
+    get_distribution_progress: this.txFromJSON<Option<DistributionProgress>>,
     see_claimed_amount: this.txFromJSON<i128>,
+    see_redeemed_amount: this.txFromJSON<i128>,
+    is_redemption_funded: this.txFromJSON<boolean>,
+    get_redeemed_supply: this.txFromJSON<u32>,
     get_current_supply: this.txFromJSON<u32>,
-    get_current_state: this.txFromJSON<InvestmentStatus>,
-    get_nft_price: this.txFromJSON<i128>,
-    get_total_supply: this.txFromJSON<u32>,
-    get_min_nfts_to_mint: this.txFromJSON<u32>,
-    get_max_nfts_per_investor: this.txFromJSON<u32>,
-    get_nft_buying_phase_supply: this.txFromJSON<u32>,
-    get_distribution_intervals: this.txFromJSON<Array<u64>>,
-    get_roi_percentages: this.txFromJSON<Array<i128>>,
-    get_buying_phase_nft_supply: this.txFromJSON<u32>,
-    buy_tokens: this.txFromJSON<null>,
-    sell_tokens: this.txFromJSON<null>,
-    set_receiver: this.txFromJSON<null>,
-    set_payer: this.txFromJSON<null>,
+    get_current_state: this.txFromJSON<Result<InvestmentStatus>>,
+    get_nft_price: this.txFromJSON<Result<i128>>,
+    get_total_supply: this.txFromJSON<Result<u32>>,
+    get_min_nfts_to_mint: this.txFromJSON<Result<u32>>,
+    get_max_nfts_per_investor: this.txFromJSON<Result<u32>>,
+    get_nft_buying_phase_supply: this.txFromJSON<Result<u32>>,
+    get_distribution_intervals: this.txFromJSON<Result<Array<u64>>>,
+    get_roi_percentages: this.txFromJSON<Result<Array<i128>>>,
+    get_stage_count: this.txFromJSON<Result<u32>>,
+    get_buying_phase_nft_supply: this.txFromJSON<Result<u32>>,
+    get_soft_cap: this.txFromJSON<Result<u32>>,
+    get_refund_deadline: this.txFromJSON<Result<u64>>,
+    is_soft_cap_reached: this.txFromJSON<Result<boolean>>,
+    get_sale_start: this.txFromJSON<Result<u64>>,
+    get_sale_end: this.txFromJSON<Result<u64>>,
+    get_escrowed_amount: this.txFromJSON<i128>,
+    get_paid_amount: this.txFromJSON<i128>,
+    get_trading_fee_bps: this.txFromJSON<u32>,
+    get_fee_recipient: this.txFromJSON<Result<string>>,
+    get_listing: this.txFromJSON<Option<Listing>>,
+    get_token_listing: this.txFromJSON<Option<u32>>,
+    get_token_ranges: this.txFromJSON<Array<TokenRange>>,
+    get_offer: this.txFromJSON<Option<Offer>>,
+    get_continuous_offering: this.txFromJSON<Option<ContinuousOffering>>,
+    get_inventory_price: this.txFromJSON<Result<i128>>,
+    is_accrued_roi_pricing: this.txFromJSON<boolean>,
+    quote_price: this.txFromJSON<Result<i128>>,
+    get_inventory_supply: this.txFromJSON<u32>,
+    buy_tokens: this.txFromJSON<Result<readonly []>>,
+    buy_quantity: this.txFromJSON<Result<Array<u32>>>,
+    sell_tokens: this.txFromJSON<Result<readonly []>>,
+    list: this.txFromJSON<Result<u32>>,
+    cancel_listing: this.txFromJSON<Result<readonly []>>,
+    fill_listing: this.txFromJSON<Result<readonly []>>,
+    make_offer: this.txFromJSON<Result<u32>>,
+    accept_offer: this.txFromJSON<Result<readonly []>>,
+    reclaim_offer: this.txFromJSON<Result<i128>>,
+    set_continuous_offering: this.txFromJSON<Result<readonly []>>,
+    buy_from_inventory: this.txFromJSON<Result<readonly []>>,
     hello: this.txFromJSON<Array<string>>,
     transfer: this.txFromJSON<null>,
     transfer_from: this.txFromJSON<null>,
@@ -1543,5 +4133,20 @@ export class Client extends ContractClient {
     transfer_ownership: this.txFromJSON<null>,
     accept_ownership: this.txFromJSON<null>,
     renounce_ownership: this.txFromJSON<null>,
+    has_role: this.txFromJSON<Option<u32>>,
+    get_role_member_count: this.txFromJSON<u32>,
+    get_role_member: this.txFromJSON<string>,
+    get_role_admin: this.txFromJSON<Option<string>>,
+    get_admin: this.txFromJSON<Option<string>>,
+    grant_role: this.txFromJSON<null>,
+    revoke_role: this.txFromJSON<null>,
+    renounce_role: this.txFromJSON<null>,
+    transfer_admin_role: this.txFromJSON<null>,
+    accept_admin_transfer: this.txFromJSON<null>,
+    set_role_admin: this.txFromJSON<null>,
+    renounce_admin: this.txFromJSON<null>,
+    paused: this.txFromJSON<boolean>,
+    pause: this.txFromJSON<null>,
+    unpause: this.txFromJSON<null>,
   };
 }
@@ -199,30 +199,18 @@ const swaggerDefinition = {
       InvestmentState: {
         type: "object",
         properties: {
-          state: {
+          stage: {
             type: "number",
-            description: "Current investment state as enum value",
-            example: 1,
+            nullable: true,
+            description:
+              "Index of the next release stage while distributing, null otherwise",
+            example: 0,
           },
           stateName: {
             type: "string",
             description: "Human-readable state name",
-            example: "BeforeFirstRelease",
-            enum: [
-              "BuyingPhase",
-              "BeforeFirstRelease",
-              "Release1",
-              "Release2",
-              "Release3",
-              "Release4",
-              "Release5",
-              "Release6",
-              "Release7",
-              "Release8",
-              "Release9",
-              "Release10",
-              "Ended",
-            ],
+            example: "Distributing",
+            enum: ["BuyingPhase", "Distributing", "Ended", "Cancelled"],
           },
         },
       },
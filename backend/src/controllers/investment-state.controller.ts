@@ -1,6 +1,5 @@
 import { Request, Response } from "express";
 import { stellarService } from "../services/stellar.service";
-import { stateNames } from "../config";
 
 /**
  * @swagger
@@ -40,14 +39,15 @@ export const getInvestmentState = async (
     // Get current investment state from the contract
     const state = await stellarService.getCurrentInvestmentState();
 
-    const stateName = stateNames[state] || "Unknown";
+    // The next release stage is only carried while distributing
+    const stage = state.tag === "Distributing" ? state.values[0] : null;
 
     res.status(200).json({
       success: true,
       message: "Investment state retrieved successfully",
       data: {
-        state,
-        stateName,
+        stage,
+        stateName: state.tag,
       },
     });
   } catch (error: any) {
@@ -1,7 +1,7 @@
 import { Request, Response } from "express";
 import { stellarService } from "../services/stellar.service";
 import { formatUnits, parseUnits } from "viem";
-import { CONFIG, stateNames } from "../config";
+import { CONFIG } from "../config";
 
 /**
  * @swagger
@@ -188,7 +188,7 @@ export const releaseDistribution = async (
 
     const investmentState = await stellarService.getCurrentInvestmentState();
 
-    if (investmentState === stateNames.length - 1) {
+    if (investmentState.tag === "Ended") {
       res.status(400).json({
         success: false,
         message: "Distribution cannot be released",
@@ -197,7 +197,16 @@ export const releaseDistribution = async (
       return;
     }
 
-    if (investmentState === 0) {
+    if (investmentState.tag === "Cancelled") {
+      res.status(400).json({
+        success: false,
+        message: "Distribution cannot be released",
+        error: "Investment was cancelled",
+      });
+      return;
+    }
+
+    if (investmentState.tag === "BuyingPhase") {
       res.status(400).json({
         success: false,
         message: "Distribution cannot be released",
@@ -213,8 +222,8 @@ export const releaseDistribution = async (
 
     const elapsedTime = Math.floor(Date.now() / 1000) - chronometerBeginDate;
 
-    // Calculate the required time for the current stage
-    const currentStageIndex = investmentState - 1;
+    // Calculate the required time for the next stage to release
+    const currentStageIndex = investmentState.values[0];
 
     const distributionIntervals =
       await stellarService.getDistributionIntervals();
@@ -50,9 +50,10 @@ class StellarService {
         this.ownerKeypair.publicKey()
       );
 
-      // Prepare the create_investor function call
+      // Prepare the create_investor function call, the owner account holds the compliance role
       const operation = contract.call(
         "create_investor",
+        Address.fromString(this.ownerKeypair.publicKey()).toScVal(),
         Address.fromString(investorAddress).toScVal()
       );
 
@@ -258,7 +259,7 @@ class StellarService {
         percent: percent,
       });
 
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error(
         "Error calling calculate_amount_to_release on Stellar:",
@@ -346,8 +347,11 @@ class StellarService {
       // account to another). We fetch this sequence number from the RPC server.
       const ownerAccount = await this.server.getAccount(ownerAddress);
 
-      // Prepare the release_distribution function call
-      const operation = contract.call("release_distribution");
+      // Prepare the release_distribution function call, the owner account holds the treasury role
+      const operation = contract.call(
+        "release_distribution",
+        Address.fromString(ownerAddress).toScVal()
+      );
 
       // Build the transaction
       const builtTransaction = new TransactionBuilder(ownerAccount, {
@@ -458,7 +462,7 @@ class StellarService {
       });
 
       const { result: nftPriceB } = await contract.get_nft_price();
-      const nftPrice = Number(nftPriceB);
+      const nftPrice = Number(nftPriceB.unwrap());
       const totalCost = nftPrice * amount;
       const parsedTotalCost = parseUnits(totalCost.toString(), USDC_DECIMALS);
 
@@ -653,7 +657,7 @@ class StellarService {
    * Get the current state of the investment from the contract
    * @returns The current investment state
    */
-  async getCurrentInvestmentState(): Promise<number> {
+  async getCurrentInvestmentState(): Promise<MinahClient.InvestmentStatus> {
     try {
       const contract = new MinahClient.Client({
         ...(this.network === "testnet"
@@ -664,7 +668,7 @@ class StellarService {
 
       const { result } = await contract.get_current_state();
 
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error("Error calling get_current_state on Stellar:", error);
       throw error;
@@ -686,7 +690,7 @@ class StellarService {
 
       const { result } = await contract.is_chronometer_started();
 
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error("Error calling is_chronometer_started on Stellar:", error);
       throw error;
@@ -708,7 +712,7 @@ class StellarService {
 
       const { result } = await contract.get_begin_date();
 
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error("Error calling get_begin_date on Stellar:", error);
       throw error;
@@ -802,7 +806,7 @@ class StellarService {
 
       const { result } = await contract.get_distribution_intervals();
 
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error(
         "Error calling get_distribution_intervals on Stellar:",
@@ -848,7 +852,7 @@ class StellarService {
 
       const { result } = await contract.get_payer();
 
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error("Error calling get_payer on Stellar:", error);
       throw error;
@@ -883,7 +887,7 @@ class StellarService {
 
       const { result } = await contract.get_stablecoin();
 
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error("Error calling get_stablecoin on Stellar:", error);
       throw error;
@@ -903,7 +907,7 @@ class StellarService {
       });
 
       const { result } = await contract.get_receiver();
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error("Error calling get_receiver on Stellar:", error);
       throw error;
@@ -923,7 +927,7 @@ class StellarService {
       });
 
       const { result } = await contract.get_nft_price();
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error("Error calling get_nft_price on Stellar:", error);
       throw error;
@@ -941,7 +945,7 @@ class StellarService {
       });
 
       const { result } = await contract.get_total_supply();
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error("Error calling get_total_supply on Stellar:", error);
       throw error;
@@ -959,7 +963,7 @@ class StellarService {
       });
 
       const { result } = await contract.get_min_nfts_to_mint();
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error("Error calling get_min_nfts_to_mint on Stellar:", error);
       throw error;
@@ -977,7 +981,7 @@ class StellarService {
       });
 
       const { result } = await contract.get_max_nfts_per_investor();
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error(
         "Error calling get_max_nfts_per_investor on Stellar:",
@@ -998,7 +1002,7 @@ class StellarService {
       });
 
       const { result } = await contract.get_nft_buying_phase_supply();
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error(
         "Error calling get_nft_buying_phase_supply on Stellar:",
@@ -1019,7 +1023,7 @@ class StellarService {
       });
 
       const { result } = await contract.get_roi_percentages();
-      return result;
+      return result.unwrap();
     } catch (error) {
       console.error("Error calling get_roi_percentages on Stellar:", error);
       throw error;
@@ -52,7 +52,7 @@ export interface GetInvestorCountResponse {
 }
 
 export interface GetInvestmentStateResponse {
-  state: number;
+  stage: number | null;
   stateName: string;
 }
 
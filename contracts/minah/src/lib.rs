@@ -1,30 +1,175 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, token, vec, Address, Env, String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, token, vec, Address,
+    BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
 };
-use stellar_access::ownable::{self as ownable, Ownable};
-use stellar_macros::{default_impl, only_owner};
+use stellar_access::{
+    access_control::{self as access_control, AccessControl},
+    ownable::{self as ownable, Ownable},
+};
+use stellar_contract_utils::pausable::{self as pausable, Pausable};
+use stellar_macros::{default_impl, only_admin, only_owner, only_role};
 use stellar_tokens::non_fungible::{
+    burnable,
     consecutive::{Consecutive, NonFungibleConsecutive},
     Base, NonFungibleToken,
 };
 
+/// Lifecycle of a Minah offering.
+/// `Distributing(stage)` carries the index of the next release stage, so `Distributing(0)` is the
+/// period between `start_chronometer` and the first release, and the contract moves to `Ended`
+/// once the last stage of the stored schedule has been released.
+/// `Cancelled` is reached when the soft cap is not met by the refund deadline.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[contracttype]
 pub enum InvestmentStatus {
-    BuyingPhase = 0,
-    BeforeFirstRelease = 1,
-    Release1 = 2,
-    Release2 = 3,
-    Release3 = 4,
-    Release4 = 5,
-    Release5 = 6,
-    Release6 = 7,
-    Release7 = 8,
-    Release8 = 9,
-    Release9 = 10,
-    Release10 = 11,
-    Ended = 12,
+    BuyingPhase,
+    Distributing(u32),
+    Ended,
+    Cancelled,
+}
+
+/// Progress of a stage released page by page with `release_distribution_batch`.
+/// Stored while the stage is in progress and removed once its last page completes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct DistributionProgress {
+    pub stage: u32,
+    pub next_investor: u32,
+    pub investors_count: u32,
+    pub distributed_nfts: i128,
+    pub distributed_amount: i128,
+}
+
+/// Entry points that can be paused one by one with `pause_function`.
+/// Pausing the whole contract with `pause` blocks all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum PausableFunction {
+    Mint,
+    BuyTokens,
+    SellTokens,
+    ReleaseDistribution,
+    Transfer,
+    BuyFromInventory,
+}
+
+/// Addresses and settings that can only be changed through the timelock.
+/// The stablecoin is frozen once the chronometer starts, the price and release schedule
+/// as soon as the first NFT is sold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum ConfigParameter {
+    StableCoin,
+    Receiver,
+    Payer,
+    ChangeDelay,
+    Price,
+    ReleaseSchedule,
+    TradingFee,
+    FeeRecipient,
+    AccruedRoiPricing,
+    LockUpPeriod,
+}
+
+/// A change proposed with `propose_change`, carrying the new value of its parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub enum ConfigChange {
+    StableCoin(Address),
+    Receiver(Address),
+    Payer(Address),
+    ChangeDelay(u64),
+    Price(i128),
+    ReleaseSchedule(Vec<u64>, Vec<i128>),
+    TradingFee(u32),
+    FeeRecipient(Option<Address>),
+    AccruedRoiPricing(bool),
+    LockUpPeriod(u64),
+}
+
+impl ConfigChange {
+    pub fn parameter(&self) -> ConfigParameter {
+        match self {
+            ConfigChange::StableCoin(_) => ConfigParameter::StableCoin,
+            ConfigChange::Receiver(_) => ConfigParameter::Receiver,
+            ConfigChange::Payer(_) => ConfigParameter::Payer,
+            ConfigChange::ChangeDelay(_) => ConfigParameter::ChangeDelay,
+            ConfigChange::Price(_) => ConfigParameter::Price,
+            ConfigChange::ReleaseSchedule(_, _) => ConfigParameter::ReleaseSchedule,
+            ConfigChange::TradingFee(_) => ConfigParameter::TradingFee,
+            ConfigChange::FeeRecipient(_) => ConfigParameter::FeeRecipient,
+            ConfigChange::AccruedRoiPricing(_) => ConfigParameter::AccruedRoiPricing,
+            ConfigChange::LockUpPeriod(_) => ConfigParameter::LockUpPeriod,
+        }
+    }
+}
+
+/// A proposed change waiting for its timelock to elapse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct PendingChange {
+    pub change: ConfigChange,
+    pub executable_at: u64,
+}
+
+/// Investor tiers, each with its own maximum holding (`MaxNFTsPerInvestor` until the admin sets one).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum InvestorTier {
+    Retail,
+    Accredited,
+    Institutional,
+}
+
+/// KYC record of an investor. Investors without one are `Retail` and never expire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct InvestorProfile {
+    pub tier: InvestorTier,
+    pub kyc_expires_at: u64,
+}
+
+/// Tokens listed by a holder at their own price, in stablecoin units per NFT.
+/// They stay in the seller's account, locked until sold, or the listing is cancelled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct Listing {
+    pub seller: Address,
+    pub token_ids: Vec<u32>,
+    pub unit_price: i128,
+    pub expiry: u64,
+}
+
+/// Stablecoin escrowed by a buyer for `quantity` tokens at `unit_price`, any tokens when
+/// `token_ids` is empty, only those otherwise. `fee` is the trading fee escrowed with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct Offer {
+    pub buyer: Address,
+    pub token_ids: Vec<u32>,
+    pub quantity: u32,
+    pub unit_price: i128,
+    pub fee: i128,
+    pub expiry: u64,
+}
+
+/// Terms on which investors buy the unsold NFTs minted to the owner by `start_chronometer`.
+/// `unit_price` is in stablecoin units per NFT, lowered by the ROI already paid per NFT when
+/// `deduct_paid_roi` is set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct ContinuousOffering {
+    pub unit_price: i128,
+    pub deduct_paid_roi: bool,
+}
+
+/// Consecutive token ids `start..end` held by an account, as kept by the token index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub struct TokenRange {
+    pub start: u32,
+    pub end: u32,
 }
 
 #[contracttype]
@@ -35,9 +180,9 @@ pub enum DataKey {
     AmountToReleaseForCurrentStage,
     Receiver,
     Payer,
-    InvestorsArray,
     CountdownStart,
     State,
+    // Per-investor entries (keyed by address or investor index) live in persistent storage
     Investor(Address),
     ClaimedAmount(Address),
     DistributionIntervals,
@@ -47,6 +192,262 @@ pub enum DataKey {
     MaxNFTsPerInvestor,
     TotalSupply,
     NFTBuyingPhaseSupply,
+    SoftCap,
+    RefundDeadline,
+    SoftCapReached,
+    EscrowedAmount,
+    PaidAmount(Address),
+    SaleStart,
+    SaleEnd,
+    RedemptionFunded,
+    RedeemedSupply,
+    RedeemedAmount(Address),
+    ClaimableAmount(Address),
+    StageAmountPerNFT(u32),
+    DistributionProgress,
+    BalanceCheckpoints(Address),
+    StageRemainder(u32),
+    DustBalance,
+    RolledDust,
+    InvestorsCount,
+    InvestorAt(u32),
+    // Position of an investor in the investors index, in persistent storage
+    InvestorIndex(Address),
+    StorageVersion,
+    FunctionPaused(PausableFunction),
+    ChangeDelay,
+    PendingChange(ConfigParameter),
+    // Compliance records, in persistent storage
+    InvestorFrozen(Address),
+    FrozenAmount(Address),
+    InvestorProfile(Address),
+    TierCap(InvestorTier),
+    InvestorLockUp(Address),
+    LockedUntil(Address),
+    // Seconds after the begin date during which NFTs cannot change hands, in instance storage
+    LockUpPeriod,
+}
+
+/// Storage keys of the secondary market.
+/// Kept apart from `DataKey`, which is at the size limit of the contract spec.
+#[contracttype]
+pub enum MarketplaceDataKey {
+    NextListingId,
+    NextOfferId,
+    TradingFeeBps,
+    FeeRecipient,
+    ContinuousOffering,
+    AccruedRoiPricing,
+    // First token of the owner inventory not sold yet, and the count of inventory tokens the owner still holds
+    InventoryCursor,
+    InventorySupply,
+    // Listings, the listing of each locked token, offers and the token ranges of each holder, in persistent storage
+    Listing(u32),
+    ListedToken(u32),
+    Offer(u32),
+    OwnedTokens(Address),
+}
+
+/// Errors returned by the Minah contract.
+/// Codes are part of the contract interface: never renumber a variant, only append new ones.
+/// The contract spec allows at most 50 variants, prefer reusing a code when a new check fits an existing one.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MinahError {
+    NotInitialized = 1,
+    InvalidReleaseSchedule = 2,
+    InvestmentNotInBuyingPhase = 3,
+    ConfigLockedAfterFirstMint = 4,
+    SoftCapExceedsTotalSupply = 5,
+    InvalidRefundDeadline = 6,
+    InvalidSaleWindow = 7,
+    InvestorAlreadyExists = 8,
+    UserNotAnInvestor = 9,
+    SaleNotOpen = 10,
+    SaleClosed = 11,
+    MinimumInvestmentNotMet = 12,
+    MaximumNftsPerInvestorExceeded = 13,
+    MaximumSupplyExceeded = 14,
+    InsufficientBalance = 15,
+    InsufficientAllowance = 16,
+    ChronometerAlreadyStarted = 17,
+    OfferingCancelled = 18,
+    SoftCapNotReached = 19,
+    SoftCapAlreadyReached = 20,
+    RefundDeadlineNotReached = 21,
+    OfferingNotCancelled = 22,
+    NothingToRefund = 23,
+    RefundMustBurnAllNfts = 24,
+    CountdownNotStarted = 25,
+    InvalidLedgerTime = 26,
+    DistributionAlreadyEnded = 27,
+    DistributionNotReadyYet = 28,
+    DistributionBatchInProgress = 29,
+    InvalidBatchLimit = 30,
+    DistributionRemainderMismatch = 31,
+    NothingToClaim = 32,
+    NoDust = 33,
+    InvestmentNotEnded = 34,
+    RedemptionAlreadyFunded = 35,
+    RedemptionNotFunded = 36,
+    OwnerCannotRedeem = 37,
+    NoTokensToRedeem = 38,
+    NftTransfersNotAllowedDuringBuyingPhase = 39,
+    TradingClosedForRedemption = 40,
+    NotInvestorOrOwner = 41,
+    InsufficientFromNftBalance = 42,
+    SpenderNotApprovedForAll = 43,
+    UnsupportedStorageVersion = 46,
+    FunctionPaused = 47,
+    InvestorFrozen = 100,
+    KycExpired = 103,
+    LockedUp = 105,
+    TokenListed = 304,
+}
+
+/// Errors of the investor compliance entry points, added once `MinahError` got close to the 50 variants the spec allows.
+/// Every entry point only fails with the error enum it returns. A failure reported by several areas has a variant
+/// in each of their enums under the same code, so a code means the same failure whichever enum reports it.
+/// Codes of the compliance failures start at 100.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ComplianceError {
+    NotInitialized = 1,
+    UserNotAnInvestor = 9,
+    DistributionBatchInProgress = 29,
+    InvestorFrozen = 100,
+    InvestorNotFrozen = 101,
+    InvestorHasBalance = 102,
+    KycExpired = 103,
+    TransferRestricted = 104,
+    LockedUp = 105,
+    ReleasePending = 106,
+}
+
+/// Errors of the secondary market entry points, following the same rules as `ComplianceError`.
+/// Codes of the marketplace failures start at 300 as 200 to 212 are used by the NFT errors of `stellar-tokens`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MarketplaceError {
+    NotInitialized = 1,
+    UserNotAnInvestor = 9,
+    MaximumNftsPerInvestorExceeded = 13,
+    InsufficientBalance = 15,
+    InsufficientAllowance = 16,
+    OfferingCancelled = 18,
+    DistributionBatchInProgress = 29,
+    NftTransfersNotAllowedDuringBuyingPhase = 39,
+    TradingClosedForRedemption = 40,
+    NotInvestorOrOwner = 41,
+    InsufficientFromNftBalance = 42,
+    SpenderNotApprovedForAll = 43,
+    FunctionPaused = 47,
+    InvestorFrozen = 100,
+    KycExpired = 103,
+    LockedUp = 105,
+    IncorrectOwner = 201,
+    ListingNotFound = 300,
+    ListingExpired = 301,
+    InvalidListing = 302,
+    InvalidQuantity = 303,
+    TokenListed = 304,
+    OfferNotFound = 306,
+    OfferExpired = 307,
+    InvalidOffer = 308,
+    ContinuousOfferingClosed = 309,
+    PriceAboveLimit = 310,
+    InvalidPrice = 311,
+}
+
+/// Errors of the timelocked configuration changes, split from `MinahError` to make room in it.
+/// Failures shared with another enum keep their code there, as in `ComplianceError`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ConfigError {
+    NotInitialized = 1,
+    InvalidReleaseSchedule = 2,
+    InvestmentNotInBuyingPhase = 3,
+    ConfigLockedAfterFirstMint = 4,
+    NoPendingChange = 48,
+    ChangeNotReady = 49,
+    TradingFeeTooHigh = 305,
+}
+
+/// Failure of the storage helpers every area relies on, converted into the error enum of the calling entry point.
+struct NotInitialized;
+
+impl From<NotInitialized> for MinahError {
+    fn from(_: NotInitialized) -> Self {
+        MinahError::NotInitialized
+    }
+}
+
+impl From<NotInitialized> for ComplianceError {
+    fn from(_: NotInitialized) -> Self {
+        ComplianceError::NotInitialized
+    }
+}
+
+impl From<NotInitialized> for MarketplaceError {
+    fn from(_: NotInitialized) -> Self {
+        MarketplaceError::NotInitialized
+    }
+}
+
+/// Declares `TradeError` and its conversions into `MinahError` and `MarketplaceError`,
+/// which both have a variant of the same name and code for each failure.
+macro_rules! trade_errors {
+    ($($variant:ident),* $(,)?) => {
+        /// Failures of the trading checks shared by `buy_tokens`, `sell_tokens` and the marketplace entry points.
+        enum TradeError {
+            $($variant),*
+        }
+
+        impl From<TradeError> for MinahError {
+            fn from(err: TradeError) -> Self {
+                match err {
+                    $(TradeError::$variant => MinahError::$variant),*
+                }
+            }
+        }
+
+        impl From<TradeError> for MarketplaceError {
+            fn from(err: TradeError) -> Self {
+                match err {
+                    $(TradeError::$variant => MarketplaceError::$variant),*
+                }
+            }
+        }
+    };
+}
+
+trade_errors!(
+    NotInitialized,
+    MaximumNftsPerInvestorExceeded,
+    InsufficientBalance,
+    InsufficientAllowance,
+    OfferingCancelled,
+    DistributionBatchInProgress,
+    NftTransfersNotAllowedDuringBuyingPhase,
+    TradingClosedForRedemption,
+    NotInvestorOrOwner,
+    InsufficientFromNftBalance,
+    SpenderNotApprovedForAll,
+    FunctionPaused,
+    InvestorFrozen,
+    KycExpired,
+    LockedUp,
+    TokenListed,
+);
+
+impl From<NotInitialized> for TradeError {
+    fn from(_: NotInitialized) -> Self {
+        TradeError::NotInitialized
+    }
 }
 
 //////////////////////// EVENTS ////////////////////////////////
@@ -56,21 +457,193 @@ fn emit_investor_created_event(e: &Env, investor: Address) {
     e.events().publish(topics, ());
 }
 
+fn emit_investor_frozen_event(e: &Env, investor: Address) {
+    let topics = (Symbol::new(e, "InvestorFrozen"), investor);
+    e.events().publish(topics, ());
+}
+
+fn emit_investor_unfrozen_event(e: &Env, investor: Address, released_amount: i128) {
+    let topics = (Symbol::new(e, "InvestorUnfrozen"), investor);
+    e.events().publish(topics, released_amount);
+}
+
+fn emit_investor_removed_event(e: &Env, investor: Address, custodian: Option<Address>) {
+    let topics = (Symbol::new(e, "InvestorRemoved"), investor);
+    e.events().publish(topics, custodian);
+}
+
+fn emit_investor_kyc_updated_event(e: &Env, investor: Address, profile: InvestorProfile) {
+    let topics = (Symbol::new(e, "InvestorKycUpdated"), investor);
+    e.events()
+        .publish(topics, (profile.tier, profile.kyc_expires_at));
+}
+
+fn emit_investor_lock_up_set_event(e: &Env, investor: Address, period: u64) {
+    let topics = (Symbol::new(e, "InvestorLockUpSet"), investor);
+    e.events().publish(topics, period);
+}
+
 fn emit_started_chronometer_event(e: &Env) {
     let topics = (Symbol::new(e, "ChronometerStarted"),);
     e.events().publish(topics, ());
 }
 
-fn emit_tokens_bought_event(e: &Env, from: Address, to: Address, amount: u32) {
+fn emit_tokens_bought_event(e: &Env, from: Address, to: Address, amount: u32, fee: i128) {
     let topics = (Symbol::new(e, "TokensBought"), from, to);
-    e.events().publish(topics, amount);
+    e.events().publish(topics, (amount, fee));
 }
 
-fn emit_tokens_sold_event(e: &Env, from: Address, to: Address, amount: u32) {
+fn emit_tokens_sold_event(e: &Env, from: Address, to: Address, amount: u32, fee: i128) {
     let topics = (Symbol::new(e, "TokensSold"), from, to);
+    e.events().publish(topics, (amount, fee));
+}
+
+fn emit_soft_cap_reached_event(e: &Env, released_amount: i128) {
+    let topics = (Symbol::new(e, "SoftCapReached"),);
+    e.events().publish(topics, released_amount);
+}
+
+fn emit_offering_cancelled_event(e: &Env) {
+    let topics = (Symbol::new(e, "OfferingCancelled"),);
+    e.events().publish(topics, ());
+}
+
+fn emit_refunded_event(e: &Env, investor: Address, amount: i128) {
+    let topics = (Symbol::new(e, "Refunded"), investor);
+    e.events().publish(topics, amount);
+}
+
+fn emit_redemption_funded_event(e: &Env, nft_amount: u32, amount: i128) {
+    let topics = (Symbol::new(e, "RedemptionFunded"),);
+    e.events().publish(topics, (nft_amount, amount));
+}
+
+fn emit_redeemed_event(e: &Env, holder: Address, token_ids: Vec<u32>, amount: i128) {
+    let topics = (Symbol::new(e, "Redeemed"), holder);
+    e.events().publish(topics, (token_ids, amount));
+}
+
+fn emit_claimed_event(e: &Env, investor: Address, amount: i128) {
+    let topics = (Symbol::new(e, "Claimed"), investor);
+    e.events().publish(topics, amount);
+}
+
+fn emit_distribution_batch_processed_event(e: &Env, progress: &DistributionProgress) {
+    let topics = (Symbol::new(e, "DistributionBatchProcessed"), progress.stage);
+    e.events()
+        .publish(topics, (progress.next_investor, progress.investors_count));
+}
+
+fn emit_dust_swept_event(e: &Env, to: Address, amount: i128) {
+    let topics = (Symbol::new(e, "DustSwept"), to);
+    e.events().publish(topics, amount);
+}
+
+fn emit_dust_rolled_event(e: &Env, amount: i128) {
+    let topics = (Symbol::new(e, "DustRolled"),);
+    e.events().publish(topics, amount);
+}
+
+fn emit_upgraded_event(e: &Env, new_wasm_hash: BytesN<32>) {
+    let topics = (Symbol::new(e, "Upgraded"),);
+    e.events().publish(topics, new_wasm_hash);
+}
+
+fn emit_function_paused_event(e: &Env, function: PausableFunction) {
+    let topics = (Symbol::new(e, "Paused"), function);
+    e.events().publish(topics, ());
+}
+
+fn emit_function_unpaused_event(e: &Env, function: PausableFunction) {
+    let topics = (Symbol::new(e, "Unpaused"), function);
+    e.events().publish(topics, ());
+}
+
+fn emit_change_proposed_event(e: &Env, pending: PendingChange) {
+    let topics = (Symbol::new(e, "ChangeProposed"), pending.change.parameter());
+    e.events()
+        .publish(topics, (pending.change, pending.executable_at));
+}
+
+fn emit_change_executed_event(e: &Env, change: ConfigChange) {
+    let topics = (Symbol::new(e, "ChangeExecuted"), change.parameter());
+    e.events().publish(topics, change);
+}
+
+fn emit_change_cancelled_event(e: &Env, parameter: ConfigParameter) {
+    let topics = (Symbol::new(e, "ChangeCancelled"), parameter);
+    e.events().publish(topics, ());
+}
+
+fn emit_listed_event(e: &Env, listing_id: u32, listing: &Listing) {
+    let topics = (Symbol::new(e, "Listed"), listing_id, listing.seller.clone());
+    e.events().publish(
+        topics,
+        (
+            listing.token_ids.clone(),
+            listing.unit_price,
+            listing.expiry,
+        ),
+    );
+}
+
+fn emit_listing_filled_event(
+    e: &Env,
+    listing_id: u32,
+    buyer: Address,
+    token_ids: Vec<u32>,
+    total_price: i128,
+    fee: i128,
+) {
+    let topics = (Symbol::new(e, "ListingFilled"), listing_id, buyer);
+    e.events().publish(topics, (token_ids, total_price, fee));
+}
+
+fn emit_listing_cancelled_event(e: &Env, listing_id: u32) {
+    let topics = (Symbol::new(e, "ListingCancelled"), listing_id);
+    e.events().publish(topics, ());
+}
+
+fn emit_offer_made_event(e: &Env, offer_id: u32, offer: &Offer) {
+    let topics = (Symbol::new(e, "OfferMade"), offer_id, offer.buyer.clone());
+    e.events().publish(
+        topics,
+        (
+            offer.token_ids.clone(),
+            offer.quantity,
+            offer.unit_price,
+            offer.expiry,
+        ),
+    );
+}
+
+fn emit_offer_accepted_event(
+    e: &Env,
+    offer_id: u32,
+    seller: Address,
+    token_ids: Vec<u32>,
+    total_price: i128,
+    fee: i128,
+) {
+    let topics = (Symbol::new(e, "OfferAccepted"), offer_id, seller);
+    e.events().publish(topics, (token_ids, total_price, fee));
+}
+
+fn emit_offer_reclaimed_event(e: &Env, offer_id: u32, buyer: Address, amount: i128) {
+    let topics = (Symbol::new(e, "OfferReclaimed"), offer_id, buyer);
     e.events().publish(topics, amount);
 }
 
+fn emit_continuous_offering_updated_event(e: &Env, offering: Option<ContinuousOffering>) {
+    let topics = (Symbol::new(e, "ContinuousOfferingUpdated"),);
+    e.events().publish(topics, offering);
+}
+
+fn emit_inventory_sold_event(e: &Env, buyer: Address, token_ids: Vec<u32>, unit_price: i128) {
+    let topics = (Symbol::new(e, "InventorySold"), buyer);
+    e.events().publish(topics, (token_ids, unit_price));
+}
+
 fn emit_batch_transfer_event(e: &Env, from: &Address, to: &Address, token_ids: Vec<u32>) {
     let topics = (Symbol::new(e, "BatchTransfer"), from, to);
     e.events().publish(topics, token_ids);
@@ -82,183 +655,750 @@ pub struct Minah;
 // Constants
 const STABLECOIN_DECIMALS: u32 = 7;
 const STABLECOIN_SCALE: u32 = 10u32.pow(STABLECOIN_DECIMALS);
+// Version of the storage layout, bumped whenever a release changes how `DataKey` entries are stored.
+// 1: per-investor records and the investors array in instance storage, the baseline contract cannot be upgraded
+// 2: per-investor records, the investors index and the token index in persistent storage, access control roles
+const STORAGE_VERSION: u32 = 2;
+// Delay between proposing and executing a change of a `ConfigParameter`, until the admin changes it
+const DEFAULT_CHANGE_DELAY: u64 = 2 * 24 * 60 * 60;
+// Cap of the secondary market fee, in basis points of the trade price
+const MAX_TRADING_FEE_BPS: u32 = 1_000;
+const BPS_DENOMINATOR: i128 = 10_000;
+const DAY_IN_LEDGERS: u32 = 17280;
+// Per-investor entries are extended to ~30 days of ledgers whenever they are read or written
+const PERSISTENT_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_TTL_THRESHOLD: u32 = PERSISTENT_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+// The instance, holding the configuration and the offering state, is extended to ~30 days by the state-changing entry points
+const INSTANCE_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_TTL_THRESHOLD: u32 = INSTANCE_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+/// Extends the TTL of the contract instance and of its code.
+fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_EXTEND_AMOUNT);
+}
 
-#[contractimpl]
-impl Minah {
-    /// There is a limitation of max 10 params by the soroban contract
-    /// Initializes the Minah contract.
-    pub fn __constructor(
-        e: &Env,
-        owner: Address,
-        stablecoin: Address,
-        receiver: Address,
-        payer: Address,
-        price: i128,
-        total_supply: u32,
-        min_nfts_to_mint: u32,
-        max_nfts_per_investor: u32,
-        distribution_intervals: Vec<u64>,
-        roi_percentages: Vec<i128>,
-    ) {
-        // Ownner should authorize this call
-        owner.require_auth();
-
-        // ROI percentages length should be 10 because we have 10 release stages
-        assert_eq!(
-            roi_percentages.len(),
-            10,
-            "ROI_PERCENTAGES_LENGTH_MUST_BE_10"
-        );
-        assert_eq!(
-            distribution_intervals.len(),
-            10,
-            "DISTRIBUTION_INTERVALS_LENGTH_MUST_BE_10"
+/// Reads a per-investor or marketplace entry from persistent storage, extending its TTL when it exists.
+fn read_persistent<K: IntoVal<Env, Val>, V: TryFromVal<Env, Val>>(e: &Env, key: &K) -> Option<V> {
+    let value = e.storage().persistent().get(key);
+    if value.is_some() {
+        e.storage().persistent().extend_ttl(
+            key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_EXTEND_AMOUNT,
         );
+    }
+    value
+}
 
-        let uri = String::from_str(e, "");
-        let name = String::from_str(e, "Minah");
-        let symbol = String::from_str(e, "MNH");
+/// Unwraps the result of a check inside the NFT transfer functions, whose trait signature returns no error.
+fn unwrap_or_panic<T, E: Into<MinahError>>(e: &Env, result: Result<T, E>) -> T {
+    result.unwrap_or_else(|err| panic_with_error!(e, err.into()))
+}
 
-        Base::set_metadata(e, uri, name, symbol);
-        ownable::set_owner(e, &owner);
+/// Writes a per-investor or marketplace entry to persistent storage and extends its TTL.
+fn write_persistent<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(e: &Env, key: &K, value: &V) {
+    e.storage().persistent().set(key, value);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_EXTEND_AMOUNT);
+}
 
-        // Initialize Storage
-        e.storage()
-            .instance()
-            .set(&DataKey::StableCoin, &stablecoin);
-        e.storage().instance().set(&DataKey::Receiver, &receiver);
-        e.storage().instance().set(&DataKey::Payer, &payer);
-        e.storage().instance().set(&DataKey::CurrentSupply, &0u32);
-        e.storage().instance().set(&DataKey::BeginDate, &0u64);
-        e.storage()
-            .instance()
-            .set(&DataKey::AmountToReleaseForCurrentStage, &0i128);
-        e.storage().instance().set(&DataKey::CountdownStart, &false);
-        e.storage()
-            .instance()
-            .set(&DataKey::State, &InvestmentStatus::BuyingPhase);
+// The argument helpers generated for `__constructor` exceed clippy's argument count and cannot
+// carry an attribute of their own, so the constructor gets its own `contractimpl` block.
+#[allow(clippy::too_many_arguments)]
+mod constructor {
+    use super::*;
+
+    #[contractimpl]
+    impl Minah {
+        /// There is a limitation of max 10 params by the soroban contract
+        /// Initializes the Minah contract.
+        pub fn __constructor(
+            e: &Env,
+            owner: Address,
+            stablecoin: Address,
+            receiver: Address,
+            payer: Address,
+            price: i128,
+            total_supply: u32,
+            min_nfts_to_mint: u32,
+            max_nfts_per_investor: u32,
+            distribution_intervals: Vec<u64>,
+            roi_percentages: Vec<i128>,
+        ) -> Result<(), MinahError> {
+            extend_instance_ttl(e);
+
+            // Ownner should authorize this call
+            owner.require_auth();
+
+            if !Self::is_valid_release_schedule(&distribution_intervals, &roi_percentages) {
+                return Err(MinahError::InvalidReleaseSchedule);
+            }
 
-        let empty_investors: Vec<Address> = vec![&e];
-        e.storage()
-            .instance()
-            .set(&DataKey::InvestorsArray, &empty_investors);
+            let uri = String::from_str(e, "");
+            let name = String::from_str(e, "Minah");
+            let symbol = String::from_str(e, "MNH");
 
-        e.storage()
-            .instance()
-            .set(&DataKey::DistributionIntervals, &distribution_intervals);
+            Base::set_metadata(e, uri, name, symbol);
+            ownable::set_owner(e, &owner);
+            Self::grant_initial_roles(e, &owner);
 
-        e.storage()
-            .instance()
-            .set(&DataKey::ROIPercentages, &roi_percentages);
+            // Initialize Storage
+            e.storage()
+                .instance()
+                .set(&DataKey::StableCoin, &stablecoin);
+            e.storage().instance().set(&DataKey::Receiver, &receiver);
+            e.storage().instance().set(&DataKey::Payer, &payer);
+            e.storage().instance().set(&DataKey::CurrentSupply, &0u32);
+            e.storage().instance().set(&DataKey::BeginDate, &0u64);
+            e.storage()
+                .instance()
+                .set(&DataKey::AmountToReleaseForCurrentStage, &0i128);
+            e.storage().instance().set(&DataKey::CountdownStart, &false);
+            e.storage()
+                .instance()
+                .set(&DataKey::State, &InvestmentStatus::BuyingPhase);
 
-        e.storage().instance().set(&DataKey::Price, &price);
+            e.storage().instance().set(&DataKey::InvestorsCount, &0u32);
+            e.storage()
+                .instance()
+                .set(&DataKey::StorageVersion, &STORAGE_VERSION);
 
-        e.storage()
-            .instance()
-            .set(&DataKey::MinNFTsToMint, &min_nfts_to_mint);
+            e.storage()
+                .instance()
+                .set(&DataKey::DistributionIntervals, &distribution_intervals);
 
-        e.storage()
-            .instance()
-            .set(&DataKey::MaxNFTsPerInvestor, &max_nfts_per_investor);
+            e.storage()
+                .instance()
+                .set(&DataKey::ROIPercentages, &roi_percentages);
 
-        e.storage()
-            .instance()
-            .set(&DataKey::TotalSupply, &total_supply);
+            e.storage().instance().set(&DataKey::Price, &price);
 
-        e.storage()
-            .instance()
-            .set(&DataKey::NFTBuyingPhaseSupply, &0u32);
-    }
+            e.storage()
+                .instance()
+                .set(&DataKey::MinNFTsToMint, &min_nfts_to_mint);
 
-    /// Sets a new stablecoin address. Only the contract owner can call this function.
-    #[only_owner]
-    pub fn set_stablecoin(e: &Env, stablecoin: Address) {
-        e.storage()
-            .instance()
-            .set(&DataKey::StableCoin, &stablecoin);
-    }
+            e.storage()
+                .instance()
+                .set(&DataKey::MaxNFTsPerInvestor, &max_nfts_per_investor);
 
-    /// Creates a new investor.
-    /// Function called from the backend when a user creates a profile on the Minah platform
-    /// # Arguments
-    /// * `newInvestor` : the fireblocks address generated for the new user. To store in the backend.
-    #[only_owner]
-    pub fn create_investor(e: Env, new_investor: Address) {
-        // Check if investor already exists
-        let is_investor = e
-            .storage()
-            .instance()
-            .get(&DataKey::Investor(new_investor.clone()))
-            .unwrap_or(false);
+            e.storage()
+                .instance()
+                .set(&DataKey::TotalSupply, &total_supply);
 
-        assert!(!is_investor, "INVESTOR_ALREADY_EXISTS");
+            e.storage()
+                .instance()
+                .set(&DataKey::NFTBuyingPhaseSupply, &0u32);
 
-        // Add to investors mapping
-        e.storage()
-            .instance()
-            .set(&DataKey::Investor(new_investor.clone()), &true);
+            // No soft cap by default, mint proceeds go straight to the receiver
+            e.storage().instance().set(&DataKey::SoftCap, &0u32);
+            e.storage().instance().set(&DataKey::RefundDeadline, &0u64);
+            e.storage().instance().set(&DataKey::SoftCapReached, &true);
+            e.storage().instance().set(&DataKey::EscrowedAmount, &0i128);
 
-        // Add to investors array
-        let mut investors: Vec<Address> = e
-            .storage()
-            .instance()
-            .get(&DataKey::InvestorsArray)
-            .unwrap_or(vec![&e]);
-        investors.push_back(new_investor.clone());
-        e.storage()
-            .instance()
-            .set(&DataKey::InvestorsArray, &investors);
+            // The sale is open until the chronometer starts unless a sale window is configured
+            e.storage().instance().set(&DataKey::SaleStart, &0u64);
+            e.storage().instance().set(&DataKey::SaleEnd, &u64::MAX);
 
-        // Initialize claimed amount to 0
-        e.storage()
-            .instance()
-            .set(&DataKey::ClaimedAmount(new_investor.clone()), &0i128);
+            e.storage()
+                .instance()
+                .set(&DataKey::RedemptionFunded, &false);
+            e.storage().instance().set(&DataKey::RedeemedSupply, &0u32);
 
-        // Emit INVESTOR_CREATED event
-        emit_investor_created_event(&e, new_investor);
+            e.storage()
+                .instance()
+                .set(&DataKey::ChangeDelay, &DEFAULT_CHANGE_DELAY);
+
+            Ok(())
+        }
     }
+}
 
-    /// Mints a new NFT to the specified address.
-    pub fn mint(e: Env, user: Address, amount: u32) {
-        // User should authorize this call
-        user.require_auth();
+#[contractimpl]
+impl Minah {
+    /// Proposes a new value for a `ConfigParameter`. Only the admin can call this function.
+    /// The change can be executed once the current change delay has elapsed, giving investors time to
+    /// react. Proposing again for the same parameter replaces the pending change and restarts the delay.
+    /// # Arguments
+    /// * `change` : the parameter to change and its new value
+    #[only_admin]
+    pub fn propose_change(e: &Env, change: ConfigChange) -> Result<u64, ConfigError> {
+        extend_instance_ttl(e);
 
-        let min_nfts_to_mint: u32 = e
-            .storage()
-            .instance()
-            .get(&DataKey::MinNFTsToMint)
-            .expect("MinNFTsToMint not set");
+        // CHECK: The parameter is not frozen yet
+        Self::require_parameter_unlocked(e, change.parameter())?;
 
-        // CHECK: Amount should be >= MIN_NFTS_TO_MINT
-        assert!(amount >= min_nfts_to_mint, "MINIMUM_INVESTMENT_NOT_MET");
+        if let ConfigChange::ReleaseSchedule(distribution_intervals, roi_percentages) = &change {
+            if !Self::is_valid_release_schedule(distribution_intervals, roi_percentages) {
+                return Err(ConfigError::InvalidReleaseSchedule);
+            }
+        }
 
-        // CHECK: Current state should be BuyingPhase
-        let current_state: InvestmentStatus = e
-            .storage()
-            .instance()
-            .get(&DataKey::State)
-            .expect("State not set");
+        if let ConfigChange::TradingFee(fee_bps) = change {
+            if fee_bps > MAX_TRADING_FEE_BPS {
+                return Err(ConfigError::TradingFeeTooHigh);
+            }
+        }
+
+        let executable_at = e.ledger().timestamp() + Self::get_change_delay(e.clone());
 
-        assert!(
-            current_state == InvestmentStatus::BuyingPhase,
-            "INVESTMENT_NOT_IN_BUYING_PHASE"
+        let pending = PendingChange {
+            change,
+            executable_at,
+        };
+        e.storage().instance().set(
+            &DataKey::PendingChange(pending.change.parameter()),
+            &pending,
         );
 
-        // CHECK: User should be an investor
-        let is_investor = e
+        emit_change_proposed_event(e, pending);
+
+        Ok(executable_at)
+    }
+
+    /// Applies the pending change of a parameter once its delay has elapsed. Can be called by anyone.
+    pub fn execute_change(e: &Env, parameter: ConfigParameter) -> Result<(), ConfigError> {
+        extend_instance_ttl(e);
+
+        let pending: PendingChange = e
             .storage()
             .instance()
-            .get(&DataKey::Investor(user.clone()))
-            .unwrap_or(false);
+            .get(&DataKey::PendingChange(parameter))
+            .ok_or(ConfigError::NoPendingChange)?;
 
-        assert!(is_investor, "USER_NOT_AN_INVESTOR");
+        if e.ledger().timestamp() < pending.executable_at {
+            return Err(ConfigError::ChangeNotReady);
+        }
+
+        // CHECK: The parameter was not frozen while the change was pending
+        Self::require_parameter_unlocked(e, parameter)?;
+
+        match pending.change.clone() {
+            ConfigChange::StableCoin(stablecoin) => e
+                .storage()
+                .instance()
+                .set(&DataKey::StableCoin, &stablecoin),
+            ConfigChange::Receiver(receiver) => {
+                e.storage().instance().set(&DataKey::Receiver, &receiver)
+            }
+            ConfigChange::Payer(payer) => e.storage().instance().set(&DataKey::Payer, &payer),
+            ConfigChange::ChangeDelay(delay) => {
+                e.storage().instance().set(&DataKey::ChangeDelay, &delay)
+            }
+            ConfigChange::Price(price) => e.storage().instance().set(&DataKey::Price, &price),
+            ConfigChange::ReleaseSchedule(distribution_intervals, roi_percentages) => {
+                e.storage()
+                    .instance()
+                    .set(&DataKey::DistributionIntervals, &distribution_intervals);
+                e.storage()
+                    .instance()
+                    .set(&DataKey::ROIPercentages, &roi_percentages);
+            }
+            ConfigChange::TradingFee(fee_bps) => e
+                .storage()
+                .instance()
+                .set(&MarketplaceDataKey::TradingFeeBps, &fee_bps),
+            ConfigChange::FeeRecipient(Some(fee_recipient)) => e
+                .storage()
+                .instance()
+                .set(&MarketplaceDataKey::FeeRecipient, &fee_recipient),
+            ConfigChange::FeeRecipient(None) => e
+                .storage()
+                .instance()
+                .remove(&MarketplaceDataKey::FeeRecipient),
+            ConfigChange::AccruedRoiPricing(enabled) => e
+                .storage()
+                .instance()
+                .set(&MarketplaceDataKey::AccruedRoiPricing, &enabled),
+            ConfigChange::LockUpPeriod(period) => {
+                e.storage().instance().set(&DataKey::LockUpPeriod, &period)
+            }
+        }
+        e.storage()
+            .instance()
+            .remove(&DataKey::PendingChange(parameter));
+
+        emit_change_executed_event(e, pending.change);
+
+        Ok(())
+    }
+
+    /// Drops the pending change of a parameter. Only the admin can call this function.
+    #[only_admin]
+    pub fn cancel_change(e: &Env, parameter: ConfigParameter) -> Result<(), ConfigError> {
+        extend_instance_ttl(e);
+
+        if !e
+            .storage()
+            .instance()
+            .has(&DataKey::PendingChange(parameter))
+        {
+            return Err(ConfigError::NoPendingChange);
+        }
+
+        e.storage()
+            .instance()
+            .remove(&DataKey::PendingChange(parameter));
+
+        emit_change_cancelled_event(e, parameter);
+
+        Ok(())
+    }
+
+    /// Sets the soft cap of the offering. Only the admin can call this function.
+    /// Mint proceeds are escrowed in the contract until `soft_cap` NFTs are sold. If the soft cap is not
+    /// reached by `refund_deadline` the offering can be cancelled and investors refunded.
+    /// Must be called before the first mint.
+    /// # Arguments
+    /// * `soft_cap` : minimum number of NFTs to sell during the buying phase (0 disables the soft cap)
+    /// * `refund_deadline` : ledger timestamp after which the offering can be cancelled if the soft cap is not reached
+    #[only_admin]
+    pub fn set_soft_cap(e: &Env, soft_cap: u32, refund_deadline: u64) -> Result<(), MinahError> {
+        extend_instance_ttl(e);
+
+        let current_state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if current_state != InvestmentStatus::BuyingPhase {
+            return Err(MinahError::InvestmentNotInBuyingPhase);
+        }
+
+        let current_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentSupply)
+            .unwrap_or(0);
+
+        // Proceeds of previous mints were already sent to the receiver so they could not be refunded
+        if current_supply != 0 {
+            return Err(MinahError::ConfigLockedAfterFirstMint);
+        }
+
+        let total_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if soft_cap > total_supply {
+            return Err(MinahError::SoftCapExceedsTotalSupply);
+        }
+
+        if soft_cap > 0 && refund_deadline <= e.ledger().timestamp() {
+            return Err(MinahError::InvalidRefundDeadline);
+        }
+
+        e.storage().instance().set(&DataKey::SoftCap, &soft_cap);
+        e.storage()
+            .instance()
+            .set(&DataKey::RefundDeadline, &refund_deadline);
+        e.storage()
+            .instance()
+            .set(&DataKey::SoftCapReached, &(soft_cap == 0));
+
+        Ok(())
+    }
+
+    /// Sets the buying phase window. Only the admin can call this function.
+    /// `mint` is only allowed between `sale_start` (inclusive) and `sale_end` (exclusive), and anyone can start
+    /// the chronometer once `sale_end` has passed. Must be called before the first mint.
+    /// # Arguments
+    /// * `sale_start` : ledger timestamp at which the sale opens
+    /// * `sale_end` : ledger timestamp at which the sale closes
+    #[only_admin]
+    pub fn set_sale_window(e: &Env, sale_start: u64, sale_end: u64) -> Result<(), MinahError> {
+        extend_instance_ttl(e);
+
+        let current_state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if current_state != InvestmentStatus::BuyingPhase {
+            return Err(MinahError::InvestmentNotInBuyingPhase);
+        }
+
+        let current_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentSupply)
+            .unwrap_or(0);
+
+        if current_supply != 0 {
+            return Err(MinahError::ConfigLockedAfterFirstMint);
+        }
+
+        if sale_start >= sale_end {
+            return Err(MinahError::InvalidSaleWindow);
+        }
+
+        e.storage().instance().set(&DataKey::SaleStart, &sale_start);
+        e.storage().instance().set(&DataKey::SaleEnd, &sale_end);
+
+        Ok(())
+    }
+
+    /// Creates a new investor.
+    /// Function called from the backend when a user creates a profile on the Minah platform
+    /// # Arguments
+    /// * `caller` : an account holding the compliance role
+    /// * `newInvestor` : the fireblocks address generated for the new user. To store in the backend.
+    #[only_role(caller, "compliance")]
+    pub fn create_investor(
+        e: Env,
+        caller: Address,
+        new_investor: Address,
+    ) -> Result<(), MinahError> {
+        extend_instance_ttl(&e);
+
+        // Check if investor already exists
+        let is_investor =
+            read_persistent(&e, &DataKey::Investor(new_investor.clone())).unwrap_or(false);
+
+        if is_investor {
+            return Err(MinahError::InvestorAlreadyExists);
+        }
+
+        // Add to investors mapping
+        write_persistent(&e, &DataKey::Investor(new_investor.clone()), &true);
+
+        // Add to investors index
+        let investors_count = Self::get_investors_array_length(e.clone());
+        write_persistent(&e, &DataKey::InvestorAt(investors_count), &new_investor);
+        write_persistent(
+            &e,
+            &DataKey::InvestorIndex(new_investor.clone()),
+            &investors_count,
+        );
+        e.storage()
+            .instance()
+            .set(&DataKey::InvestorsCount, &(investors_count + 1));
+
+        // Initialize claimable, claimed and redeemed amounts to 0, a removed investor added back keeps them
+        for key in [
+            DataKey::ClaimableAmount(new_investor.clone()),
+            DataKey::ClaimedAmount(new_investor.clone()),
+            DataKey::RedeemedAmount(new_investor.clone()),
+        ] {
+            if !e.storage().persistent().has(&key) {
+                write_persistent(&e, &key, &0i128);
+            }
+        }
+
+        // Emit INVESTOR_CREATED event
+        emit_investor_created_event(&e, new_investor);
+
+        Ok(())
+    }
+
+    /// Records the tier and KYC expiry of an investor, also used to renew an expired KYC.
+    /// Once `kyc_expires_at` has passed the investor can no longer mint or receive NFTs on the marketplace.
+    /// # Arguments
+    /// * `caller` : an account holding the compliance role
+    /// * `investor` : the investor whose KYC was checked
+    /// * `tier` : the tier setting the maximum holding of the investor
+    /// * `kyc_expires_at` : ledger timestamp from which the KYC has to be renewed
+    #[only_role(caller, "compliance")]
+    pub fn set_investor_kyc(
+        e: Env,
+        caller: Address,
+        investor: Address,
+        tier: InvestorTier,
+        kyc_expires_at: u64,
+    ) -> Result<(), ComplianceError> {
+        extend_instance_ttl(&e);
+
+        if !Self::is_investor(&e, investor.clone()) {
+            return Err(ComplianceError::UserNotAnInvestor);
+        }
+
+        let profile = InvestorProfile {
+            tier,
+            kyc_expires_at,
+        };
+        write_persistent(&e, &DataKey::InvestorProfile(investor.clone()), &profile);
+
+        // Emit INVESTOR_KYC_UPDATED event
+        emit_investor_kyc_updated_event(&e, investor, profile);
+
+        Ok(())
+    }
+
+    /// Sets the lock-up of an investor, applied from each of their mints on top of the offering lock-up.
+    /// Their NFTs cannot be sold or transferred until `period` seconds after their latest mint.
+    /// # Arguments
+    /// * `caller` : an account holding the compliance role
+    /// * `investor` : the investor to lock up
+    /// * `period` : lock-up in seconds, `0` to stop locking up later mints
+    #[only_role(caller, "compliance")]
+    pub fn set_investor_lock_up(
+        e: Env,
+        caller: Address,
+        investor: Address,
+        period: u64,
+    ) -> Result<(), ComplianceError> {
+        extend_instance_ttl(&e);
+
+        if !Self::is_investor(&e, investor.clone()) {
+            return Err(ComplianceError::UserNotAnInvestor);
+        }
+
+        write_persistent(&e, &DataKey::InvestorLockUp(investor.clone()), &period);
+
+        // Emit INVESTOR_LOCK_UP_SET event
+        emit_investor_lock_up_set_event(&e, investor, period);
+
+        Ok(())
+    }
+
+    /// Sets the maximum number of NFTs an investor of a tier can hold. Only the admin can call this function.
+    #[only_admin]
+    pub fn set_tier_cap(e: &Env, tier: InvestorTier, max_nfts: u32) {
+        extend_instance_ttl(e);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::TierCap(tier), &max_nfts);
+    }
+
+    /// Freezes an investor, for instance for AML reasons.
+    /// A frozen investor cannot mint, trade, claim, refund or redeem, and the ROI released to them
+    /// is held in escrow by the contract until they are unfrozen.
+    /// # Arguments
+    /// * `caller` : an account holding the compliance role
+    /// * `investor` : the investor to freeze
+    #[only_role(caller, "compliance")]
+    pub fn freeze_investor(
+        e: Env,
+        caller: Address,
+        investor: Address,
+    ) -> Result<(), ComplianceError> {
+        extend_instance_ttl(&e);
+
+        if !Self::is_investor(&e, investor.clone()) {
+            return Err(ComplianceError::UserNotAnInvestor);
+        }
+
+        if Self::is_investor_frozen(e.clone(), investor.clone()) {
+            return Err(ComplianceError::InvestorFrozen);
+        }
+
+        write_persistent(&e, &DataKey::InvestorFrozen(investor.clone()), &true);
+
+        // Emit INVESTOR_FROZEN event
+        emit_investor_frozen_event(&e, investor);
+
+        Ok(())
+    }
+
+    /// Unfreezes an investor and releases the ROI held in escrow while they were frozen to their claimable amount.
+    /// Also works for an investor removed while frozen.
+    /// # Arguments
+    /// * `caller` : an account holding the compliance role
+    /// * `investor` : the investor to unfreeze
+    #[only_role(caller, "compliance")]
+    pub fn unfreeze_investor(
+        e: Env,
+        caller: Address,
+        investor: Address,
+    ) -> Result<i128, ComplianceError> {
+        extend_instance_ttl(&e);
+
+        if !Self::is_investor_frozen(e.clone(), investor.clone()) {
+            return Err(ComplianceError::InvestorNotFrozen);
+        }
+
+        let frozen_amount = Self::get_frozen_amount(e.clone(), investor.clone());
+        let claimable: i128 =
+            read_persistent(&e, &DataKey::ClaimableAmount(investor.clone())).unwrap_or(0);
+
+        write_persistent(
+            &e,
+            &DataKey::ClaimableAmount(investor.clone()),
+            &(claimable + frozen_amount),
+        );
+        e.storage()
+            .persistent()
+            .remove(&DataKey::FrozenAmount(investor.clone()));
+        e.storage()
+            .persistent()
+            .remove(&DataKey::InvestorFrozen(investor.clone()));
+
+        // Emit INVESTOR_UNFROZEN event
+        emit_investor_unfrozen_event(&e, investor, frozen_amount);
+
+        Ok(frozen_amount)
+    }
+
+    /// Removes an investor from the registry so they no longer take part in distributions.
+    /// The investor must not hold any NFT once `token_ids` have been moved to `custodian`.
+    /// Their claimed, claimable and escrowed amounts are kept: a frozen investor stays frozen.
+    /// Fails with `ComplianceError::ReleasePending` while a stage is due but not released yet.
+    /// # Arguments
+    /// * `caller` : an account holding the compliance role
+    /// * `investor` : the investor to remove
+    /// * `custodian` : a registered investor receiving `token_ids`, required when the investor still holds NFTs
+    /// * `token_ids` : the tokens of the investor to move to the custodian
+    #[only_role(caller, "compliance")]
+    pub fn remove_investor(
+        e: Env,
+        caller: Address,
+        investor: Address,
+        custodian: Option<Address>,
+        token_ids: Vec<u32>,
+    ) -> Result<(), ComplianceError> {
+        extend_instance_ttl(&e);
+
+        if !Self::is_investor(&e, investor.clone()) {
+            return Err(ComplianceError::UserNotAnInvestor);
+        }
+
+        // CHECK: The investors index is not being walked page by page
+        if e.storage().instance().has(&DataKey::DistributionProgress) {
+            return Err(ComplianceError::DistributionBatchInProgress);
+        }
+
+        // CHECK: No stage is due and waiting for its release, the investor takes part in it from their
+        // balance at the due date, which the custodian does not inherit
+        if let Some(InvestmentStatus::Distributing(next_stage)) =
+            e.storage().instance().get(&DataKey::State)
+        {
+            if Self::current_period(&e)? > next_stage {
+                return Err(ComplianceError::ReleasePending);
+            }
+        }
+
+        // DO: Force the transfer of the given tokens to the custodian
+        if !token_ids.is_empty() {
+            let custodian = match &custodian {
+                Some(custodian) => custodian,
+                None => return Err(ComplianceError::InvestorHasBalance),
+            };
+
+            if !Self::is_investor(&e, custodian.clone()) {
+                return Err(ComplianceError::UserNotAnInvestor);
+            }
+
+            // Listed tokens are taken out of their listing
+            for token_id in token_ids.iter() {
+                Self::unlist_token(&e, token_id);
+            }
+
+            Self::move_tokens(&e, &investor, custodian, token_ids)?;
+        }
+
+        if Base::balance(&e, &investor) != 0 {
+            return Err(ComplianceError::InvestorHasBalance);
+        }
+
+        // DO: Move the last investor of the index into the slot of the removed one
+        let investors_count = Self::get_investors_array_length(e.clone());
+        let last_index = investors_count - 1;
+        let index: u32 = read_persistent(&e, &DataKey::InvestorIndex(investor.clone()))
+            .ok_or(ComplianceError::NotInitialized)?;
+
+        if index != last_index {
+            let last_investor: Address = read_persistent(&e, &DataKey::InvestorAt(last_index))
+                .ok_or(ComplianceError::NotInitialized)?;
+            write_persistent(&e, &DataKey::InvestorAt(index), &last_investor);
+            write_persistent(&e, &DataKey::InvestorIndex(last_investor), &index);
+        }
+
+        e.storage()
+            .persistent()
+            .remove(&DataKey::InvestorAt(last_index));
+        e.storage()
+            .persistent()
+            .remove(&DataKey::InvestorIndex(investor.clone()));
+        e.storage()
+            .instance()
+            .set(&DataKey::InvestorsCount, &last_index);
+        e.storage()
+            .persistent()
+            .remove(&DataKey::Investor(investor.clone()));
+
+        // Emit INVESTOR_REMOVED event
+        emit_investor_removed_event(&e, investor, custodian);
+
+        Ok(())
+    }
+
+    /// Mints a new NFT to the specified address.
+    pub fn mint(e: Env, user: Address, amount: u32) -> Result<(), MinahError> {
+        extend_instance_ttl(&e);
+
+        // User should authorize this call
+        user.require_auth();
+
+        // CHECK: Minting is not paused
+        Self::require_not_paused(&e, PausableFunction::Mint)?;
+
+        // CHECK: User is not frozen
+        Self::require_not_frozen(&e, &user)?;
+
+        let min_nfts_to_mint: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::MinNFTsToMint)
+            .ok_or(MinahError::NotInitialized)?;
+
+        // CHECK: Amount should be >= MIN_NFTS_TO_MINT
+        if amount < min_nfts_to_mint {
+            return Err(MinahError::MinimumInvestmentNotMet);
+        }
+
+        // CHECK: Current state should be BuyingPhase
+        let current_state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if current_state != InvestmentStatus::BuyingPhase {
+            return Err(MinahError::InvestmentNotInBuyingPhase);
+        }
+
+        // CHECK: Sale window should be open
+        let sale_start: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::SaleStart)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let sale_end: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::SaleEnd)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let current_time = e.ledger().timestamp();
+
+        if current_time < sale_start {
+            return Err(MinahError::SaleNotOpen);
+        }
+        if current_time >= sale_end {
+            return Err(MinahError::SaleClosed);
+        }
+
+        // CHECK: User should be an investor
+        let is_investor = read_persistent(&e, &DataKey::Investor(user.clone())).unwrap_or(false);
+
+        if !is_investor {
+            return Err(MinahError::UserNotAnInvestor);
+        }
 
         // CHECK: Total supply should not be exceeded
         let total_supply: u32 = e
             .storage()
             .instance()
             .get(&DataKey::TotalSupply)
-            .expect("TotalSupply not set");
+            .ok_or(MinahError::NotInitialized)?;
 
         let current_supply: u32 = e
             .storage()
@@ -268,730 +1408,3146 @@ impl Minah {
 
         let new_supply = current_supply + amount;
 
-        assert!(new_supply <= total_supply, "MAXIMUM_SUPPLY_EXCEEDED");
+        if new_supply > total_supply {
+            return Err(MinahError::MaximumSupplyExceeded);
+        }
+
+        // CHECK: KYC is valid and investor NFTs should not exceed the cap of their tier
+        Self::require_can_receive(&e, &user, amount)?;
+
+        let price: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::Price)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let usd_amount = price * amount as i128 * STABLECOIN_SCALE as i128;
+
+        // CHECK: User has enough balance of stablecoin
+        let stablecoin_address: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
+
+        let user_balance = stablecoin_client.balance(&user);
+
+        if user_balance < usd_amount {
+            return Err(MinahError::InsufficientBalance);
+        }
+
+        // CHECK: User has enough allowance of stablecoin
+        let current_address = e.current_contract_address();
 
-        // CHECK: Investor NFTS should not exceed MAX_NFTS_PER_INVESTOR
-        let investor_balance = Self::balance(&e, user.clone());
+        let user_allowance = stablecoin_client.allowance(&user, &current_address);
+
+        if user_allowance < usd_amount {
+            return Err(MinahError::InsufficientAllowance);
+        }
+
+        let receiver: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Receiver)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let soft_cap_reached: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::SoftCapReached)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if soft_cap_reached {
+            // Do the transfer of stablecoin from user to the receiver address
+            // NOTE: The user must have approved the contract to spend the stablecoin on their behalf
+            stablecoin_client.transfer_from(&current_address, &user, &receiver, &usd_amount);
+        } else {
+            // Keep the stablecoin in the contract until the soft cap is reached
+            stablecoin_client.transfer_from(&current_address, &user, &current_address, &usd_amount);
+
+            let escrowed_amount: i128 = e
+                .storage()
+                .instance()
+                .get(&DataKey::EscrowedAmount)
+                .unwrap_or(0);
+
+            e.storage()
+                .instance()
+                .set(&DataKey::EscrowedAmount, &(escrowed_amount + usd_amount));
+        }
+
+        // Keep track of what the user paid so it can be refunded if the offering is cancelled
+        let paid_amount: i128 =
+            read_persistent(&e, &DataKey::PaidAmount(user.clone())).unwrap_or(0);
+
+        write_persistent(
+            &e,
+            &DataKey::PaidAmount(user.clone()),
+            &(paid_amount + usd_amount),
+        );
+
+        // Update current supply to new supply
+        e.storage()
+            .instance()
+            .set(&DataKey::CurrentSupply, &new_supply);
+
+        let soft_cap: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::SoftCap)
+            .ok_or(MinahError::NotInitialized)?;
+
+        // Release the escrowed proceeds to the receiver once the soft cap is reached
+        if !soft_cap_reached && new_supply >= soft_cap {
+            let escrowed_amount: i128 = e
+                .storage()
+                .instance()
+                .get(&DataKey::EscrowedAmount)
+                .unwrap_or(0);
+
+            stablecoin_client.transfer(&current_address, &receiver, &escrowed_amount);
+
+            e.storage().instance().set(&DataKey::EscrowedAmount, &0i128);
+            e.storage().instance().set(&DataKey::SoftCapReached, &true);
+
+            emit_soft_cap_reached_event(&e, escrowed_amount);
+        }
+
+        // Start the lock-up of the investor, if any
+        let lock_up: u64 = read_persistent(&e, &DataKey::InvestorLockUp(user.clone())).unwrap_or(0);
+
+        if lock_up > 0 {
+            let locked_until: u64 =
+                read_persistent(&e, &DataKey::LockedUntil(user.clone())).unwrap_or(0);
+            let new_locked_until = e.ledger().timestamp() + lock_up;
+
+            if new_locked_until > locked_until {
+                write_persistent(&e, &DataKey::LockedUntil(user.clone()), &new_locked_until);
+            }
+        }
+
+        // Mint the requested amount of NFTs to the specified address
+        let last_id = Consecutive::batch_mint(&e, &user, amount);
+        Self::index_minted_tokens(&e, &user, last_id + 1 - amount, last_id + 1);
+        Self::checkpoint_balance(&e, &user)?;
+
+        Ok(())
+    }
+
+    /// Start the chronometer for ROI distribution
+    /// The admin can start it at any time, anyone else can start it once the sale window is closed or the supply is sold out.
+    pub fn start_chronometer(e: Env) -> Result<(), MinahError> {
+        extend_instance_ttl(&e);
+
+        let countdown_start: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::CountdownStart)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let begin_date: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::BeginDate)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if countdown_start || begin_date != 0 {
+            return Err(MinahError::ChronometerAlreadyStarted);
+        }
+
+        let current_state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if current_state == InvestmentStatus::Cancelled {
+            return Err(MinahError::OfferingCancelled);
+        }
+
+        let soft_cap_reached: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::SoftCapReached)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if !soft_cap_reached {
+            return Err(MinahError::SoftCapNotReached);
+        }
+
+        let sale_end: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::SaleEnd)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let total_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let current_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentSupply)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let sale_closed = e.ledger().timestamp() >= sale_end || current_supply == total_supply;
+
+        // Before the sale is closed only the admin can end the buying phase
+        if !sale_closed {
+            access_control::enforce_admin_auth(&e);
+        }
+
+        // Set begin date and countdown
+        e.storage()
+            .instance()
+            .set(&DataKey::BeginDate, &e.ledger().timestamp());
+        e.storage().instance().set(&DataKey::CountdownStart, &true);
+        e.storage()
+            .instance()
+            .set(&DataKey::State, &InvestmentStatus::Distributing(0));
+
+        // Update NFTBuyingPhaseSupply to current supply
+        e.storage()
+            .instance()
+            .set(&DataKey::NFTBuyingPhaseSupply, &current_supply);
+
+        let remaining = total_supply - current_supply;
+
+        // Update current supply to total supply
+        e.storage()
+            .instance()
+            .set(&DataKey::CurrentSupply, &total_supply);
+
+        // The unsold NFTs form the owner inventory, sold through the continuous offering
+        e.storage()
+            .instance()
+            .set(&MarketplaceDataKey::InventoryCursor, &current_supply);
+        e.storage()
+            .instance()
+            .set(&MarketplaceDataKey::InventorySupply, &remaining);
+
+        if remaining > 0 {
+            // Mint the remaining amount of NFTs to the owner
+            let owner = ownable::get_owner(&e).ok_or(MinahError::NotInitialized)?;
+
+            let last_id = Consecutive::batch_mint(&e, &owner, remaining);
+            Self::index_minted_tokens(&e, &owner, last_id + 1 - remaining, last_id + 1);
+            Self::checkpoint_balance(&e, &owner)?;
+        }
+
+        // Emit CHRONOMETER_STARTED event
+        emit_started_chronometer_event(&e);
+
+        Ok(())
+    }
+
+    /// Cancels the offering when the soft cap was not reached by the refund deadline.
+    /// Anyone can call this function once the deadline has passed, investors then get their stablecoin back through `refund`.
+    pub fn cancel_offering(e: Env) -> Result<(), MinahError> {
+        extend_instance_ttl(&e);
+
+        let current_state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if current_state != InvestmentStatus::BuyingPhase {
+            return Err(MinahError::InvestmentNotInBuyingPhase);
+        }
+
+        let soft_cap_reached: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::SoftCapReached)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if soft_cap_reached {
+            return Err(MinahError::SoftCapAlreadyReached);
+        }
+
+        let refund_deadline: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::RefundDeadline)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if e.ledger().timestamp() < refund_deadline {
+            return Err(MinahError::RefundDeadlineNotReached);
+        }
+
+        e.storage()
+            .instance()
+            .set(&DataKey::State, &InvestmentStatus::Cancelled);
+
+        // Emit OFFERING_CANCELLED event
+        emit_offering_cancelled_event(&e);
+
+        Ok(())
+    }
+
+    /// Refunds an investor of a cancelled offering.
+    /// The investor gets back everything paid during the buying phase and all their NFTs are burned.
+    /// # Arguments
+    /// * `investor` : the investor to refund
+    /// * `token_ids` : all the token ids owned by the investor
+    pub fn refund(e: Env, investor: Address, token_ids: Vec<u32>) -> Result<(), MinahError> {
+        extend_instance_ttl(&e);
+
+        // Investor should authorize this call
+        investor.require_auth();
+
+        // CHECK: Investor is not frozen
+        Self::require_not_frozen(&e, &investor)?;
+
+        let current_state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if current_state != InvestmentStatus::Cancelled {
+            return Err(MinahError::OfferingNotCancelled);
+        }
+
+        let paid_amount: i128 =
+            read_persistent(&e, &DataKey::PaidAmount(investor.clone())).unwrap_or(0);
+
+        if paid_amount <= 0 {
+            return Err(MinahError::NothingToRefund);
+        }
+
+        // CHECK: The refund covers the whole position of the investor
+        let investor_balance = Self::balance(&e, investor.clone());
+
+        if token_ids.len() != investor_balance {
+            return Err(MinahError::RefundMustBurnAllNfts);
+        }
+
+        // DO: Burn the NFTs, this also checks the investor owns every token
+        Self::batch_burn(&e, &investor, token_ids)?;
+
+        let current_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentSupply)
+            .unwrap_or(0);
+
+        e.storage().instance().set(
+            &DataKey::CurrentSupply,
+            &(current_supply - investor_balance),
+        );
+
+        let escrowed_amount: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::EscrowedAmount)
+            .unwrap_or(0);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::EscrowedAmount, &(escrowed_amount - paid_amount));
+
+        write_persistent(&e, &DataKey::PaidAmount(investor.clone()), &0i128);
+
+        // DO: Send the escrowed stablecoin back to the investor
+        let stablecoin_address: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
+
+        stablecoin_client.transfer(&e.current_contract_address(), &investor, &paid_amount);
+
+        // Emit REFUNDED event
+        emit_refunded_event(&e, investor, paid_amount);
+
+        Ok(())
+    }
+
+    /// Calculate amount to release for a given percentage
+    /// Function to know how much to approve() on the STABLECOIN smart contract before releasing the amount to all investors.
+    /// Arguments:
+    /// * `percentage`: the percentage of ROI to be released for the current stage.(Scaled by 10_000_000 to handle decimal percentages)
+    pub fn calculate_amount_to_release(e: Env, percent: i128) -> Result<i128, MinahError> {
+        // NFTs held by investors: the outstanding supply, kept up to date by mints and burns,
+        // less the owner inventory, so the quote does not grow with the number of investors
+        let current_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentSupply)
+            .unwrap_or(0);
+
+        let owner = ownable::get_owner(&e).ok_or(MinahError::NotInitialized)?;
+
+        let mut total_invested_nfts = current_supply as i128;
+
+        if !Self::is_investor(&e, owner.clone()) {
+            total_invested_nfts -= Base::balance(&e, &owner) as i128;
+        }
+
+        let price: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::Price)
+            .ok_or(MinahError::NotInitialized)?;
+
+        Ok(total_invested_nfts * price * percent / 100)
+    }
+
+    /// Releases the distribution for the current stage.
+    /// This function needs to be called by the treasury at the end of every distribution period/stage to trigger the current release and next stage.
+    #[only_role(caller, "treasury")]
+    pub fn release_distribution(e: &Env, caller: Address) -> Result<(), MinahError> {
+        extend_instance_ttl(e);
+
+        // CHECK: Releases are not paused
+        Self::require_not_paused(e, PausableFunction::ReleaseDistribution)?;
+
+        // CHECK: No stage is being released page by page
+        if e.storage().instance().has(&DataKey::DistributionProgress) {
+            return Err(MinahError::DistributionBatchInProgress);
+        }
+
+        let elapsed = Self::elapsed_since_begin(e)?;
+
+        let state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
+
+        // Index of the next stage to release, the countdown check above guarantees we are past the buying phase
+        let mut current_stage_index = match state {
+            InvestmentStatus::Distributing(stage) => stage,
+            _ => return Err(MinahError::DistributionAlreadyEnded),
+        };
+        let mut distributed = false;
+
+        let distribution_intervals: Vec<u64> = e
+            .storage()
+            .instance()
+            .get(&DataKey::DistributionIntervals)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let roi_percentages: Vec<i128> = e
+            .storage()
+            .instance()
+            .get(&DataKey::ROIPercentages)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let stage_count = distribution_intervals.len();
+
+        // Loop through all stages that are ready for distribution
+        while current_stage_index < stage_count
+            && elapsed
+                >= distribution_intervals
+                    .get(current_stage_index)
+                    .ok_or(MinahError::NotInitialized)?
+        {
+            // Distribute for this stage
+            Self::distribute(
+                e,
+                current_stage_index,
+                roi_percentages
+                    .get(current_stage_index)
+                    .ok_or(MinahError::NotInitialized)?,
+            )?;
+
+            current_stage_index += 1;
+            distributed = true;
+        }
+
+        if !distributed {
+            return Err(MinahError::DistributionNotReadyYet);
+        }
+
+        // Move to the next stage, or end the investment once the whole schedule has been released
+        let next_state = if current_stage_index == stage_count {
+            InvestmentStatus::Ended
+        } else {
+            InvestmentStatus::Distributing(current_stage_index)
+        };
+
+        e.storage().instance().set(&DataKey::State, &next_state);
+
+        Ok(())
+    }
+
+    /// Releases the next ready stage for at most `limit` investors.
+    /// Progress is persisted between calls so large investor sets can be processed over several transactions,
+    /// the stage is funded and the state advanced only when the last page completes.
+    /// Returns true once the stage has been fully released.
+    /// # Arguments
+    /// * `caller` : an account holding the treasury role
+    /// * `limit` : maximum number of investors to process in this call
+    #[only_role(caller, "treasury")]
+    pub fn release_distribution_batch(
+        e: &Env,
+        caller: Address,
+        limit: u32,
+    ) -> Result<bool, MinahError> {
+        extend_instance_ttl(e);
+
+        if limit == 0 {
+            return Err(MinahError::InvalidBatchLimit);
+        }
+
+        // CHECK: Releases are not paused
+        Self::require_not_paused(e, PausableFunction::ReleaseDistribution)?;
+
+        let roi_percentages: Vec<i128> = e
+            .storage()
+            .instance()
+            .get(&DataKey::ROIPercentages)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let mut progress = match e
+            .storage()
+            .instance()
+            .get::<_, DistributionProgress>(&DataKey::DistributionProgress)
+        {
+            Some(progress) => progress,
+            None => {
+                // Starting a new stage: same checks as release_distribution()
+                let elapsed = Self::elapsed_since_begin(e)?;
+
+                let state: InvestmentStatus = e
+                    .storage()
+                    .instance()
+                    .get(&DataKey::State)
+                    .ok_or(MinahError::NotInitialized)?;
+
+                let stage = match state {
+                    InvestmentStatus::Distributing(stage) => stage,
+                    _ => return Err(MinahError::DistributionAlreadyEnded),
+                };
+
+                let distribution_intervals: Vec<u64> = e
+                    .storage()
+                    .instance()
+                    .get(&DataKey::DistributionIntervals)
+                    .ok_or(MinahError::NotInitialized)?;
+
+                if elapsed
+                    < distribution_intervals
+                        .get(stage)
+                        .ok_or(MinahError::NotInitialized)?
+                {
+                    return Err(MinahError::DistributionNotReadyYet);
+                }
+
+                DistributionProgress {
+                    stage,
+                    next_investor: 0,
+                    investors_count: Self::get_investors_array_length(e.clone()),
+                    distributed_nfts: 0,
+                    distributed_amount: 0,
+                }
+            }
+        };
+
+        let price: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::Price)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let percent = roi_percentages
+            .get(progress.stage)
+            .ok_or(MinahError::NotInitialized)?;
+
+        // DO: Credit the next page of investors
+        let end = progress
+            .next_investor
+            .saturating_add(limit)
+            .min(progress.investors_count);
+
+        let (nfts, amount) = Self::credit_investors(
+            e,
+            progress.stage,
+            progress.next_investor,
+            end,
+            price,
+            percent,
+        )?;
+
+        progress.next_investor = end;
+        progress.distributed_nfts += nfts;
+        progress.distributed_amount += amount;
+
+        emit_distribution_batch_processed_event(e, &progress);
+
+        if progress.next_investor < progress.investors_count {
+            e.storage()
+                .instance()
+                .set(&DataKey::DistributionProgress, &progress);
+            return Ok(false);
+        }
+
+        // Last page: reconcile, fund the stage and move to the next one
+        Self::settle_stage(
+            e,
+            progress.stage,
+            progress.investors_count,
+            progress.distributed_nfts,
+            progress.distributed_amount,
+            price,
+            percent,
+        )?;
+
+        let next_stage = progress.stage + 1;
+        let next_state = if next_stage == roi_percentages.len() {
+            InvestmentStatus::Ended
+        } else {
+            InvestmentStatus::Distributing(next_stage)
+        };
+
+        e.storage().instance().set(&DataKey::State, &next_state);
+        e.storage()
+            .instance()
+            .remove(&DataKey::DistributionProgress);
+
+        Ok(true)
+    }
+
+    /// Claims all the ROI released to an investor across completed stages.
+    /// Sends the whole claimable amount to the investor and adds it to their claimed amount.
+    /// Fails while a stage is released page by page with `release_distribution_batch`.
+    /// # Arguments
+    /// * `investor` : the investor claiming their ROI
+    pub fn claim(e: Env, investor: Address) -> Result<i128, MinahError> {
+        extend_instance_ttl(&e);
+
+        // Investor should authorize this call
+        investor.require_auth();
+
+        // CHECK: Investor is not frozen
+        Self::require_not_frozen(&e, &investor)?;
+
+        // CHECK: No stage is being released page by page, its credits are only funded by the last page
+        if e.storage().instance().has(&DataKey::DistributionProgress) {
+            return Err(MinahError::DistributionBatchInProgress);
+        }
+
+        let claimable: i128 =
+            read_persistent(&e, &DataKey::ClaimableAmount(investor.clone())).unwrap_or(0);
+
+        if claimable <= 0 {
+            return Err(MinahError::NothingToClaim);
+        }
+
+        let claimed: i128 =
+            read_persistent(&e, &DataKey::ClaimedAmount(investor.clone())).unwrap_or(0);
+
+        write_persistent(&e, &DataKey::ClaimableAmount(investor.clone()), &0i128);
+        write_persistent(
+            &e,
+            &DataKey::ClaimedAmount(investor.clone()),
+            &(claimed + claimable),
+        );
+
+        // DO: Send the released ROI held by the contract to the investor
+        let stablecoin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MinahError::NotInitialized)?;
+
+        token::Client::new(&e, &stablecoin).transfer(
+            &e.current_contract_address(),
+            &investor,
+            &claimable,
+        );
+
+        // Emit CLAIMED event
+        emit_claimed_event(&e, investor, claimable);
+
+        Ok(claimable)
+    }
+
+    /// Sends the distribution dust (rounding remainders kept by the contract) to an address.
+    /// # Arguments
+    /// * `caller` : an account holding the treasury role
+    /// * `to` : the address receiving the dust, usually the payer
+    #[only_role(caller, "treasury")]
+    pub fn sweep_dust(e: &Env, caller: Address, to: Address) -> Result<(), MinahError> {
+        extend_instance_ttl(e);
+
+        let dust: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::DustBalance)
+            .unwrap_or(0);
+
+        if dust <= 0 {
+            return Err(MinahError::NoDust);
+        }
+
+        e.storage().instance().set(&DataKey::DustBalance, &0i128);
+
+        let stablecoin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MinahError::NotInitialized)?;
+
+        token::Client::new(e, &stablecoin).transfer(&e.current_contract_address(), &to, &dust);
+
+        // Emit DUST_SWEPT event
+        emit_dust_swept_event(e, to, dust);
+
+        Ok(())
+    }
+
+    /// Rolls the distribution dust into the next stage.
+    /// The dust is used to fund the next released stage, so the payer is charged that much less.
+    /// Only an account holding the treasury role can call this function.
+    #[only_role(caller, "treasury")]
+    pub fn roll_dust(e: &Env, caller: Address) -> Result<(), MinahError> {
+        extend_instance_ttl(e);
+
+        let dust: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::DustBalance)
+            .unwrap_or(0);
+
+        if dust <= 0 {
+            return Err(MinahError::NoDust);
+        }
+
+        let rolled_dust: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::RolledDust)
+            .unwrap_or(0);
+
+        e.storage().instance().set(&DataKey::DustBalance, &0i128);
+        e.storage()
+            .instance()
+            .set(&DataKey::RolledDust, &(rolled_dust + dust));
+
+        // Emit DUST_ROLLED event
+        emit_dust_rolled_event(e, dust);
+
+        Ok(())
+    }
+
+    /// Funds the principal redemption at the end of the term.
+    /// Pulls `price * STABLECOIN_SCALE` per NFT held by investors from the payer into the contract so holders can `redeem` them.
+    /// Only an account holding the treasury role can call this function.
+    /// NOTE: The payer must have approved the contract to spend the stablecoin on their behalf
+    #[only_role(caller, "treasury")]
+    pub fn fund_redemption(e: &Env, caller: Address) -> Result<(), MinahError> {
+        extend_instance_ttl(e);
+
+        let state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if state != InvestmentStatus::Ended {
+            return Err(MinahError::InvestmentNotEnded);
+        }
+
+        let redemption_funded: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::RedemptionFunded)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if redemption_funded {
+            return Err(MinahError::RedemptionAlreadyFunded);
+        }
+
+        // Unsold NFTs minted to the owner at the start of the chronometer are not redeemable
+        let owner = ownable::get_owner(e).ok_or(MinahError::NotInitialized)?;
+
+        let current_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentSupply)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let outstanding_nfts = current_supply - Base::balance(e, &owner);
+
+        let price: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::Price)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let amount = outstanding_nfts as i128 * price * STABLECOIN_SCALE as i128;
+
+        let stablecoin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let payer: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Payer)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let current_address = e.current_contract_address();
+
+        token::Client::new(e, &stablecoin).transfer_from(
+            &current_address,
+            &payer,
+            &current_address,
+            &amount,
+        );
+
+        e.storage()
+            .instance()
+            .set(&DataKey::RedemptionFunded, &true);
+
+        // Emit REDEMPTION_FUNDED event
+        emit_redemption_funded_event(e, outstanding_nfts, amount);
+
+        Ok(())
+    }
+
+    /// Redeems NFTs for their principal once the term has ended and the redemption has been funded.
+    /// The NFTs are burned and the holder receives `price * STABLECOIN_SCALE` per NFT.
+    /// # Arguments
+    /// * `holder` : the NFT holder redeeming the tokens
+    /// * `token_ids` : the token ids to redeem
+    pub fn redeem(e: Env, holder: Address, token_ids: Vec<u32>) -> Result<(), MinahError> {
+        extend_instance_ttl(&e);
+
+        // Holder should authorize this call
+        holder.require_auth();
+
+        // CHECK: Holder is not frozen
+        Self::require_not_frozen(&e, &holder)?;
+
+        let state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if state != InvestmentStatus::Ended {
+            return Err(MinahError::InvestmentNotEnded);
+        }
+
+        let redemption_funded: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::RedemptionFunded)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if !redemption_funded {
+            return Err(MinahError::RedemptionNotFunded);
+        }
+
+        let owner = ownable::get_owner(&e).ok_or(MinahError::NotInitialized)?;
+
+        if holder == owner {
+            return Err(MinahError::OwnerCannotRedeem);
+        }
+
+        let nft_amount = token_ids.len();
+
+        if nft_amount == 0 {
+            return Err(MinahError::NoTokensToRedeem);
+        }
+
+        // DO: Burn the NFTs, this also checks the holder owns every token
+        Self::batch_burn(&e, &holder, token_ids.clone())?;
+
+        let current_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentSupply)
+            .ok_or(MinahError::NotInitialized)?;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::CurrentSupply, &(current_supply - nft_amount));
+
+        let redeemed_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::RedeemedSupply)
+            .unwrap_or(0);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::RedeemedSupply, &(redeemed_supply + nft_amount));
+
+        let price: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::Price)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let amount = nft_amount as i128 * price * STABLECOIN_SCALE as i128;
+
+        // Update redeemed amount for the holder
+        let redeemed: i128 =
+            read_persistent(&e, &DataKey::RedeemedAmount(holder.clone())).unwrap_or(0);
+
+        write_persistent(
+            &e,
+            &DataKey::RedeemedAmount(holder.clone()),
+            &(redeemed + amount),
+        );
+
+        // DO: Pay back the principal from the funded redemption
+        let stablecoin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MinahError::NotInitialized)?;
+
+        token::Client::new(&e, &stablecoin).transfer(
+            &e.current_contract_address(),
+            &holder,
+            &amount,
+        );
+
+        // Emit REDEEMED event
+        emit_redeemed_event(&e, holder, token_ids, amount);
+
+        Ok(())
+    }
+
+    /// Pauses a single entry point. Only an account holding the pauser role can call this function.
+    /// Use `pause` to stop every pausable entry point at once.
+    #[only_role(caller, "pauser")]
+    pub fn pause_function(e: &Env, caller: Address, function: PausableFunction) {
+        extend_instance_ttl(e);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::FunctionPaused(function), &true);
+
+        // Emit PAUSED event
+        emit_function_paused_event(e, function);
+    }
+
+    /// Unpauses an entry point paused with `pause_function`. Only an account holding the pauser role can call this function.
+    /// The entry point stays blocked while the whole contract is paused.
+    #[only_role(caller, "pauser")]
+    pub fn unpause_function(e: &Env, caller: Address, function: PausableFunction) {
+        extend_instance_ttl(e);
+
+        e.storage()
+            .instance()
+            .remove(&DataKey::FunctionPaused(function));
+
+        // Emit UNPAUSED event
+        emit_function_unpaused_event(e, function);
+    }
+
+    /// Replaces the contract code, keeping its address and storage. Only the contract owner can call this function.
+    /// Call `migrate` afterwards when the new release changes the storage layout.
+    /// # Arguments
+    /// * `new_wasm_hash` : hash of the new WASM, already installed on the network
+    #[only_owner]
+    pub fn upgrade(e: &Env, new_wasm_hash: BytesN<32>) {
+        extend_instance_ttl(e);
+
+        e.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        // Emit UPGRADED event
+        emit_upgraded_event(e, new_wasm_hash);
+    }
+
+    /// Brings the storage layout up to the version of the running code. Only the contract owner can call this function.
+    /// Releases that change the layout bump `STORAGE_VERSION` and add a step here, so a deployment can skip releases.
+    /// Calling it once up to date does nothing. Returns the storage version.
+    #[only_owner]
+    pub fn migrate(e: &Env) -> Result<u32, MinahError> {
+        extend_instance_ttl(e);
+
+        let from_version = Self::get_storage_version(e.clone());
+
+        // CHECK: The storage was not written by a newer release, nor by the baseline contract, which cannot
+        // call upgrade() and has to be redeployed
+        if from_version != STORAGE_VERSION {
+            return Err(MinahError::UnsupportedStorageVersion);
+        }
+
+        Ok(from_version)
+    }
+
+    //////////////////////////////// Getters ////////////////////////////////
+
+    /// Tier and KYC expiry of an investor
+    pub fn get_investor_profile(e: Env, investor: Address) -> InvestorProfile {
+        read_persistent(&e, &DataKey::InvestorProfile(investor)).unwrap_or(InvestorProfile {
+            tier: InvestorTier::Retail,
+            kyc_expires_at: u64::MAX,
+        })
+    }
+
+    /// Lock-up applied from each mint of an investor, in seconds
+    pub fn get_investor_lock_up(e: Env, investor: Address) -> u64 {
+        read_persistent(&e, &DataKey::InvestorLockUp(investor)).unwrap_or(0)
+    }
+
+    /// Seconds after the begin date during which NFTs cannot change hands
+    pub fn get_lock_up_period(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::LockUpPeriod)
+            .unwrap_or(0)
+    }
+
+    /// Timestamp from which an account can sell or transfer its NFTs: the end of the offering lock-up,
+    /// or of its own lock-up when later. `u64::MAX` until the chronometer starts.
+    pub fn transferable_after(e: Env, account: Address) -> u64 {
+        let countdown_start: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::CountdownStart)
+            .unwrap_or(false);
+
+        if !countdown_start {
+            return u64::MAX;
+        }
+
+        let begin_date: u64 = e.storage().instance().get(&DataKey::BeginDate).unwrap_or(0);
+        let locked_until: u64 = read_persistent(&e, &DataKey::LockedUntil(account)).unwrap_or(0);
+
+        (begin_date + Self::get_lock_up_period(e.clone())).max(locked_until)
+    }
+
+    /// Maximum number of NFTs an investor of a tier can hold
+    pub fn get_tier_cap(e: Env, tier: InvestorTier) -> Result<u32, MinahError> {
+        Ok(Self::tier_cap(&e, tier)?)
+    }
+
+    /// Whether an entry point is blocked, either on its own or because the whole contract is paused
+    pub fn is_function_paused(e: &Env, function: PausableFunction) -> bool {
+        pausable::paused(e)
+            || e.storage()
+                .instance()
+                .get(&DataKey::FunctionPaused(function))
+                .unwrap_or(false)
+    }
+
+    /// Seconds between proposing a change and being able to execute it
+    pub fn get_change_delay(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::ChangeDelay)
+            .unwrap_or(DEFAULT_CHANGE_DELAY)
+    }
+
+    /// The change waiting to be executed for a parameter, if any
+    pub fn get_pending_change(e: Env, parameter: ConfigParameter) -> Option<PendingChange> {
+        e.storage()
+            .instance()
+            .get(&DataKey::PendingChange(parameter))
+    }
+
+    /// Parameters that can no longer be changed
+    pub fn get_locked_parameters(e: Env) -> Vec<ConfigParameter> {
+        let mut locked_parameters = Vec::new(&e);
+        for parameter in [
+            ConfigParameter::StableCoin,
+            ConfigParameter::Receiver,
+            ConfigParameter::Payer,
+            ConfigParameter::ChangeDelay,
+            ConfigParameter::Price,
+            ConfigParameter::ReleaseSchedule,
+            ConfigParameter::TradingFee,
+            ConfigParameter::FeeRecipient,
+            ConfigParameter::AccruedRoiPricing,
+            ConfigParameter::LockUpPeriod,
+        ] {
+            if Self::require_parameter_unlocked(&e, parameter).is_err() {
+                locked_parameters.push_back(parameter);
+            }
+        }
+        locked_parameters
+    }
+
+    /// Version of the storage layout, deployments made before it was recorded are version 1
+    pub fn get_storage_version(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::StorageVersion)
+            .unwrap_or(1)
+    }
+
+    /// Check if an address is an investor
+    pub fn is_investor(e: &Env, investor: Address) -> bool {
+        read_persistent(e, &DataKey::Investor(investor)).unwrap_or(false)
+    }
+
+    /// Check if an investor is frozen
+    pub fn is_investor_frozen(e: Env, investor: Address) -> bool {
+        read_persistent(&e, &DataKey::InvestorFrozen(investor)).unwrap_or(false)
+    }
+
+    /// ROI released to a frozen investor and held in escrow until they are unfrozen
+    pub fn get_frozen_amount(e: Env, investor: Address) -> i128 {
+        read_persistent(&e, &DataKey::FrozenAmount(investor)).unwrap_or(0)
+    }
+
+    /// Get investors array length
+    pub fn get_investors_array_length(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::InvestorsCount)
+            .unwrap_or(0)
+    }
+
+    /// Returns the address of the stablecoin used for investments.
+    pub fn get_stablecoin(e: &Env) -> Result<Address, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Returns the address of the receiver.
+    pub fn get_receiver(e: &Env) -> Result<Address, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::Receiver)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Returns the start time of the chronometer.
+    pub fn get_begin_date(e: &Env) -> Result<u64, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::BeginDate)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Returns whether the chronometer has started.
+    pub fn is_chronometer_started(e: &Env) -> Result<bool, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::CountdownStart)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Returns the address of the payer.
+    pub fn get_payer(e: &Env) -> Result<Address, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::Payer)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get ROI released to an investor and not claimed yet
+    pub fn see_claimable_amount(e: Env, investor: Address) -> i128 {
+        read_persistent(&e, &DataKey::ClaimableAmount(investor)).unwrap_or(0)
+    }
+
+    /// Get the rounding remainder recorded for a released stage
+    pub fn get_stage_remainder(e: Env, stage: u32) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::StageRemainder(stage))
+            .unwrap_or(0)
+    }
+
+    /// Get the distribution dust held by the contract that can be swept or rolled
+    pub fn get_dust_balance(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::DustBalance)
+            .unwrap_or(0)
+    }
+
+    /// Get the dust rolled into the next stage funding
+    pub fn get_rolled_dust(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::RolledDust)
+            .unwrap_or(0)
+    }
+
+    /// Get the ROI amount per NFT (rounded down) recorded for a released stage
+    pub fn get_stage_amount_per_nft(e: Env, stage: u32) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::StageAmountPerNFT(stage))
+            .unwrap_or(0)
+    }
+
+    /// Get the balance of an investor at the due date of a stage.
+    /// For a stage not due yet this is the current balance.
+    pub fn balance_at_stage(e: Env, investor: Address, stage: u32) -> u32 {
+        Self::snapshot_balance(&e, &investor, stage)
+    }
+
+    /// Get the progress of the stage being released page by page, if any
+    pub fn get_distribution_progress(e: Env) -> Option<DistributionProgress> {
+        e.storage().instance().get(&DataKey::DistributionProgress)
+    }
+
+    /// Get claimed amount for an investor (ROI already sent to the investor)
+    pub fn see_claimed_amount(e: Env, investor: Address) -> i128 {
+        read_persistent(&e, &DataKey::ClaimedAmount(investor)).unwrap_or(0)
+    }
+
+    /// Get redeemed principal amount for an investor
+    pub fn see_redeemed_amount(e: Env, investor: Address) -> i128 {
+        read_persistent(&e, &DataKey::RedeemedAmount(investor)).unwrap_or(0)
+    }
+
+    /// Returns whether the principal redemption has been funded by the payer
+    pub fn is_redemption_funded(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::RedemptionFunded)
+            .unwrap_or(false)
+    }
+
+    /// Get the number of NFTs redeemed and burned
+    pub fn get_redeemed_supply(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::RedeemedSupply)
+            .unwrap_or(0)
+    }
+
+    /// Get current supply
+    pub fn get_current_supply(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::CurrentSupply)
+            .unwrap_or(0)
+    }
+
+    /// Get current state
+    pub fn get_current_state(e: Env) -> Result<InvestmentStatus, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get NFT PRICE
+    pub fn get_nft_price(e: Env) -> Result<i128, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::Price)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get total supply
+    pub fn get_total_supply(e: Env) -> Result<u32, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get minimum nfts to mint
+    pub fn get_min_nfts_to_mint(e: Env) -> Result<u32, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::MinNFTsToMint)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get maximum nfts per investor
+    pub fn get_max_nfts_per_investor(e: Env) -> Result<u32, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::MaxNFTsPerInvestor)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get nft buying phase supply
+    pub fn get_nft_buying_phase_supply(e: Env) -> Result<u32, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::NFTBuyingPhaseSupply)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get distribution intervals array
+    pub fn get_distribution_intervals(e: Env) -> Result<Vec<u64>, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::DistributionIntervals)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get ROI percentages array
+    pub fn get_roi_percentages(e: Env) -> Result<Vec<i128>, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::ROIPercentages)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get the number of release stages in the distribution schedule
+    pub fn get_stage_count(e: Env) -> Result<u32, MinahError> {
+        Ok(Self::get_distribution_intervals(e)?.len())
+    }
+
+    /// Get buying phase nft supply
+    pub fn get_buying_phase_nft_supply(e: Env) -> Result<u32, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::NFTBuyingPhaseSupply)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get the soft cap (minimum number of NFTs to sell during the buying phase)
+    pub fn get_soft_cap(e: Env) -> Result<u32, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::SoftCap)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get the refund deadline
+    pub fn get_refund_deadline(e: Env) -> Result<u64, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::RefundDeadline)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Returns whether the soft cap has been reached
+    pub fn is_soft_cap_reached(e: Env) -> Result<bool, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::SoftCapReached)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get the timestamp at which the sale opens
+    pub fn get_sale_start(e: Env) -> Result<u64, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::SaleStart)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get the timestamp at which the sale closes
+    pub fn get_sale_end(e: Env) -> Result<u64, MinahError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::SaleEnd)
+            .ok_or(MinahError::NotInitialized)
+    }
+
+    /// Get the amount of stablecoin escrowed in the contract until the soft cap is reached
+    pub fn get_escrowed_amount(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::EscrowedAmount)
+            .unwrap_or(0)
+    }
+
+    /// Get the amount of stablecoin paid by an investor during the buying phase
+    pub fn get_paid_amount(e: Env, investor: Address) -> i128 {
+        read_persistent(&e, &DataKey::PaidAmount(investor)).unwrap_or(0)
+    }
+
+    /// Fee charged to buyers on secondary trades, in basis points of the trade price
+    pub fn get_trading_fee_bps(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&MarketplaceDataKey::TradingFeeBps)
+            .unwrap_or(0)
+    }
+
+    /// Address collecting the trading fees, the receiver unless another one was set
+    pub fn get_fee_recipient(e: Env) -> Result<Address, MinahError> {
+        Ok(Self::fee_recipient(&e)?)
+    }
+
+    /// A listing still open for sale, if any
+    pub fn get_listing(e: Env, listing_id: u32) -> Option<Listing> {
+        read_persistent(&e, &MarketplaceDataKey::Listing(listing_id))
+    }
+
+    /// The listing locking a token, if any
+    pub fn get_token_listing(e: Env, token_id: u32) -> Option<u32> {
+        read_persistent(&e, &MarketplaceDataKey::ListedToken(token_id))
+    }
+
+    /// Tokens held by an account as sorted ranges of consecutive ids
+    pub fn get_token_ranges(e: Env, account: Address) -> Vec<TokenRange> {
+        read_persistent(&e, &MarketplaceDataKey::OwnedTokens(account)).unwrap_or(Vec::new(&e))
+    }
+
+    /// Open offer with the stablecoin still escrowed for it
+    pub fn get_offer(e: Env, offer_id: u32) -> Option<Offer> {
+        read_persistent(&e, &MarketplaceDataKey::Offer(offer_id))
+    }
+
+    /// Terms of the continuous offering, `None` while it is closed
+    pub fn get_continuous_offering(e: Env) -> Option<ContinuousOffering> {
+        e.storage()
+            .instance()
+            .get(&MarketplaceDataKey::ContinuousOffering)
+    }
+
+    /// Current price of one NFT of the owner inventory, in stablecoin units
+    pub fn get_inventory_price(e: Env) -> Result<i128, MarketplaceError> {
+        let offering = Self::get_continuous_offering(e.clone())
+            .ok_or(MarketplaceError::ContinuousOfferingClosed)?;
+
+        if !offering.deduct_paid_roi {
+            return Ok(offering.unit_price);
+        }
+
+        // ROI of the released stages, which a buyer of the NFT no longer receives
+        let stage_count = e
+            .storage()
+            .instance()
+            .get::<_, Vec<u64>>(&DataKey::DistributionIntervals)
+            .ok_or(MarketplaceError::NotInitialized)?
+            .len();
+        let mut paid_roi: i128 = 0;
+
+        for stage in 0..stage_count {
+            match e
+                .storage()
+                .instance()
+                .get::<_, i128>(&DataKey::StageAmountPerNFT(stage))
+            {
+                Some(amount_per_nft) => paid_roi += amount_per_nft,
+                None => break,
+            }
+        }
+
+        Ok((offering.unit_price - paid_roi).max(0))
+    }
+
+    /// Whether `buy_tokens` and `sell_tokens` settle at `quote_price` instead of the original price
+    pub fn is_accrued_roi_pricing(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&MarketplaceDataKey::AccruedRoiPricing)
+            .unwrap_or(false)
+    }
+
+    /// Fair value of `token_count` NFTs in stablecoin units: the principal plus the ROI accrued,
+    /// pro rata of the elapsed time, in the stage whose due date has not passed yet.
+    /// A stage past its due date is paid from the balances at that date, so it stays with the seller,
+    /// while the buyer receives the whole accruing stage and pays the seller for their share.
+    pub fn quote_price(e: Env, token_count: u32) -> Result<i128, MinahError> {
+        Ok(Self::accrued_price(&e, token_count)?)
+    }
+
+    /// Number of unsold NFTs still held by the owner, apart from what was sold during the buying phase
+    pub fn get_inventory_supply(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&MarketplaceDataKey::InventorySupply)
+            .unwrap_or(0)
+    }
+
+    //////////////////////// NFT MARKETPLACE ////////////////////////////////
+
+    pub fn buy_tokens(
+        e: Env,
+        from: Address,
+        to: Address,
+        token_ids: Vec<u32>,
+    ) -> Result<(), MinahError> {
+        // To should authorize this call
+        to.require_auth();
+
+        Ok(Self::buy(e, from, to, token_ids)?)
+    }
+
+    /// Buys `quantity` tokens of `from` without naming them, on the terms of `buy_tokens`.
+    /// The tokens are taken in id order from the token index of the seller, skipping listed ones.
+    /// Returns the ids of the tokens bought.
+    /// # Arguments
+    /// * `from` : the seller, must have approved the contract for all its tokens
+    /// * `to` : the buyer, must have approved the contract for the total price and the fee
+    /// * `quantity` : number of tokens to buy
+    /// * `max_unit_price` : highest price per NFT the buyer accepts
+    pub fn buy_quantity(
+        e: Env,
+        from: Address,
+        to: Address,
+        quantity: u32,
+        max_unit_price: i128,
+    ) -> Result<Vec<u32>, MarketplaceError> {
+        // To should authorize this call
+        to.require_auth();
+
+        if quantity == 0 {
+            return Err(MarketplaceError::InvalidQuantity);
+        }
+
+        let unit_price = Self::secondary_unit_price(&e)?;
+
+        if unit_price > max_unit_price {
+            return Err(MarketplaceError::PriceAboveLimit);
+        }
+
+        // DO: Pick the first tokens of the seller that are not listed
+        let mut token_ids: Vec<u32> = Vec::new(&e);
+
+        'ranges: for range in Self::get_token_ranges(e.clone(), from.clone()).iter() {
+            for token_id in range.start..range.end {
+                if token_ids.len() == quantity {
+                    break 'ranges;
+                }
+
+                if !e
+                    .storage()
+                    .persistent()
+                    .has(&MarketplaceDataKey::ListedToken(token_id))
+                {
+                    token_ids.push_back(token_id);
+                }
+            }
+        }
+
+        if token_ids.len() < quantity {
+            return Err(MarketplaceError::InsufficientFromNftBalance);
+        }
+
+        Self::buy(e.clone(), from, to, token_ids.clone())?;
+
+        Ok(token_ids)
+    }
+
+    /// Sale of `token_ids` from `from` to `to` at the secondary price, once `to` has authorized it
+    fn buy(e: Env, from: Address, to: Address, token_ids: Vec<u32>) -> Result<(), TradeError> {
+        extend_instance_ttl(&e);
+
+        // CHECK: Buying is not paused
+        Self::require_not_paused(&e, PausableFunction::BuyTokens)?;
+
+        // CHECK: Neither side is frozen
+        Self::require_not_frozen(&e, &from)?;
+        Self::require_not_frozen(&e, &to)?;
+
+        // CHECK: Current state should not be BuyingPhase
+        let current_state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(TradeError::NotInitialized)?;
+
+        if current_state == InvestmentStatus::BuyingPhase {
+            return Err(TradeError::NftTransfersNotAllowedDuringBuyingPhase);
+        }
+
+        if current_state == InvestmentStatus::Cancelled {
+            return Err(TradeError::OfferingCancelled);
+        }
+
+        // CHECK: Balances must not move while a stage is released page by page
+        if e.storage().instance().has(&DataKey::DistributionProgress) {
+            return Err(TradeError::DistributionBatchInProgress);
+        }
+
+        // CHECK: NFTs cannot change hands once the principal redemption is funded
+        let redemption_funded: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::RedemptionFunded)
+            .unwrap_or(false);
+
+        if redemption_funded {
+            return Err(TradeError::TradingClosedForRedemption);
+        }
+
+        // CHECK: Both from and to addresses should be either investors or owner
+        let is_from_investor =
+            read_persistent(&e, &DataKey::Investor(from.clone())).unwrap_or(false);
+
+        let is_to_investor = read_persistent(&e, &DataKey::Investor(to.clone())).unwrap_or(false);
+
+        let owner = ownable::get_owner(&e).ok_or(TradeError::NotInitialized)?;
+
+        if !(is_from_investor || from == owner) {
+            return Err(TradeError::NotInvestorOrOwner);
+        }
+
+        if !(is_to_investor || to == owner) {
+            return Err(TradeError::NotInvestorOrOwner);
+        }
+
+        // CHECK: from is past its lock-up
+        Self::require_not_locked_up(&e, &from)?;
+
+        let nft_amount = token_ids.len() as i128;
+
+        // CHECK: to has a valid KYC and stays within the cap of their tier
+        if to != owner {
+            Self::require_can_receive(&e, &to, nft_amount as u32)?;
+        }
+
+        // CHECK: nft_amount should be less than or equal to maximum allowed per transaction
+        // assert!(
+        //     nft_amount <= MAXIMUM_NFTS_PER_TRANSACTION,
+        //     "MAXIMUM_NFTS_PER_TRANSACTION_EXCEEDED"
+        // );
+
+        // CHECK: from should have enough NFTs to sell
+        let from_balance = Self::balance(&e, from.clone());
+
+        if (from_balance as i128) < nft_amount {
+            return Err(TradeError::InsufficientFromNftBalance);
+        }
+
+        // CHECK: to stablecoin balance should be enough to cover the buying fee
+        let stablecoin_address: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(TradeError::NotInitialized)?;
+
+        let price_per_nft = Self::secondary_unit_price(&e)?;
+        let total_price = nft_amount * price_per_nft;
+
+        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
+
+        // The buyer pays the trading fee on top of the price
+        let fee = Self::trading_fee(&e, total_price);
+
+        let to_balance = stablecoin_client.balance(&to);
+
+        if to_balance < total_price + fee {
+            return Err(TradeError::InsufficientBalance);
+        }
+
+        // Get contract address
+        let current_address = e.current_contract_address();
+
+        // CHECK: to allowance should be enough to cover the buying fee
+        let to_allowance = stablecoin_client.allowance(&to, &current_address);
+
+        if to_allowance < total_price + fee {
+            return Err(TradeError::InsufficientAllowance);
+        }
+
+        // DO: Trasnfer stablecoin total_price and the fee
+        stablecoin_client.transfer_from(&e.current_contract_address(), &to, &from, &total_price);
+        Self::collect_trading_fee(&e, &stablecoin_client, &to, fee)?;
+
+        // DO: Transfer NFTs
+        Self::batch_transfer_from(&e, &current_address, &from, &to, token_ids)?;
+
+        // Emit TOKENS_BOUGHT event
+        emit_tokens_bought_event(&e, from, to, nft_amount as u32, fee);
+
+        Ok(())
+    }
+
+    pub fn sell_tokens(
+        e: Env,
+        from: Address,
+        to: Address,
+        token_ids: Vec<u32>,
+    ) -> Result<(), MinahError> {
+        extend_instance_ttl(&e);
+
+        // From should authorize this call
+        from.require_auth();
+
+        // CHECK: Selling is not paused
+        Self::require_not_paused(&e, PausableFunction::SellTokens)?;
+
+        // CHECK: Neither side is frozen
+        Self::require_not_frozen(&e, &from)?;
+        Self::require_not_frozen(&e, &to)?;
+
+        // CHECK: Current state should not be BuyingPhase
+        let current_state: InvestmentStatus = e
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if current_state == InvestmentStatus::BuyingPhase {
+            return Err(MinahError::NftTransfersNotAllowedDuringBuyingPhase);
+        }
+
+        if current_state == InvestmentStatus::Cancelled {
+            return Err(MinahError::OfferingCancelled);
+        }
+
+        // CHECK: Balances must not move while a stage is released page by page
+        if e.storage().instance().has(&DataKey::DistributionProgress) {
+            return Err(MinahError::DistributionBatchInProgress);
+        }
+
+        // CHECK: NFTs cannot change hands once the principal redemption is funded
+        let redemption_funded: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::RedemptionFunded)
+            .unwrap_or(false);
+
+        if redemption_funded {
+            return Err(MinahError::TradingClosedForRedemption);
+        }
+
+        // CHECK: Both from and to addresses should be either investors or owner
+        let is_from_investor =
+            read_persistent(&e, &DataKey::Investor(from.clone())).unwrap_or(false);
+
+        let is_to_investor = read_persistent(&e, &DataKey::Investor(to.clone())).unwrap_or(false);
+
+        let owner = ownable::get_owner(&e).ok_or(MinahError::NotInitialized)?;
+
+        if !(is_from_investor || from == owner) {
+            return Err(MinahError::NotInvestorOrOwner);
+        }
+
+        if !(is_to_investor || to == owner) {
+            return Err(MinahError::NotInvestorOrOwner);
+        }
+
+        // CHECK: from is past its lock-up
+        Self::require_not_locked_up(&e, &from)?;
+
+        let nft_amount = token_ids.len() as i128;
+
+        // CHECK: to has a valid KYC and stays within the cap of their tier
+        if to != owner {
+            Self::require_can_receive(&e, &to, nft_amount as u32)?;
+        }
+
+        // CHECK: nft_amount should be less than or equal to maximum allowed per transaction
+        // assert!(
+        //     nft_amount <= MAXIMUM_NFTS_PER_TRANSACTION,
+        //     "MAXIMUM_NFTS_PER_TRANSACTION_EXCEEDED"
+        // );
+
+        // CHECK: from should have enough NFTs to sell
+        let from_balance = Self::balance(&e, from.clone());
+
+        if (from_balance as i128) < nft_amount {
+            return Err(MinahError::InsufficientFromNftBalance);
+        }
+
+        // CHECK: to stablecoin balance should be enough to cover the selling fee
+        let stablecoin_address: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
+
+        let price_per_nft = Self::secondary_unit_price(&e)?;
+        let total_price = nft_amount * price_per_nft;
+
+        // The buyer pays the trading fee on top of the price
+        let fee = Self::trading_fee(&e, total_price);
+
+        let to_balance = stablecoin_client.balance(&to);
+
+        if to_balance < total_price + fee {
+            return Err(MinahError::InsufficientBalance);
+        }
+
+        // CHECK: to allowance should be enough to cover the selling fee
+        let current_address = e.current_contract_address();
+
+        let to_allowance = stablecoin_client.allowance(&to, &current_address);
+
+        if to_allowance < total_price + fee {
+            return Err(MinahError::InsufficientAllowance);
+        }
+
+        // DO: Trasnfer stablecoin total_price and the fee
+        stablecoin_client.transfer_from(&e.current_contract_address(), &to, &from, &total_price);
+        Self::collect_trading_fee(&e, &stablecoin_client, &to, fee)?;
+
+        // DO: Transfer NFTs
+        Self::batch_transfer_from(&e, &current_address, &from, &to, token_ids)?;
+
+        // Emit TOKENS_SOLD event
+        emit_tokens_sold_event(&e, from, to, nft_amount as u32, fee);
+
+        Ok(())
+    }
+
+    /// Lists tokens for sale at a price chosen by the seller.
+    /// The tokens stay with the seller, and keep earning ROI, but are locked until sold or the listing is cancelled.
+    /// Returns the id of the listing.
+    /// # Arguments
+    /// * `seller` : an investor or the owner holding the tokens
+    /// * `token_ids` : the tokens to sell
+    /// * `unit_price` : price of one NFT in stablecoin units (with the 7 decimals)
+    /// * `expiry` : ledger timestamp from which the listing can no longer be filled
+    pub fn list(
+        e: Env,
+        seller: Address,
+        token_ids: Vec<u32>,
+        unit_price: i128,
+        expiry: u64,
+    ) -> Result<u32, MarketplaceError> {
+        extend_instance_ttl(&e);
+
+        // Seller should authorize this call
+        seller.require_auth();
+
+        // CHECK: Selling is not paused and the seller can trade
+        Self::require_not_paused(&e, PausableFunction::SellTokens)?;
+        Self::require_not_frozen(&e, &seller)?;
+        Self::require_trading_open(&e)?;
+        Self::require_not_locked_up(&e, &seller)?;
+        Self::require_investor_or_owner(&e, &seller)?;
+
+        if token_ids.is_empty() || unit_price <= 0 || expiry <= e.ledger().timestamp() {
+            return Err(MarketplaceError::InvalidListing);
+        }
+
+        let listing_id: u32 = e
+            .storage()
+            .instance()
+            .get(&MarketplaceDataKey::NextListingId)
+            .unwrap_or(0);
+
+        // DO: Lock the tokens, each one can only be in a single listing
+        for token_id in token_ids.iter() {
+            if Consecutive::owner_of(&e, token_id) != seller {
+                return Err(MarketplaceError::IncorrectOwner);
+            }
+
+            Self::require_not_listed(&e, token_id)?;
+            write_persistent(&e, &MarketplaceDataKey::ListedToken(token_id), &listing_id);
+        }
+
+        let listing = Listing {
+            seller,
+            token_ids,
+            unit_price,
+            expiry,
+        };
+        write_persistent(&e, &MarketplaceDataKey::Listing(listing_id), &listing);
+        e.storage()
+            .instance()
+            .set(&MarketplaceDataKey::NextListingId, &(listing_id + 1));
+
+        // Emit LISTED event
+        emit_listed_event(&e, listing_id, &listing);
+
+        Ok(listing_id)
+    }
+
+    /// Removes a listing and unlocks its remaining tokens, also once it has expired.
+    /// Only the seller can call this function.
+    pub fn cancel_listing(e: Env, listing_id: u32) -> Result<(), MarketplaceError> {
+        extend_instance_ttl(&e);
+
+        let listing: Listing = read_persistent(&e, &MarketplaceDataKey::Listing(listing_id))
+            .ok_or(MarketplaceError::ListingNotFound)?;
+
+        // Seller should authorize this call
+        listing.seller.require_auth();
+
+        for token_id in listing.token_ids.iter() {
+            e.storage()
+                .persistent()
+                .remove(&MarketplaceDataKey::ListedToken(token_id));
+        }
+        e.storage()
+            .persistent()
+            .remove(&MarketplaceDataKey::Listing(listing_id));
+
+        // Emit LISTING_CANCELLED event
+        emit_listing_cancelled_event(&e, listing_id);
+
+        Ok(())
+    }
+
+    /// Buys `quantity` tokens of a listing at its unit price, paid straight to the seller.
+    /// The listing is removed once all its tokens are sold.
+    /// # Arguments
+    /// * `buyer` : an investor or the owner, must have approved the contract for the total price
+    /// * `listing_id` : the listing to buy from
+    /// * `quantity` : number of tokens to buy, taken in the order they were listed
+    pub fn fill_listing(
+        e: Env,
+        buyer: Address,
+        listing_id: u32,
+        quantity: u32,
+    ) -> Result<(), MarketplaceError> {
+        extend_instance_ttl(&e);
+
+        // Buyer should authorize this call
+        buyer.require_auth();
+
+        let mut listing: Listing = read_persistent(&e, &MarketplaceDataKey::Listing(listing_id))
+            .ok_or(MarketplaceError::ListingNotFound)?;
+
+        // CHECK: Buying is not paused and both sides can trade
+        Self::require_not_paused(&e, PausableFunction::BuyTokens)?;
+        Self::require_not_frozen(&e, &buyer)?;
+        Self::require_not_frozen(&e, &listing.seller)?;
+        Self::require_trading_open(&e)?;
+        Self::require_not_locked_up(&e, &listing.seller)?;
+        Self::require_investor_or_owner(&e, &buyer)?;
+        Self::require_investor_or_owner(&e, &listing.seller)?;
+
+        if e.ledger().timestamp() >= listing.expiry {
+            return Err(MarketplaceError::ListingExpired);
+        }
+
+        if quantity == 0 || quantity > listing.token_ids.len() {
+            return Err(MarketplaceError::InvalidQuantity);
+        }
+
+        // CHECK: buyer has a valid KYC and stays within the cap of their tier
+        let owner = ownable::get_owner(&e).ok_or(MarketplaceError::NotInitialized)?;
+
+        if buyer != owner {
+            Self::require_can_receive(&e, &buyer, quantity)?;
+        }
+
+        // CHECK: buyer stablecoin balance and allowance should cover the total price
+        let stablecoin_address: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MarketplaceError::NotInitialized)?;
+
+        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
+        let current_address = e.current_contract_address();
+        let total_price = listing.unit_price * quantity as i128;
+        let fee = Self::trading_fee(&e, total_price);
+
+        if stablecoin_client.balance(&buyer) < total_price + fee {
+            return Err(MarketplaceError::InsufficientBalance);
+        }
+
+        if stablecoin_client.allowance(&buyer, &current_address) < total_price + fee {
+            return Err(MarketplaceError::InsufficientAllowance);
+        }
+
+        // DO: Pay the seller and the fee
+        stablecoin_client.transfer_from(&current_address, &buyer, &listing.seller, &total_price);
+        Self::collect_trading_fee(&e, &stablecoin_client, &buyer, fee)?;
+
+        // DO: Unlock and transfer the sold tokens
+        let sold_token_ids = listing.token_ids.slice(0..quantity);
+        listing.token_ids = listing.token_ids.slice(quantity..);
+
+        for token_id in sold_token_ids.iter() {
+            e.storage()
+                .persistent()
+                .remove(&MarketplaceDataKey::ListedToken(token_id));
+        }
+
+        Self::move_tokens(&e, &listing.seller, &buyer, sold_token_ids.clone())?;
+
+        if listing.token_ids.is_empty() {
+            e.storage()
+                .persistent()
+                .remove(&MarketplaceDataKey::Listing(listing_id));
+        } else {
+            write_persistent(&e, &MarketplaceDataKey::Listing(listing_id), &listing);
+        }
+
+        // Emit LISTING_FILLED event
+        emit_listing_filled_event(&e, listing_id, buyer, sold_token_ids, total_price, fee);
+
+        Ok(())
+    }
+
+    /// Escrows the price of `quantity` tokens, plus the trading fee, until a seller accepts the offer
+    /// or the buyer reclaims it. Returns the id of the offer.
+    /// # Arguments
+    /// * `buyer` : an investor or the owner, must have approved the contract for the escrowed amount
+    /// * `token_ids` : the tokens wanted, or empty to take any tokens
+    /// * `quantity` : number of tokens wanted, must match `token_ids` when they are given
+    /// * `unit_price` : price of one NFT in stablecoin units (with the 7 decimals)
+    /// * `expiry` : ledger timestamp from which the offer can no longer be accepted
+    pub fn make_offer(
+        e: Env,
+        buyer: Address,
+        token_ids: Vec<u32>,
+        quantity: u32,
+        unit_price: i128,
+        expiry: u64,
+    ) -> Result<u32, MarketplaceError> {
+        extend_instance_ttl(&e);
+
+        // Buyer should authorize this call
+        buyer.require_auth();
+
+        // CHECK: Buying is not paused and the buyer can trade
+        Self::require_not_paused(&e, PausableFunction::BuyTokens)?;
+        Self::require_not_frozen(&e, &buyer)?;
+        Self::require_trading_open(&e)?;
+        Self::require_investor_or_owner(&e, &buyer)?;
+
+        if quantity == 0 || unit_price <= 0 || expiry <= e.ledger().timestamp() {
+            return Err(MarketplaceError::InvalidOffer);
+        }
+
+        if !token_ids.is_empty() && token_ids.len() != quantity {
+            return Err(MarketplaceError::InvalidQuantity);
+        }
+
+        // CHECK: buyer stablecoin balance and allowance should cover the escrow
+        let stablecoin_address: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MarketplaceError::NotInitialized)?;
+
+        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
+        let current_address = e.current_contract_address();
+        let total_price = unit_price * quantity as i128;
+        let fee = Self::trading_fee(&e, total_price);
+
+        if stablecoin_client.balance(&buyer) < total_price + fee {
+            return Err(MarketplaceError::InsufficientBalance);
+        }
+
+        if stablecoin_client.allowance(&buyer, &current_address) < total_price + fee {
+            return Err(MarketplaceError::InsufficientAllowance);
+        }
+
+        // DO: Escrow the price and the fee in the contract
+        stablecoin_client.transfer_from(
+            &current_address,
+            &buyer,
+            &current_address,
+            &(total_price + fee),
+        );
+
+        let offer_id: u32 = e
+            .storage()
+            .instance()
+            .get(&MarketplaceDataKey::NextOfferId)
+            .unwrap_or(0);
+
+        let offer = Offer {
+            buyer,
+            token_ids,
+            quantity,
+            unit_price,
+            fee,
+            expiry,
+        };
+        write_persistent(&e, &MarketplaceDataKey::Offer(offer_id), &offer);
+        e.storage()
+            .instance()
+            .set(&MarketplaceDataKey::NextOfferId, &(offer_id + 1));
+
+        // Emit OFFER_MADE event
+        emit_offer_made_event(&e, offer_id, &offer);
+
+        Ok(offer_id)
+    }
+
+    /// Sells tokens to an offer before its expiry, the seller is paid from the escrow.
+    /// An offer can be filled by several sellers, it is removed once its whole quantity is sold.
+    /// # Arguments
+    /// * `seller` : an investor or the owner holding the tokens, no approval is needed
+    /// * `offer_id` : the offer to sell to
+    /// * `token_ids` : the tokens sold, among those of the offer when it names some
+    pub fn accept_offer(
+        e: Env,
+        seller: Address,
+        offer_id: u32,
+        token_ids: Vec<u32>,
+    ) -> Result<(), MarketplaceError> {
+        extend_instance_ttl(&e);
+
+        // Seller should authorize this call
+        seller.require_auth();
+
+        let mut offer: Offer = read_persistent(&e, &MarketplaceDataKey::Offer(offer_id))
+            .ok_or(MarketplaceError::OfferNotFound)?;
+
+        // CHECK: Selling is not paused and both sides can trade
+        Self::require_not_paused(&e, PausableFunction::SellTokens)?;
+        Self::require_not_frozen(&e, &seller)?;
+        Self::require_not_frozen(&e, &offer.buyer)?;
+        Self::require_trading_open(&e)?;
+        Self::require_not_locked_up(&e, &seller)?;
+        Self::require_investor_or_owner(&e, &seller)?;
+        Self::require_investor_or_owner(&e, &offer.buyer)?;
+
+        if e.ledger().timestamp() >= offer.expiry {
+            return Err(MarketplaceError::OfferExpired);
+        }
+
+        let quantity = token_ids.len();
+
+        if quantity == 0 || quantity > offer.quantity {
+            return Err(MarketplaceError::InvalidQuantity);
+        }
+
+        // CHECK: the seller owns the tokens and they are the ones asked for
+        for token_id in token_ids.iter() {
+            if Consecutive::owner_of(&e, token_id) != seller {
+                return Err(MarketplaceError::IncorrectOwner);
+            }
+
+            Self::require_not_listed(&e, token_id)?;
+
+            if !offer.token_ids.is_empty() {
+                match offer.token_ids.first_index_of(token_id) {
+                    Some(index) => offer.token_ids.remove(index),
+                    None => return Err(MarketplaceError::InvalidOffer),
+                };
+            }
+        }
+
+        // CHECK: buyer has a valid KYC and stays within the cap of their tier
+        let owner = ownable::get_owner(&e).ok_or(MarketplaceError::NotInitialized)?;
+
+        if offer.buyer != owner {
+            Self::require_can_receive(&e, &offer.buyer, quantity)?;
+        }
+
+        // The last fill takes what is left of the escrowed fee
+        let total_price = offer.unit_price * quantity as i128;
+        let fee = offer.fee * quantity as i128 / offer.quantity as i128;
+
+        offer.quantity -= quantity;
+        offer.fee -= fee;
+
+        // DO: Pay the seller and the fee from the escrow
+        let stablecoin_address: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MarketplaceError::NotInitialized)?;
+
+        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
+        let current_address = e.current_contract_address();
+
+        stablecoin_client.transfer(&current_address, &seller, &total_price);
+
+        if fee > 0 {
+            let fee_recipient = Self::fee_recipient(&e)?;
+            stablecoin_client.transfer(&current_address, &fee_recipient, &fee);
+        }
+
+        // DO: Transfer the sold tokens
+        Self::move_tokens(&e, &seller, &offer.buyer, token_ids.clone())?;
+
+        if offer.quantity == 0 {
+            e.storage()
+                .persistent()
+                .remove(&MarketplaceDataKey::Offer(offer_id));
+        } else {
+            write_persistent(&e, &MarketplaceDataKey::Offer(offer_id), &offer);
+        }
+
+        // Emit OFFER_ACCEPTED event
+        emit_offer_accepted_event(&e, offer_id, seller, token_ids, total_price, fee);
+
+        Ok(())
+    }
+
+    /// Refunds what is left of an offer to its buyer and removes it.
+    /// The buyer can withdraw an offer at any time, anyone can return it once it has expired.
+    /// Returns the refunded amount.
+    pub fn reclaim_offer(e: Env, offer_id: u32) -> Result<i128, MarketplaceError> {
+        extend_instance_ttl(&e);
+
+        let offer: Offer = read_persistent(&e, &MarketplaceDataKey::Offer(offer_id))
+            .ok_or(MarketplaceError::OfferNotFound)?;
+
+        if e.ledger().timestamp() < offer.expiry {
+            // Buyer should authorize withdrawing a live offer
+            offer.buyer.require_auth();
+        }
+
+        // CHECK: A frozen buyer's escrow stays in the contract
+        Self::require_not_frozen(&e, &offer.buyer)?;
+
+        let amount = offer.unit_price * offer.quantity as i128 + offer.fee;
+
+        let stablecoin_address: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MarketplaceError::NotInitialized)?;
+
+        e.storage()
+            .persistent()
+            .remove(&MarketplaceDataKey::Offer(offer_id));
+
+        // DO: Refund the buyer
+        token::Client::new(&e, &stablecoin_address).transfer(
+            &e.current_contract_address(),
+            &offer.buyer,
+            &amount,
+        );
+
+        // Emit OFFER_RECLAIMED event
+        emit_offer_reclaimed_event(&e, offer_id, offer.buyer, amount);
+
+        Ok(amount)
+    }
+
+    /// Opens, updates or closes (`None`) the sale of the owner inventory to investors.
+    /// Can be changed at any time, buyers protect themselves with `max_unit_price`.
+    #[only_admin]
+    pub fn set_continuous_offering(
+        e: &Env,
+        offering: Option<ContinuousOffering>,
+    ) -> Result<(), MarketplaceError> {
+        extend_instance_ttl(e);
+
+        if let Some(offering) = &offering {
+            if offering.unit_price <= 0 {
+                return Err(MarketplaceError::InvalidPrice);
+            }
+
+            e.storage()
+                .instance()
+                .set(&MarketplaceDataKey::ContinuousOffering, offering);
+        } else {
+            e.storage()
+                .instance()
+                .remove(&MarketplaceDataKey::ContinuousOffering);
+        }
+
+        // Emit CONTINUOUS_OFFERING_UPDATED event
+        emit_continuous_offering_updated_event(e, offering);
+
+        Ok(())
+    }
+
+    /// Buys `quantity` NFTs of the owner inventory at the continuous offering price, paid to the receiver.
+    /// Inventory tokens are sold in order, skipping those the owner listed, which stay in the inventory.
+    /// # Arguments
+    /// * `buyer` : an investor, must have approved the contract for the total price
+    /// * `quantity` : number of NFTs to buy
+    /// * `max_unit_price` : highest price per NFT the buyer accepts
+    pub fn buy_from_inventory(
+        e: Env,
+        buyer: Address,
+        quantity: u32,
+        max_unit_price: i128,
+    ) -> Result<(), MarketplaceError> {
+        extend_instance_ttl(&e);
+
+        // Buyer should authorize this call
+        buyer.require_auth();
+
+        // CHECK: Inventory sales are not paused and the buyer can trade
+        Self::require_not_paused(&e, PausableFunction::BuyFromInventory)?;
+        Self::require_not_frozen(&e, &buyer)?;
+        Self::require_trading_open(&e)?;
+
+        if !Self::is_investor(&e, buyer.clone()) {
+            return Err(MarketplaceError::UserNotAnInvestor);
+        }
+
+        let unit_price = Self::get_inventory_price(e.clone())?;
+
+        if unit_price > max_unit_price {
+            return Err(MarketplaceError::PriceAboveLimit);
+        }
+
+        if quantity == 0 {
+            return Err(MarketplaceError::InvalidQuantity);
+        }
+
+        if Self::get_inventory_supply(e.clone()) < quantity {
+            return Err(MarketplaceError::InsufficientFromNftBalance);
+        }
+
+        // CHECK: buyer has a valid KYC and stays within the cap of their tier
+        Self::require_can_receive(&e, &buyer, quantity)?;
+
+        // CHECK: buyer stablecoin balance and allowance should cover the total price
+        let stablecoin_address: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MarketplaceError::NotInitialized)?;
+
+        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
+        let current_address = e.current_contract_address();
+        let total_price = unit_price * quantity as i128;
+
+        if stablecoin_client.balance(&buyer) < total_price {
+            return Err(MarketplaceError::InsufficientBalance);
+        }
+
+        if stablecoin_client.allowance(&buyer, &current_address) < total_price {
+            return Err(MarketplaceError::InsufficientAllowance);
+        }
+
+        // DO: Pick the next inventory tokens, the cursor stops at the first one the owner listed
+        // so it can be sold once the listing is cancelled
+        let owner = ownable::get_owner(&e).ok_or(MarketplaceError::NotInitialized)?;
+        let total_supply: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .ok_or(MarketplaceError::NotInitialized)?;
+        let mut cursor: u32 = e
+            .storage()
+            .instance()
+            .get(&MarketplaceDataKey::InventoryCursor)
+            .unwrap_or(total_supply);
+        let mut token_ids: Vec<u32> = Vec::new(&e);
+        let mut first_listed_token: Option<u32> = None;
+
+        while token_ids.len() < quantity && cursor < total_supply {
+            if Consecutive::owner_of(&e, cursor) == owner {
+                if e.storage()
+                    .persistent()
+                    .has(&MarketplaceDataKey::ListedToken(cursor))
+                {
+                    first_listed_token.get_or_insert(cursor);
+                } else {
+                    token_ids.push_back(cursor);
+                }
+            }
+            cursor += 1;
+        }
+
+        if token_ids.len() < quantity {
+            return Err(MarketplaceError::InsufficientFromNftBalance);
+        }
+
+        // DO: Pay the receiver
+        let receiver: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Receiver)
+            .ok_or(MarketplaceError::NotInitialized)?;
+        stablecoin_client.transfer_from(&current_address, &buyer, &receiver, &total_price);
+
+        // DO: Transfer the NFTs, which also takes them out of the inventory supply
+        Self::move_tokens(&e, &owner, &buyer, token_ids.clone())?;
+
+        e.storage().instance().set(
+            &MarketplaceDataKey::InventoryCursor,
+            &first_listed_token.unwrap_or(cursor),
+        );
+
+        // Emit INVENTORY_SOLD event
+        emit_inventory_sold_event(&e, buyer, token_ids, unit_price);
+
+        Ok(())
+    }
+
+    //////////////////////// INTERNALS ////////////////////////////////
+
+    fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: Vec<u32>,
+    ) -> Result<(), TradeError> {
+        spender.require_auth();
 
-        let max_nfts_per_investor: u32 = e
-            .storage()
-            .instance()
-            .get(&DataKey::MaxNFTsPerInvestor)
-            .expect("MaxNFTsPerInvestor not set");
+        let has_spender_approval_for_all = Base::is_approved_for_all(e, from, spender);
 
-        assert!(
-            investor_balance + amount <= max_nfts_per_investor,
-            "MAXIMUM_NFTS_PER_INVESTOR_EXCEEDED"
-        );
+        if !has_spender_approval_for_all {
+            return Err(TradeError::SpenderNotApprovedForAll);
+        }
 
-        let price: i128 = e
-            .storage()
-            .instance()
-            .get(&DataKey::Price)
-            .expect("Price not set");
+        for token_id in token_ids.iter() {
+            Self::require_not_listed(e, token_id)?;
+        }
 
-        let usd_amount = price * amount as i128 * STABLECOIN_SCALE as i128;
+        Ok(Self::move_tokens(e, from, to, token_ids)?)
+    }
 
-        // CHECK: User has enough balance of stablecoin
-        let stablecoin_address: Address = e
+    /// Moves tokens between two accounts and checkpoints both balances.
+    /// Callers check the tokens are not listed, or take them out of their listing first.
+    /// Keeps the count of the owner inventory, the tokens from the inventory cursor the owner holds.
+    fn move_tokens(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        token_ids: Vec<u32>,
+    ) -> Result<(), NotInitialized> {
+        let owner = ownable::get_owner(e).ok_or(NotInitialized)?;
+        let inventory_cursor: Option<u32> = e
             .storage()
             .instance()
-            .get(&DataKey::StableCoin)
-            .expect("Stablecoin not set");
-
-        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
-
-        let user_balance = stablecoin_client.balance(&user);
+            .get(&MarketplaceDataKey::InventoryCursor);
+        let mut inventory_supply = Self::get_inventory_supply(e.clone());
+        let mut from_ranges = Self::get_token_ranges(e.clone(), from.clone());
+        let mut to_ranges = Self::get_token_ranges(e.clone(), to.clone());
 
-        assert!(user_balance >= usd_amount, "INSUFFICIENT_BALANCE");
+        for token_id in token_ids.iter() {
+            Consecutive::update(e, Some(from), Some(to), token_id);
 
-        // CHECK: User has enough allowance of stablecoin
-        let current_address = e.current_contract_address();
+            Self::remove_from_token_ranges(&mut from_ranges, token_id);
+            Self::insert_token_range(&mut to_ranges, token_id, token_id + 1);
 
-        let user_allowance = stablecoin_client.allowance(&user, &current_address);
+            if inventory_cursor.is_some_and(|cursor| token_id >= cursor) {
+                if *from == owner {
+                    inventory_supply -= 1;
+                }
+                if *to == owner {
+                    inventory_supply += 1;
+                }
+            }
+        }
 
-        assert!(user_allowance >= usd_amount, "INSUFFICIENT_ALLOWANCE");
+        if inventory_cursor.is_some() {
+            e.storage()
+                .instance()
+                .set(&MarketplaceDataKey::InventorySupply, &inventory_supply);
+        }
 
-        let receiver: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Receiver)
-            .expect("Receiver not set");
+        if from != to {
+            Self::write_token_ranges(e, from, &from_ranges);
+            Self::write_token_ranges(e, to, &to_ranges);
+        }
 
-        // Do the transfer of stablecoin from user to the receiver address
-        // NOTE: The user must have approved the contract to spend the stablecoin on their behalf
-        stablecoin_client.transfer_from(&current_address, &user, &receiver, &usd_amount);
+        Self::checkpoint_balance(e, from)?;
+        Self::checkpoint_balance(e, to)?;
 
-        // Update current supply to new supply
-        e.storage()
-            .instance()
-            .set(&DataKey::CurrentSupply, &new_supply);
+        emit_batch_transfer_event(e, from, to, token_ids);
 
-        // Mint the requested amount of NFTs to the specified address
-        Consecutive::batch_mint(&e, &user, amount);
+        Ok(())
     }
 
-    /// Start the chronometer for ROI distribution
-    #[only_owner]
-    pub fn start_chronometer(e: Env) {
-        let countdown_start: bool = e
-            .storage()
-            .instance()
-            .get(&DataKey::CountdownStart)
-            .expect("CountdownStart not set");
+    fn batch_burn(e: &Env, from: &Address, token_ids: Vec<u32>) -> Result<(), MinahError> {
+        let mut from_ranges = Self::get_token_ranges(e.clone(), from.clone());
 
-        let begin_date: u64 = e
-            .storage()
-            .instance()
-            .get(&DataKey::BeginDate)
-            .expect("BeginDate not set");
+        for token_id in token_ids.iter() {
+            Self::require_not_listed(e, token_id)?;
+            Consecutive::update(e, Some(from), None, token_id);
+            burnable::emit_burn(e, from, token_id);
 
-        assert!(
-            !countdown_start && begin_date == 0,
-            "CHRONOMETER_ALREADY_STARTED"
-        );
+            Self::remove_from_token_ranges(&mut from_ranges, token_id);
+        }
 
-        // Set begin date and countdown
-        e.storage()
-            .instance()
-            .set(&DataKey::BeginDate, &e.ledger().timestamp());
-        e.storage().instance().set(&DataKey::CountdownStart, &true);
-        e.storage()
-            .instance()
-            .set(&DataKey::State, &InvestmentStatus::BeforeFirstRelease);
+        Self::write_token_ranges(e, from, &from_ranges);
 
-        // Mint remaining NFTs to owner
-        let current_supply: u32 = e
-            .storage()
-            .instance()
-            .get(&DataKey::CurrentSupply)
-            .expect("CurrentSupply not set");
+        Self::checkpoint_balance(e, from)?;
 
-        let total_supply: u32 = e
-            .storage()
-            .instance()
-            .get(&DataKey::TotalSupply)
-            .expect("TotalSupply not set");
+        Ok(())
+    }
 
-        // Update NFTBuyingPhaseSupply to current supply
-        e.storage()
-            .instance()
-            .set(&DataKey::NFTBuyingPhaseSupply, &current_supply);
+    /// Adds freshly minted tokens `start..end` to the token index of their holder
+    fn index_minted_tokens(e: &Env, holder: &Address, start: u32, end: u32) {
+        let mut ranges = Self::get_token_ranges(e.clone(), holder.clone());
+        Self::insert_token_range(&mut ranges, start, end);
+        Self::write_token_ranges(e, holder, &ranges);
+    }
 
-        let remaining = total_supply - current_supply;
+    fn write_token_ranges(e: &Env, account: &Address, ranges: &Vec<TokenRange>) {
+        if ranges.is_empty() {
+            e.storage()
+                .persistent()
+                .remove(&MarketplaceDataKey::OwnedTokens(account.clone()));
+        } else {
+            write_persistent(e, &MarketplaceDataKey::OwnedTokens(account.clone()), ranges);
+        }
+    }
 
-        // Update current supply to total supply
-        e.storage()
-            .instance()
-            .set(&DataKey::CurrentSupply, &total_supply);
+    /// Adds the ids `start..end` to sorted ranges, merging it with the ranges it touches
+    fn insert_token_range(ranges: &mut Vec<TokenRange>, start: u32, end: u32) {
+        let mut index = 0;
+        while index < ranges.len() && ranges.get_unchecked(index).start < start {
+            index += 1;
+        }
 
-        if remaining > 0 {
-            // Mint the remaining amount of NFTs to the owner
-            let owner = ownable::get_owner(&e).expect("Owner not set");
+        let mut range = TokenRange { start, end };
 
-            Consecutive::batch_mint(&e, &owner, remaining);
+        if index > 0 && ranges.get_unchecked(index - 1).end == start {
+            index -= 1;
+            range.start = ranges.get_unchecked(index).start;
+            ranges.remove(index);
         }
 
-        // Emit CHRONOMETER_STARTED event
-        emit_started_chronometer_event(&e);
+        if index < ranges.len() && ranges.get_unchecked(index).start == end {
+            range.end = ranges.get_unchecked(index).end;
+            ranges.remove(index);
+        }
+
+        ranges.insert(index, range);
     }
 
-    /// Calculate amount to release for a given percentage
-    /// Function to know how much to approve() on the STABLECOIN smart contract before releasing the amount to all investors.
-    /// Arguments:
-    /// * `percentage`: the percentage of ROI to be released for the current stage.(Scaled by 10_000_000 to handle decimal percentages)
-    pub fn calculate_amount_to_release(e: Env, percent: i128) -> i128 {
-        let investors: Vec<Address> = e
-            .storage()
-            .instance()
-            .get(&DataKey::InvestorsArray)
-            .expect("InvestorsArray not set");
+    /// Removes an id from sorted ranges, splitting the range holding it
+    fn remove_from_token_ranges(ranges: &mut Vec<TokenRange>, token_id: u32) {
+        for index in 0..ranges.len() {
+            let range = ranges.get_unchecked(index);
+
+            if range.start <= token_id && token_id < range.end {
+                ranges.remove(index);
+
+                if token_id + 1 < range.end {
+                    ranges.insert(
+                        index,
+                        TokenRange {
+                            start: token_id + 1,
+                            end: range.end,
+                        },
+                    );
+                }
+
+                if range.start < token_id {
+                    ranges.insert(
+                        index,
+                        TokenRange {
+                            start: range.start,
+                            end: token_id,
+                        },
+                    );
+                }
+
+                return;
+            }
+        }
+    }
 
-        let mut total_invested_nfts: i128 = 0;
+    /// Records the current balance of an account for the current stage period.
+    /// Checkpoints are (period, balance) pairs where the period is the number of stages already due
+    /// when the balance changed, so the balance at a stage due date is the last checkpoint of a period <= stage.
+    fn checkpoint_balance(e: &Env, account: &Address) -> Result<(), NotInitialized> {
+        let period = Self::current_period(e)?;
+        let balance = Base::balance(e, account);
 
-        for investor in investors.iter() {
-            let balance = Base::balance(&e, &investor) as i128;
+        let key = DataKey::BalanceCheckpoints(account.clone());
+        let mut checkpoints: Vec<(u32, u32)> = read_persistent(e, &key).unwrap_or(vec![e]);
 
-            total_invested_nfts += balance;
+        // Only the last balance of a period matters
+        match checkpoints.last() {
+            Some((last_period, _)) if last_period == period => {
+                checkpoints.set(checkpoints.len() - 1, (period, balance));
+            }
+            _ => checkpoints.push_back((period, balance)),
         }
 
-        let price: i128 = e
-            .storage()
-            .instance()
-            .get(&DataKey::Price)
-            .expect("Price not set");
+        write_persistent(e, &key, &checkpoints);
 
-        total_invested_nfts * price * percent / 100
+        Ok(())
     }
 
-    /// Releases the distribution for the current stage.
-    /// This function needs to be called by the owner at the end of every distribution period/stage to trigger the current release and next stage.
-    #[only_owner]
-    pub fn release_distribution(e: &Env) {
-        // CHECK: Countdown should be started
+    /// Number of stages whose due date has passed, 0 before the chronometer is started
+    fn current_period(e: &Env) -> Result<u32, NotInitialized> {
         let countdown_start: bool = e
             .storage()
             .instance()
             .get(&DataKey::CountdownStart)
-            .expect("CountdownStart not set");
+            .unwrap_or(false);
 
-        assert!(countdown_start, "COUNDOWN_NOT_STARTED");
+        if !countdown_start {
+            return Ok(0);
+        }
 
         let begin_date: u64 = e
             .storage()
             .instance()
             .get(&DataKey::BeginDate)
-            .expect("BeginDate not set");
-
-        let current_time = e.ledger().timestamp();
-
-        assert!(current_time >= begin_date, "INVALID_LEDGER_TIME");
-
-        let elapsed = current_time - begin_date;
-
-        let mut state: InvestmentStatus = e
-            .storage()
-            .instance()
-            .get(&DataKey::State)
-            .expect("State not set");
+            .ok_or(NotInitialized)?;
 
-        // SUB is safe here because of the check(countdownStart should be true)
-        // We start from (state - 1) because the state enum starts from 1 for BeforeFirstRelease
-        // Cast to usize cause we will use it for indexing arrays which uses usize by default in rust
-        let mut current_stage_index = (state as u32) - 1;
-        let mut distributed = false;
+        let elapsed = e.ledger().timestamp().saturating_sub(begin_date);
 
         let distribution_intervals: Vec<u64> = e
             .storage()
             .instance()
             .get(&DataKey::DistributionIntervals)
-            .expect("DistributionIntervals not set");
-
-        let roi_percentages: Vec<i128> = e
-            .storage()
-            .instance()
-            .get(&DataKey::ROIPercentages)
-            .expect("ROIPercentages not set");
+            .ok_or(NotInitialized)?;
 
-        // Loop through all stages that are ready for distribution
-        while (current_stage_index) < distribution_intervals.len()
-            && elapsed
-                >= distribution_intervals
-                    .get(current_stage_index)
-                    .expect("DISTRIBUTION_INTERVAL_NOT_SET")
-        {
-            // Distribute for this stage
-            Self::distribute(
-                &e,
-                roi_percentages
-                    .get(current_stage_index)
-                    .expect("ROI_PERCENTAGE_NOT_SET"),
-            );
-
-            // Update state
-            state = match state {
-                InvestmentStatus::BeforeFirstRelease => InvestmentStatus::Release1,
-                InvestmentStatus::Release1 => InvestmentStatus::Release2,
-                InvestmentStatus::Release2 => InvestmentStatus::Release3,
-                InvestmentStatus::Release3 => InvestmentStatus::Release4,
-                InvestmentStatus::Release4 => InvestmentStatus::Release5,
-                InvestmentStatus::Release5 => InvestmentStatus::Release6,
-                InvestmentStatus::Release6 => InvestmentStatus::Release7,
-                InvestmentStatus::Release7 => InvestmentStatus::Release8,
-                InvestmentStatus::Release8 => InvestmentStatus::Release9,
-                InvestmentStatus::Release9 => InvestmentStatus::Release10,
-                InvestmentStatus::Release10 => InvestmentStatus::Ended,
-                _ => InvestmentStatus::Ended,
-            };
-
-            e.storage().instance().set(&DataKey::State, &state);
-
-            // SUB is safe here because of the check(countdownStart should be true)
-            current_stage_index = (state as u32) - 1;
-            distributed = true;
-
-            // This is needed to break the loop when the last distribution is done so when we get out of the loop the state will be Ended instead of Release10 Cause due to the while condition it will not be able to enter the loop again so the state will not be updated to Ended
-            if state == InvestmentStatus::Release10 {
-                e.storage()
-                    .instance()
-                    .set(&DataKey::State, &InvestmentStatus::Ended);
+        let mut period = 0;
+        for interval in distribution_intervals.iter() {
+            if elapsed < interval {
                 break;
             }
+            period += 1;
         }
 
-        assert!(distributed, "DISTRIBUTION_NOT_READY_YET");
+        Ok(period)
     }
 
-    //////////////////////////////// Getters ////////////////////////////////
+    /// Balance of an account at the due date of a stage, from its checkpoints
+    fn snapshot_balance(e: &Env, account: &Address, stage: u32) -> u32 {
+        let checkpoints: Vec<(u32, u32)> =
+            read_persistent(e, &DataKey::BalanceCheckpoints(account.clone())).unwrap_or(vec![e]);
 
-    /// Check if an address is an investor
-    pub fn is_investor(e: &Env, investor: Address) -> bool {
-        e.storage()
-            .instance()
-            .get(&DataKey::Investor(investor))
-            .unwrap_or(false)
+        let mut balance = 0;
+        for (period, period_balance) in checkpoints.iter() {
+            if period > stage {
+                break;
+            }
+            balance = period_balance;
+        }
+
+        balance
     }
 
-    /// Get investors array length
-    pub fn get_investors_array_length(e: Env) -> u32 {
-        let investors: Vec<Address> = e
+    /// Internal distribution function
+    /// The function called from releaseDistribution() and used to record what investors earned during the current period/stage.
+    /// The amount is pulled from the payer into the contract and investors get it with `claim`.
+    /// Arguments:
+    /// * `stage`: the index of the stage being released
+    /// * `percent`: the percentage of ROI to be released for the current stage.(Scaled by 10_000_000 to handle decimal percentages)
+    fn distribute(e: &Env, stage: u32, percent: i128) -> Result<(), MinahError> {
+        // CHECK: State should not be Ended
+        let state: InvestmentStatus = e
             .storage()
             .instance()
-            .get(&DataKey::InvestorsArray)
-            .unwrap_or(vec![&e]);
-        investors.len()
-    }
+            .get(&DataKey::State)
+            .ok_or(MinahError::NotInitialized)?;
 
-    /// Returns the address of the stablecoin used for investments.
-    pub fn get_stablecoin(e: &Env) -> Address {
-        e.storage()
-            .instance()
-            .get(&DataKey::StableCoin)
-            .expect("Stablecoin not set")
-    }
+        if state == InvestmentStatus::Ended {
+            return Err(MinahError::DistributionAlreadyEnded);
+        }
 
-    /// Returns the address of the receiver.
-    pub fn get_receiver(e: &Env) -> Address {
-        e.storage()
-            .instance()
-            .get(&DataKey::Receiver)
-            .expect("Receiver not set")
-    }
+        let investors_count = Self::get_investors_array_length(e.clone());
 
-    /// Returns the start time of the chronometer.
-    pub fn get_begin_date(e: &Env) -> u64 {
-        e.storage()
+        let price: i128 = e
+            .storage()
             .instance()
-            .get(&DataKey::BeginDate)
-            .expect("BeginDate not set")
+            .get(&DataKey::Price)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let (total_invested_nfts, credited_amount) =
+            Self::credit_investors(e, stage, 0, investors_count, price, percent)?;
+
+        Self::settle_stage(
+            e,
+            stage,
+            investors_count,
+            total_invested_nfts,
+            credited_amount,
+            price,
+            percent,
+        )?;
+
+        Ok(())
     }
 
-    /// Returns whether the chronometer has started.
-    pub fn is_chronometer_started(e: &Env) -> bool {
-        e.storage()
-            .instance()
-            .get(&DataKey::CountdownStart)
-            .expect("CountdownStart not set")
-    }
+    /// Reconciles a fully credited stage and funds it.
+    /// The stage amount is `floor(total_nfts * price * percent / 100)`, what the rounded down investor shares
+    /// do not cover is recorded as the stage remainder and kept by the contract as dust.
+    fn settle_stage(
+        e: &Env,
+        stage: u32,
+        investors_count: u32,
+        credited_nfts: i128,
+        credited_amount: i128,
+        price: i128,
+        percent: i128,
+    ) -> Result<(), MinahError> {
+        let amount_to_release = credited_nfts * price * percent / 100;
+        let remainder = amount_to_release - credited_amount;
+
+        // CHECK: each investor share loses less than one unit to rounding
+        if remainder < 0 || remainder >= investors_count.max(1) as i128 {
+            return Err(MinahError::DistributionRemainderMismatch);
+        }
 
-    /// Returns the address of the payer.
-    pub fn get_payer(e: &Env) -> Address {
+        // Rounded down entitlement of a single NFT, for reference
+        e.storage().instance().set(
+            &DataKey::StageAmountPerNFT(stage),
+            &((percent * price) / 100),
+        );
         e.storage()
             .instance()
-            .get(&DataKey::Payer)
-            .expect("Payer not set")
-    }
+            .set(&DataKey::StageRemainder(stage), &remainder);
 
-    /// Get claimed amount for an investor
-    pub fn see_claimed_amount(e: Env, investor: Address) -> i128 {
-        e.storage()
+        let dust: i128 = e
+            .storage()
             .instance()
-            .get(&DataKey::ClaimedAmount(investor))
-            .unwrap_or(0)
-    }
-
-    /// Get current supply
-    pub fn get_current_supply(e: Env) -> u32 {
+            .get(&DataKey::DustBalance)
+            .unwrap_or(0);
         e.storage()
             .instance()
-            .get(&DataKey::CurrentSupply)
-            .unwrap_or(0)
-    }
+            .set(&DataKey::DustBalance, &(dust + remainder));
 
-    /// Get current state
-    pub fn get_current_state(e: Env) -> InvestmentStatus {
-        e.storage()
-            .instance()
-            .get(&DataKey::State)
-            .expect("State not set")
-    }
+        Self::fund_stage(e, amount_to_release)?;
 
-    /// Get NFT PRICE
-    pub fn get_nft_price(e: Env) -> i128 {
-        e.storage()
-            .instance()
-            .get(&DataKey::Price)
-            .expect("Price not set")
+        Ok(())
     }
 
-    /// Get total supply
-    pub fn get_total_supply(e: Env) -> u32 {
-        e.storage()
-            .instance()
-            .get(&DataKey::TotalSupply)
-            .expect("TotalSupply not set")
-    }
+    /// Adds the stage entitlement of the investors at index start..end to their claimable amount.
+    /// Entitlements use the balances snapshotted at the stage due date and are rounded down:
+    /// `floor(balance * price * percent / 100)` stablecoin units.
+    /// Returns the number of NFTs and the amount credited.
+    fn credit_investors(
+        e: &Env,
+        stage: u32,
+        start: u32,
+        end: u32,
+        price: i128,
+        percent: i128,
+    ) -> Result<(i128, i128), MinahError> {
+        let mut credited_nfts: i128 = 0;
+        let mut credited_amount: i128 = 0;
+
+        for index in start..end {
+            let investor: Address = read_persistent(e, &DataKey::InvestorAt(index))
+                .ok_or(MinahError::NotInitialized)?;
+            let balance = Self::snapshot_balance(e, &investor, stage) as i128;
+            let investor_amount = balance * price * percent / 100;
+
+            // Update claimable amount for the investor, or hold it in escrow while they are frozen
+            let key = if Self::is_investor_frozen(e.clone(), investor.clone()) {
+                DataKey::FrozenAmount(investor)
+            } else {
+                DataKey::ClaimableAmount(investor)
+            };
+            let amount: i128 = read_persistent(e, &key).unwrap_or(0);
 
-    /// Get minimum nfts to mint
-    pub fn get_min_nfts_to_mint(e: Env) -> u32 {
-        e.storage()
-            .instance()
-            .get(&DataKey::MinNFTsToMint)
-            .expect("MinNFTsToMint not set")
-    }
+            write_persistent(e, &key, &(amount + investor_amount));
 
-    /// Get maximum nfts per investor
-    pub fn get_max_nfts_per_investor(e: Env) -> u32 {
-        e.storage()
-            .instance()
-            .get(&DataKey::MaxNFTsPerInvestor)
-            .expect("MaxNFTsPerInvestor not set")
-    }
+            credited_nfts += balance;
+            credited_amount += investor_amount;
+        }
 
-    /// Get nft buying phase supply
-    pub fn get_nft_buying_phase_supply(e: Env) -> u32 {
-        e.storage()
-            .instance()
-            .get(&DataKey::NFTBuyingPhaseSupply)
-            .expect("NFTBuyingPhaseSupply not set")
+        Ok((credited_nfts, credited_amount))
     }
 
-    /// Get distribution intervals array
-    pub fn get_distribution_intervals(e: Env) -> Vec<u64> {
+    /// Pulls the amount released for a stage from the payer into the contract, investors pull their share with claim().
+    /// Dust rolled by the owner is used first and reduces what the payer is charged.
+    fn fund_stage(e: &Env, amount_to_release: i128) -> Result<(), MinahError> {
         e.storage()
             .instance()
-            .get(&DataKey::DistributionIntervals)
-            .expect("DistributionIntervals not set")
-    }
+            .set(&DataKey::AmountToReleaseForCurrentStage, &amount_to_release);
 
-    /// Get ROI percentages array
-    pub fn get_roi_percentages(e: Env) -> Vec<i128> {
-        e.storage()
+        let rolled_dust: i128 = e
+            .storage()
             .instance()
-            .get(&DataKey::ROIPercentages)
-            .expect("ROIPercentages not set")
-    }
+            .get(&DataKey::RolledDust)
+            .unwrap_or(0);
+        let used_dust = rolled_dust.min(amount_to_release);
 
-    /// Get buying phase nft supply
-    pub fn get_buying_phase_nft_supply(e: Env) -> u32 {
         e.storage()
             .instance()
-            .get(&DataKey::NFTBuyingPhaseSupply)
-            .expect("NFTBuyingPhaseSupply not set")
-    }
+            .set(&DataKey::RolledDust, &(rolled_dust - used_dust));
 
-    //////////////////////// NFT MARKETPLACE ////////////////////////////////
+        let amount_to_pull = amount_to_release - used_dust;
 
-    pub fn buy_tokens(e: Env, from: Address, to: Address, token_ids: Vec<u32>) {
-        // To should authorize this call
-        to.require_auth();
+        if amount_to_pull == 0 {
+            return Ok(());
+        }
+
+        let stablecoin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StableCoin)
+            .ok_or(MinahError::NotInitialized)?;
 
-        // CHECK: Current state should not be BuyingPhase
-        let current_state: InvestmentStatus = e
+        let payer: Address = e
             .storage()
             .instance()
-            .get(&DataKey::State)
-            .expect("State not set");
+            .get(&DataKey::Payer)
+            .ok_or(MinahError::NotInitialized)?;
+
+        let current_address = e.current_contract_address();
 
-        assert!(
-            current_state != InvestmentStatus::BuyingPhase,
-            "NFT_TRANSFERS_NOT_ALLOWED_DURING_BUYING_PHASE"
+        // NOTE: The payer must have approved the contract to spend the stablecoin on their behalf
+        token::Client::new(e, &stablecoin).transfer_from(
+            &current_address,
+            &payer,
+            &current_address,
+            &amount_to_pull,
         );
 
-        // CHECK: Both from and to addresses should be either investors or owner
-        let is_from_investor = e
+        Ok(())
+    }
+
+    /// Seconds elapsed since the chronometer started
+    fn elapsed_since_begin(e: &Env) -> Result<u64, MinahError> {
+        // CHECK: Countdown should be started
+        let countdown_start: bool = e
             .storage()
             .instance()
-            .get(&DataKey::Investor(from.clone()))
-            .unwrap_or(false);
+            .get(&DataKey::CountdownStart)
+            .ok_or(MinahError::NotInitialized)?;
+
+        if !countdown_start {
+            return Err(MinahError::CountdownNotStarted);
+        }
 
-        let is_to_investor = e
+        let begin_date: u64 = e
             .storage()
             .instance()
-            .get(&DataKey::Investor(to.clone()))
-            .unwrap_or(false);
+            .get(&DataKey::BeginDate)
+            .ok_or(MinahError::NotInitialized)?;
 
-        let owner = ownable::get_owner(&e).expect("OWNER_NOT_SET");
+        let current_time = e.ledger().timestamp();
 
-        assert!(
-            is_from_investor || from == owner,
-            "FROM_ADDRESS_NOT_INVESTOR_OR_OWNER"
-        );
+        if current_time < begin_date {
+            return Err(MinahError::InvalidLedgerTime);
+        }
 
-        assert!(
-            is_to_investor || to == owner,
-            "TO_ADDRESS_NOT_INVESTOR_OR_OWNER"
-        );
+        Ok(current_time - begin_date)
+    }
 
-        let nft_amount = token_ids.len() as i128;
+    /// Makes `account` the admin and grants it every role, the admin can then hand the roles over
+    fn grant_initial_roles(e: &Env, account: &Address) {
+        access_control::set_admin(e, account);
 
-        // CHECK: nft_amount should be less than or equal to maximum allowed per transaction
-        // assert!(
-        //     nft_amount <= MAXIMUM_NFTS_PER_TRANSACTION,
-        //     "MAXIMUM_NFTS_PER_TRANSACTION_EXCEEDED"
-        // );
+        for role in ["compliance", "treasury", "pauser"] {
+            access_control::grant_role_no_auth(e, account, account, &Symbol::new(e, role));
+        }
+    }
 
-        // CHECK: from should have enough NFTs to sell
-        let from_balance = Self::balance(&e, from.clone());
+    /// Whether every release stage has an interval and an ROI percentage, with strictly increasing intervals
+    fn is_valid_release_schedule(
+        distribution_intervals: &Vec<u64>,
+        roi_percentages: &Vec<i128>,
+    ) -> bool {
+        // Every release stage needs both an interval and an ROI percentage
+        if distribution_intervals.is_empty()
+            || roi_percentages.len() != distribution_intervals.len()
+        {
+            return false;
+        }
 
-        assert!(
-            from_balance as i128 >= nft_amount,
-            "INSUFFICIENT_FROM_NFT_BALANCE"
-        );
+        // Intervals are measured from the begin date so they must be strictly increasing
+        for i in 1..distribution_intervals.len() {
+            if distribution_intervals.get_unchecked(i)
+                <= distribution_intervals.get_unchecked(i - 1)
+            {
+                return false;
+            }
+        }
 
-        // CHECK: to stablecoin balance should be enough to cover the buying fee
-        let stablecoin_address: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::StableCoin)
-            .expect("STABLECOIN_NOT_SET");
+        true
+    }
 
-        let price: i128 = e
-            .storage()
-            .instance()
-            .get(&DataKey::Price)
-            .expect("Price not set");
+    /// Fails once a parameter is frozen: investors bought their NFTs at the current price and release
+    /// schedule, and paid in the stablecoin the contract escrows and refunds from the first mint.
+    fn require_parameter_unlocked(e: &Env, parameter: ConfigParameter) -> Result<(), ConfigError> {
+        let frozen_at_first_mint = matches!(
+            parameter,
+            ConfigParameter::StableCoin | ConfigParameter::Price | ConfigParameter::ReleaseSchedule
+        );
+        let frozen_at_chronometer_start =
+            frozen_at_first_mint || matches!(parameter, ConfigParameter::LockUpPeriod);
 
-        let price_per_nft = price * STABLECOIN_SCALE as i128;
-        let total_price = nft_amount * price_per_nft;
+        if frozen_at_chronometer_start {
+            let current_state: InvestmentStatus = e
+                .storage()
+                .instance()
+                .get(&DataKey::State)
+                .ok_or(ConfigError::NotInitialized)?;
 
-        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
+            if current_state != InvestmentStatus::BuyingPhase {
+                return Err(ConfigError::InvestmentNotInBuyingPhase);
+            }
+        }
 
-        let to_balance = stablecoin_client.balance(&to);
+        if frozen_at_first_mint {
+            let current_supply: u32 = e
+                .storage()
+                .instance()
+                .get(&DataKey::CurrentSupply)
+                .unwrap_or(0);
 
-        assert!(
-            to_balance >= total_price,
-            "INSUFFICIENT_BALANCE_TO_BUY_NFTS"
-        );
+            if current_supply != 0 {
+                return Err(ConfigError::ConfigLockedAfterFirstMint);
+            }
+        }
 
-        // Get contract address
-        let current_address = e.current_contract_address();
+        Ok(())
+    }
 
-        // CHECK: to allowance should be enough to cover the buying fee
-        let to_allowance = stablecoin_client.allowance(&to, &current_address);
+    /// Checks an investor can receive `amount` more NFTs: their KYC has not expired
+    /// and they stay within the cap of their tier.
+    fn require_can_receive(e: &Env, investor: &Address, amount: u32) -> Result<(), TradeError> {
+        let profile = Self::get_investor_profile(e.clone(), investor.clone());
 
-        assert!(
-            to_allowance >= total_price,
-            "INSUFFICIENT_ALLOWANCE_TO_BUY_NFTS"
-        );
+        if e.ledger().timestamp() >= profile.kyc_expires_at {
+            return Err(TradeError::KycExpired);
+        }
 
-        // DO: Trasnfer stablecoin total_price
-        stablecoin_client.transfer_from(&e.current_contract_address(), &to, &from, &total_price);
+        let max_nfts = Self::tier_cap(e, profile.tier)?;
 
-        // DO: Transfer NFTs
-        Self::batch_transfer_from(&e, &current_address, &from, &to, token_ids);
+        if Base::balance(e, investor) + amount > max_nfts {
+            return Err(TradeError::MaximumNftsPerInvestorExceeded);
+        }
 
-        // Emit TOKENS_BOUGHT event
-        emit_tokens_bought_event(&e, from, to, nft_amount as u32);
+        Ok(())
     }
 
-    pub fn sell_tokens(e: Env, from: Address, to: Address, token_ids: Vec<u32>) {
-        // From should authorize this call
-        from.require_auth();
-
-        // CHECK: Current state should not be BuyingPhase
+    /// Checks NFTs can change hands: the offering is past its buying phase and not cancelled,
+    /// no stage is being released page by page and the principal redemption is not funded yet.
+    fn require_trading_open(e: &Env) -> Result<(), TradeError> {
         let current_state: InvestmentStatus = e
             .storage()
             .instance()
             .get(&DataKey::State)
-            .expect("State not set");
+            .ok_or(TradeError::NotInitialized)?;
 
-        assert!(
-            current_state != InvestmentStatus::BuyingPhase,
-            "NFT_TRANSFERS_NOT_ALLOWED_DURING_BUYING_PHASE"
-        );
+        if current_state == InvestmentStatus::BuyingPhase {
+            return Err(TradeError::NftTransfersNotAllowedDuringBuyingPhase);
+        }
 
-        // CHECK: Both from and to addresses should be either investors or owner
-        let is_from_investor = e
-            .storage()
-            .instance()
-            .get(&DataKey::Investor(from.clone()))
-            .unwrap_or(false);
+        if current_state == InvestmentStatus::Cancelled {
+            return Err(TradeError::OfferingCancelled);
+        }
+
+        if e.storage().instance().has(&DataKey::DistributionProgress) {
+            return Err(TradeError::DistributionBatchInProgress);
+        }
 
-        let is_to_investor = e
+        let redemption_funded: bool = e
             .storage()
             .instance()
-            .get(&DataKey::Investor(to.clone()))
+            .get(&DataKey::RedemptionFunded)
             .unwrap_or(false);
 
-        let owner = ownable::get_owner(&e).expect("OWNER_NOT_SET");
+        if redemption_funded {
+            return Err(TradeError::TradingClosedForRedemption);
+        }
 
-        assert!(
-            is_from_investor || from == owner,
-            "FROM_ADDRESS_NOT_INVESTOR_OR_OWNER"
-        );
+        Ok(())
+    }
 
-        assert!(
-            is_to_investor || to == owner,
-            "TO_ADDRESS_NOT_INVESTOR_OR_OWNER"
-        );
+    fn require_investor_or_owner(e: &Env, account: &Address) -> Result<(), TradeError> {
+        let is_investor = read_persistent(e, &DataKey::Investor(account.clone())).unwrap_or(false);
+        let owner = ownable::get_owner(e).ok_or(TradeError::NotInitialized)?;
 
-        let nft_amount = token_ids.len() as i128;
+        if !(is_investor || *account == owner) {
+            return Err(TradeError::NotInvestorOrOwner);
+        }
 
-        // CHECK: nft_amount should be less than or equal to maximum allowed per transaction
-        // assert!(
-        //     nft_amount <= MAXIMUM_NFTS_PER_TRANSACTION,
-        //     "MAXIMUM_NFTS_PER_TRANSACTION_EXCEEDED"
-        // );
+        Ok(())
+    }
 
-        // CHECK: from should have enough NFTs to sell
-        let from_balance = Self::balance(&e, from.clone());
+    /// Computes `quote_price`
+    fn accrued_price(e: &Env, token_count: u32) -> Result<i128, NotInitialized> {
+        let price: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::Price)
+            .ok_or(NotInitialized)?;
 
-        assert!(
-            from_balance as i128 >= nft_amount,
-            "INSUFFICIENT_FROM_NFT_BALANCE"
-        );
+        let principal = price * STABLECOIN_SCALE as i128;
 
-        // CHECK: to stablecoin balance should be enough to cover the selling fee
-        let stablecoin_address: Address = e
+        // Only a distributing offering accrues ROI
+        let current_state: InvestmentStatus = e
             .storage()
             .instance()
-            .get(&DataKey::StableCoin)
-            .expect("STABLECOIN_NOT_SET");
-
-        let stablecoin_client = token::Client::new(&e, &stablecoin_address);
+            .get(&DataKey::State)
+            .ok_or(NotInitialized)?;
+        if !matches!(current_state, InvestmentStatus::Distributing(_)) {
+            return Ok(principal * token_count as i128);
+        }
 
-        let price: i128 = e
+        // The accruing stage is the first one not due yet, none once every stage is due
+        let stage = Self::current_period(e)?;
+        let distribution_intervals: Vec<u64> = e
             .storage()
             .instance()
-            .get(&DataKey::Price)
-            .expect("Price not set");
+            .get(&DataKey::DistributionIntervals)
+            .ok_or(NotInitialized)?;
+        if stage >= distribution_intervals.len() {
+            return Ok(principal * token_count as i128);
+        }
 
-        let price_per_nft = price * STABLECOIN_SCALE as i128;
-        let total_price = nft_amount * price_per_nft;
+        let roi_percentages: Vec<i128> = e
+            .storage()
+            .instance()
+            .get(&DataKey::ROIPercentages)
+            .ok_or(NotInitialized)?;
 
-        let to_balance = stablecoin_client.balance(&to);
+        let stage_start = if stage == 0 {
+            0
+        } else {
+            distribution_intervals.get_unchecked(stage - 1)
+        };
+        let stage_end = distribution_intervals.get_unchecked(stage);
+        let stage_roi = (roi_percentages.get_unchecked(stage) * price) / 100;
 
-        assert!(
-            to_balance >= total_price,
-            "INSUFFICIENT_BALANCE_TO_SELL_NFTS"
-        );
+        // A distributing offering has started its chronometer
+        let begin_date: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::BeginDate)
+            .ok_or(NotInitialized)?;
+        let elapsed = e.ledger().timestamp().saturating_sub(begin_date);
+        let accrued =
+            stage_roi * (elapsed - stage_start) as i128 / (stage_end - stage_start) as i128;
 
-        // CHECK: to allowance should be enough to cover the selling fee
-        let current_address = e.current_contract_address();
+        Ok((principal + accrued) * token_count as i128)
+    }
 
-        let to_allowance = stablecoin_client.allowance(&to, &current_address);
+    /// Price of one NFT on `buy_tokens` and `sell_tokens`
+    fn secondary_unit_price(e: &Env) -> Result<i128, TradeError> {
+        if Self::is_accrued_roi_pricing(e.clone()) {
+            return Ok(Self::accrued_price(e, 1)?);
+        }
 
-        assert!(
-            to_allowance >= total_price,
-            "INSUFFICIENT_ALLOWANCE_TO_SELL_NFTS"
-        );
+        let price: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::Price)
+            .ok_or(TradeError::NotInitialized)?;
 
-        // DO: Trasnfer stablecoin total_price
-        stablecoin_client.transfer_from(&e.current_contract_address(), &to, &from, &total_price);
+        Ok(price * STABLECOIN_SCALE as i128)
+    }
 
-        // DO: Transfer NFTs
-        Self::batch_transfer_from(&e, &current_address, &from, &to, token_ids);
+    /// Computes `get_tier_cap`
+    fn tier_cap(e: &Env, tier: InvestorTier) -> Result<u32, NotInitialized> {
+        match e.storage().instance().get(&DataKey::TierCap(tier)) {
+            Some(max_nfts) => Ok(max_nfts),
+            None => e
+                .storage()
+                .instance()
+                .get(&DataKey::MaxNFTsPerInvestor)
+                .ok_or(NotInitialized),
+        }
+    }
 
-        // Emit TOKENS_SOLD event
-        emit_tokens_sold_event(&e, from, to, nft_amount as u32);
+    /// Computes `get_fee_recipient`
+    fn fee_recipient(e: &Env) -> Result<Address, NotInitialized> {
+        match e
+            .storage()
+            .instance()
+            .get(&MarketplaceDataKey::FeeRecipient)
+        {
+            Some(fee_recipient) => Ok(fee_recipient),
+            None => e
+                .storage()
+                .instance()
+                .get(&DataKey::Receiver)
+                .ok_or(NotInitialized),
+        }
     }
 
-    //////////////////////// INTERNALS ////////////////////////////////
+    /// Trading fee owed on a secondary trade of `total_price`, rounded down
+    fn trading_fee(e: &Env, total_price: i128) -> i128 {
+        total_price * Self::get_trading_fee_bps(e.clone()) as i128 / BPS_DENOMINATOR
+    }
 
-    fn batch_transfer_from(
+    /// Pulls the trading fee from the buyer to the fee recipient
+    fn collect_trading_fee(
         e: &Env,
-        spender: &Address,
-        from: &Address,
-        to: &Address,
-        token_ids: Vec<u32>,
-    ) {
-        spender.require_auth();
-
-        let has_spender_approval_for_all = Base::is_approved_for_all(e, from, spender);
+        stablecoin_client: &token::Client,
+        buyer: &Address,
+        fee: i128,
+    ) -> Result<(), TradeError> {
+        if fee == 0 {
+            return Ok(());
+        }
 
-        assert!(has_spender_approval_for_all, "SPENDER_NOT_APPROVED_FOR_ALL");
+        let fee_recipient = Self::fee_recipient(e)?;
 
-        for i in 0..token_ids.len() {
-            let token_id = token_ids.get(i).expect("Token id not found");
-            Consecutive::update(e, Some(from), Some(to), token_id);
-        }
+        stablecoin_client.transfer_from(&e.current_contract_address(), buyer, &fee_recipient, &fee);
 
-        emit_batch_transfer_event(e, from, to, token_ids);
+        Ok(())
     }
 
-    /// Internal distribution function
-    /// The function called from releaseDistribution() and used to distribute to investors what they earned during the current period/stage.
-    /// Arguments:
-    /// * `percent`: the percentage of ROI to be released for the current stage.(Scaled by 10_000_000 to handle decimal percentages)
-    fn distribute(e: &Env, percent: i128) {
-        // CHECK: State should not be Ended
-        let state: InvestmentStatus = e
-            .storage()
-            .instance()
-            .get(&DataKey::State)
-            .expect("State not set");
+    /// Checks a peer-to-peer transfer follows the trading rules: trading is open, both sides are investors
+    /// or the owner, neither is frozen, the sender is past its lock-up and the recipient has a valid KYC and stays within the cap of their tier.
+    /// Panics with `ComplianceError::TransferRestricted` otherwise.
+    fn require_transfer_allowed(e: &Env, from: &Address, to: &Address) {
+        unwrap_or_panic(e, Self::require_not_paused(e, PausableFunction::Transfer));
 
-        assert!(
-            state != InvestmentStatus::Ended,
-            "DISTRIBUTION_ALREADY_ENDED"
-        );
+        let mut allowed = Self::require_trading_open(e).is_ok()
+            && Self::require_investor_or_owner(e, from).is_ok()
+            && Self::require_investor_or_owner(e, to).is_ok()
+            && !Self::is_investor_frozen(e.clone(), from.clone())
+            && !Self::is_investor_frozen(e.clone(), to.clone())
+            && e.ledger().timestamp() >= Self::transferable_after(e.clone(), from.clone());
 
-        // CALCULATE amount to release for the current stage
-        let amount_to_release = Self::calculate_amount_to_release(e.clone(), percent);
-        e.storage()
-            .instance()
-            .set(&DataKey::AmountToReleaseForCurrentStage, &amount_to_release);
+        let owner = unwrap_or_panic(e, ownable::get_owner(e).ok_or(MinahError::NotInitialized));
 
-        let stablecoin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::StableCoin)
-            .expect("Stablecoin not set");
-        let payer: Address = e.storage().instance().get(&DataKey::Payer).unwrap();
-        let investors: Vec<Address> = e
-            .storage()
-            .instance()
-            .get(&DataKey::InvestorsArray)
-            .expect("InvestorsArray not set");
+        if allowed && *to != owner {
+            let profile = Self::get_investor_profile(e.clone(), to.clone());
+            let max_nfts = unwrap_or_panic(e, Self::tier_cap(e, profile.tier));
 
-        let token_client = token::Client::new(&e, &stablecoin);
-        let mut verify_released_amount: i128 = 0;
+            allowed =
+                e.ledger().timestamp() < profile.kyc_expires_at && Base::balance(e, to) < max_nfts;
+        }
 
-        let current_address = e.current_contract_address();
+        if !allowed {
+            panic_with_error!(e, ComplianceError::TransferRestricted);
+        }
+    }
 
-        let price: i128 = e
-            .storage()
-            .instance()
-            .get(&DataKey::Price)
-            .expect("Price not set");
+    /// Fails with `TokenListed` while a token is locked by a listing
+    fn require_not_listed(e: &Env, token_id: u32) -> Result<(), TradeError> {
+        if e.storage()
+            .persistent()
+            .has(&MarketplaceDataKey::ListedToken(token_id))
+        {
+            return Err(TradeError::TokenListed);
+        }
 
-        for investor in investors.iter() {
-            let balance = Base::balance(&e, &investor) as i128;
-            let investor_amount = ((balance * percent) / 100) * price;
+        Ok(())
+    }
 
-            // Update claimed amount for the investor
-            let mut claimed: i128 = e
-                .storage()
-                .instance()
-                .get(&DataKey::ClaimedAmount(investor.clone()))
-                .unwrap_or(0);
+    /// Takes a token out of its listing, if any, removing the listing once it is empty
+    fn unlist_token(e: &Env, token_id: u32) {
+        let listing_id: u32 = match read_persistent(e, &MarketplaceDataKey::ListedToken(token_id)) {
+            Some(listing_id) => listing_id,
+            None => return,
+        };
 
-            claimed += investor_amount;
+        e.storage()
+            .persistent()
+            .remove(&MarketplaceDataKey::ListedToken(token_id));
 
-            e.storage()
-                .instance()
-                .set(&DataKey::ClaimedAmount(investor.clone()), &claimed);
+        let mut listing: Listing =
+            match read_persistent(e, &MarketplaceDataKey::Listing(listing_id)) {
+                Some(listing) => listing,
+                None => return,
+            };
+
+        if let Some(index) = listing.token_ids.first_index_of(token_id) {
+            listing.token_ids.remove(index);
+        }
 
-            // Increase the verify released amount by the investor amount
-            verify_released_amount += investor_amount;
+        if listing.token_ids.is_empty() {
+            e.storage()
+                .persistent()
+                .remove(&MarketplaceDataKey::Listing(listing_id));
+        } else {
+            write_persistent(e, &MarketplaceDataKey::Listing(listing_id), &listing);
+        }
+    }
 
-            // Do the transfer from payer to investor
-            // NOTE: The payer must have approved the contract to spend the stablecoin on their behalf
-            token_client.transfer_from(&current_address, &payer, &investor, &investor_amount);
+    /// Fails with `LockedUp` until the account can sell its NFTs
+    fn require_not_locked_up(e: &Env, account: &Address) -> Result<(), TradeError> {
+        if e.ledger().timestamp() < Self::transferable_after(e.clone(), account.clone()) {
+            return Err(TradeError::LockedUp);
         }
 
-        // CHECK: verify released amount should be equal to amount to release
-        assert!(
-            verify_released_amount == amount_to_release,
-            "DISTRIBUTION_AMOUNT_MISMATCH"
-        );
+        Ok(())
     }
 
-    //////////////////////// TO DELETE FOR PROD ////////////////////////////////
+    /// Fails with `InvestorFrozen` when the account is a frozen investor
+    fn require_not_frozen(e: &Env, account: &Address) -> Result<(), TradeError> {
+        if Self::is_investor_frozen(e.clone(), account.clone()) {
+            return Err(TradeError::InvestorFrozen);
+        }
 
-    /// Sets a new receiver address. Only the contract owner can call this function.
-    #[only_owner]
-    pub fn set_receiver(e: &Env, receiver: Address) {
-        e.storage().instance().set(&DataKey::Receiver, &receiver);
+        Ok(())
     }
 
-    /// Sets a new payer address. Only the contract owner can call this function.
-    #[only_owner]
-    pub fn set_payer(e: &Env, payer: Address) {
-        e.storage().instance().set(&DataKey::Payer, &payer);
+    /// Fails when the entry point is paused on its own or the whole contract is paused
+    fn require_not_paused(e: &Env, function: PausableFunction) -> Result<(), TradeError> {
+        if Self::is_function_paused(e, function) {
+            return Err(TradeError::FunctionPaused);
+        }
+
+        Ok(())
     }
 
+    //////////////////////// TO DELETE FOR PROD ////////////////////////////////
+
     pub fn hello(env: Env, to: String) -> Vec<String> {
         vec![&env, String::from_str(&env, "Hello"), to]
     }
@@ -1002,20 +4558,113 @@ impl Minah {
 impl NonFungibleToken for Minah {
     type ContractType = Consecutive;
 
-    fn transfer(_e: &Env, _from: Address, _to: Address, _token_id: u32) {
-        panic!("TRANSFERS_DISABLED_FOR_MINAH_NFTS");
+    /// Peer-to-peer transfer, free of charge, between accounts allowed to trade.
+    /// Fails with `ComplianceError::TransferRestricted` when a trading rule blocks it.
+    fn transfer(e: &Env, from: Address, to: Address, token_id: u32) {
+        extend_instance_ttl(e);
+
+        from.require_auth();
+
+        Self::require_transfer_allowed(e, &from, &to);
+        unwrap_or_panic(e, Self::require_not_listed(e, token_id));
+        unwrap_or_panic(e, Self::move_tokens(e, &from, &to, vec![e, token_id]));
     }
 
-    fn transfer_from(_e: &Env, _spender: Address, _from: Address, _to: Address, _token_id: u32) {
-        panic!("TRANSFERS_DISABLED_FOR_MINAH_NFTS");
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        extend_instance_ttl(e);
+
+        spender.require_auth();
+
+        Base::check_spender_approval(e, &spender, &from, token_id);
+        Self::require_transfer_allowed(e, &from, &to);
+        unwrap_or_panic(e, Self::require_not_listed(e, token_id));
+        unwrap_or_panic(e, Self::move_tokens(e, &from, &to, vec![e, token_id]));
     }
 }
 
 impl NonFungibleConsecutive for Minah {}
 
+/// Ownership changes extend the instance TTL like the other state-changing entry points.
+#[default_impl]
+#[contractimpl]
+impl Ownable for Minah {
+    fn transfer_ownership(e: &Env, new_owner: Address, live_until_ledger: u32) {
+        extend_instance_ttl(e);
+        ownable::transfer_ownership(e, &new_owner, live_until_ledger);
+    }
+
+    fn accept_ownership(e: &Env) {
+        extend_instance_ttl(e);
+        ownable::accept_ownership(e);
+    }
+
+    fn renounce_ownership(e: &Env) {
+        extend_instance_ttl(e);
+        ownable::renounce_ownership(e);
+    }
+}
+
+/// Roles: the admin changes addresses and configuration, `compliance` onboards investors,
+/// `treasury` triggers releases and manages distribution funds, `pauser` operates the circuit breaker.
+/// Role changes extend the instance TTL, the getters keep their default implementation.
 #[default_impl]
 #[contractimpl]
-impl Ownable for Minah {}
+impl AccessControl for Minah {
+    fn grant_role(e: &Env, caller: Address, account: Address, role: Symbol) {
+        extend_instance_ttl(e);
+        access_control::grant_role(e, &caller, &account, &role);
+    }
+
+    fn revoke_role(e: &Env, caller: Address, account: Address, role: Symbol) {
+        extend_instance_ttl(e);
+        access_control::revoke_role(e, &caller, &account, &role);
+    }
+
+    fn renounce_role(e: &Env, caller: Address, role: Symbol) {
+        extend_instance_ttl(e);
+        access_control::renounce_role(e, &caller, &role);
+    }
+
+    fn transfer_admin_role(e: &Env, new_admin: Address, live_until_ledger: u32) {
+        extend_instance_ttl(e);
+        access_control::transfer_admin_role(e, &new_admin, live_until_ledger);
+    }
+
+    fn accept_admin_transfer(e: &Env) {
+        extend_instance_ttl(e);
+        access_control::accept_admin_transfer(e);
+    }
+
+    fn set_role_admin(e: &Env, role: Symbol, admin_role: Symbol) {
+        extend_instance_ttl(e);
+        access_control::set_role_admin(e, &role, &admin_role);
+    }
+
+    fn renounce_admin(e: &Env) {
+        extend_instance_ttl(e);
+        access_control::renounce_admin(e);
+    }
+}
+
+/// Emergency stop for mint, marketplace trades and releases. Getters, claims and redemptions keep working.
+#[contractimpl]
+impl Pausable for Minah {
+    fn paused(e: &Env) -> bool {
+        pausable::paused(e)
+    }
+
+    #[only_role(caller, "pauser")]
+    fn pause(e: &Env, caller: Address) {
+        extend_instance_ttl(e);
+        pausable::pause(e);
+    }
+
+    #[only_role(caller, "pauser")]
+    fn unpause(e: &Env, caller: Address) {
+        extend_instance_ttl(e);
+        pausable::unpause(e);
+    }
+}
 
 #[cfg(test)]
 mod tests;
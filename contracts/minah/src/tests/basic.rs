@@ -2,7 +2,11 @@ use crate::tests::utils::{
     create_client, deploy_stablecoin_contract, distribution_intervals_vec, roi_percentages_vec,
     MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY, USDC_DECIMALS,
 };
-use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+use crate::{ConfigChange, ConfigParameter, MinahError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String, Vec,
+};
 use stablecoin::StablecoinClient;
 
 #[test]
@@ -101,7 +105,10 @@ fn test_stablecoin_setter_getter() {
     // Change the stablecoin address
     let new_stablecoin = Address::generate(&env);
 
-    client.set_stablecoin(&new_stablecoin);
+    client.propose_change(&ConfigChange::StableCoin(new_stablecoin.clone()));
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + client.get_change_delay());
+    client.execute_change(&ConfigParameter::StableCoin);
 
     // Verify that the stablecoin address has been updated
     let updated_stablecoin = client.get_stablecoin();
@@ -140,8 +147,12 @@ fn test_receiver_payer_setter_getter() {
     // Change the receiver and payer addresses
     let new_receiver = Address::generate(&env);
     let new_payer = Address::generate(&env);
-    client.set_receiver(&new_receiver);
-    client.set_payer(&new_payer);
+    client.propose_change(&ConfigChange::Receiver(new_receiver.clone()));
+    client.propose_change(&ConfigChange::Payer(new_payer.clone()));
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + client.get_change_delay());
+    client.execute_change(&ConfigParameter::Receiver);
+    client.execute_change(&ConfigParameter::Payer);
 
     // Verify that the receiver and payer addresses have been updated
     let updated_receiver = client.get_receiver();
@@ -173,7 +184,7 @@ fn test_investor_creation() {
     );
 
     let new_investor = Address::generate(&env);
-    client.create_investor(&new_investor);
+    client.create_investor(&owner, &new_investor);
 
     // Check if the investor was created successfully
     let is_investor = client.is_investor(&new_investor);
@@ -189,7 +200,6 @@ fn test_investor_creation() {
 }
 
 #[test]
-#[should_panic(expected = "INVESTOR_ALREADY_EXISTS")]
 fn test_double_investor_creation() {
     let env = Env::default();
     let receiver = Address::generate(&env);
@@ -212,14 +222,17 @@ fn test_double_investor_creation() {
     );
 
     let new_investor = Address::generate(&env);
-    client.create_investor(&new_investor);
+    client.create_investor(&owner, &new_investor);
 
     // Check if the investor was created successfully
     let is_investor = client.is_investor(&new_investor);
     assert!(is_investor);
 
-    // Attempt to create the same investor again, which should panic
-    client.create_investor(&new_investor);
+    // Attempt to create the same investor again, which should fail
+    assert_eq!(
+        client.try_create_investor(&owner, &new_investor),
+        Err(Ok(MinahError::InvestorAlreadyExists))
+    );
 }
 
 #[test]
@@ -245,7 +258,7 @@ fn test_deploy_mock_stablecoin() {
 }
 
 #[test]
-#[should_panic(expected = "ROI_PERCENTAGES_LENGTH_MUST_BE_10")]
+#[should_panic(expected = "Error(Contract, #2)")] // MinahError::InvalidReleaseSchedule
 fn test_initialization_with_invalid_roi_percentages_length() {
     let env = Env::default();
     let receiver = Address::generate(&env);
@@ -253,7 +266,7 @@ fn test_initialization_with_invalid_roi_percentages_length() {
     let owner = Address::generate(&env);
     let stablecoin_address = deploy_stablecoin_contract(&env, &owner, 1000000);
 
-    // Create ROI percentages with wrong length (9 instead of 10)
+    // Create ROI percentages with a different length than the intervals (9 instead of 10)
     let mut invalid_roi_percentages = roi_percentages_vec(&env);
     invalid_roi_percentages.pop_back();
 
@@ -273,7 +286,7 @@ fn test_initialization_with_invalid_roi_percentages_length() {
 }
 
 #[test]
-#[should_panic(expected = "DISTRIBUTION_INTERVALS_LENGTH_MUST_BE_10")]
+#[should_panic(expected = "Error(Contract, #2)")] // MinahError::InvalidReleaseSchedule
 fn test_initialization_with_invalid_distribution_intervals_length() {
     let env = Env::default();
     let receiver = Address::generate(&env);
@@ -281,7 +294,7 @@ fn test_initialization_with_invalid_distribution_intervals_length() {
     let owner = Address::generate(&env);
     let stablecoin_address = deploy_stablecoin_contract(&env, &owner, 1000000);
 
-    // Create distribution intervals with wrong length (11 instead of 10)
+    // Create distribution intervals with a different length than the percentages (11 instead of 10)
     let mut invalid_intervals = distribution_intervals_vec(&env);
     invalid_intervals.push_back(700);
 
@@ -300,6 +313,90 @@ fn test_initialization_with_invalid_distribution_intervals_length() {
     );
 }
 
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")] // MinahError::InvalidReleaseSchedule
+fn test_initialization_with_empty_release_schedule() {
+    let env = Env::default();
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let stablecoin_address = deploy_stablecoin_contract(&env, &owner, 1000000);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        vec![&env],
+        vec![&env],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")] // MinahError::InvalidReleaseSchedule
+fn test_initialization_with_unordered_distribution_intervals() {
+    let env = Env::default();
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let stablecoin_address = deploy_stablecoin_contract(&env, &owner, 1000000);
+
+    // Second interval happens before the first one
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        vec![&env, 120u64, 60u64],
+        vec![&env, 40_000_000i128, 40_000_000i128],
+    );
+}
+
+#[test]
+fn test_initialization_with_custom_stage_count() {
+    let env = Env::default();
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let stablecoin_address = deploy_stablecoin_contract(&env, &owner, 1000000);
+
+    // 36 monthly payouts
+    let mut intervals: Vec<u64> = Vec::new(&env);
+    let mut percentages: Vec<i128> = Vec::new(&env);
+    for month in 1..=36u64 {
+        intervals.push_back(month * 2_628_000);
+        percentages.push_back(10_000_000);
+    }
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        intervals.clone(),
+        percentages.clone(),
+    );
+
+    assert_eq!(client.get_stage_count(), 36);
+    assert_eq!(client.get_distribution_intervals(), intervals);
+    assert_eq!(client.get_roi_percentages(), percentages);
+}
+
 #[test]
 fn test_initial_state_is_buying_phase() {
     let env = Env::default();
@@ -405,9 +502,9 @@ fn test_multiple_investors_creation() {
     let investor2 = Address::generate(&env);
     let investor3 = Address::generate(&env);
 
-    client.create_investor(&investor1);
-    client.create_investor(&investor2);
-    client.create_investor(&investor3);
+    client.create_investor(&owner, &investor1);
+    client.create_investor(&owner, &investor2);
+    client.create_investor(&owner, &investor3);
 
     // Verify all are investors
     assert!(client.is_investor(&investor1));
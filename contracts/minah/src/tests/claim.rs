@@ -0,0 +1,206 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, DISTRIBUTION_INTERVALS, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT,
+        PRICE, ROI_PERCENTAGES, TOTAL_SUPPLY, USDC_DECIMALS,
+    },
+    InvestmentStatus, MinahError,
+};
+
+#[test]
+fn test_claim_released_roi() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let investor1 = Address::generate(&env);
+    let investor2 = Address::generate(&env);
+
+    mint_nft(
+        &env,
+        &client,
+        &investor1,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    mint_nft(
+        &env,
+        &client,
+        &investor2,
+        50,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    client.start_chronometer();
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let payer_funding = 1_000_000 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &payer, &payer_funding);
+    stablecoin_client.approve(&payer, &contract_id, &payer_funding, &1000);
+
+    let start_time = client.get_begin_date();
+
+    // --- Release the first two stages without claiming ---
+    env.ledger()
+        .set_timestamp(start_time + DISTRIBUTION_INTERVALS[1]);
+    client.release_distribution(&owner);
+
+    assert_eq!(
+        client.get_current_state(),
+        InvestmentStatus::Distributing(2)
+    );
+
+    let price = client.get_nft_price();
+    let per_nft_0 = (ROI_PERCENTAGES[0] * price) / 100;
+    let per_nft_1 = (ROI_PERCENTAGES[1] * price) / 100;
+
+    assert_eq!(client.get_stage_amount_per_nft(&0), per_nft_0);
+    assert_eq!(client.get_stage_amount_per_nft(&1), per_nft_1);
+
+    let expected_investor1 = 100 * (per_nft_0 + per_nft_1);
+    let expected_investor2 = 50 * (per_nft_0 + per_nft_1);
+
+    // CHECK: The stages are funded by the payer and held by the contract
+    assert_eq!(
+        stablecoin_client.balance(&contract_id),
+        expected_investor1 + expected_investor2
+    );
+    assert_eq!(stablecoin_client.balance(&investor1), 0);
+    assert_eq!(client.see_claimable_amount(&investor1), expected_investor1);
+    assert_eq!(client.see_claimed_amount(&investor1), 0);
+
+    // --- Investor1 claims ---
+    let claimed = client.claim(&investor1);
+
+    assert_eq!(claimed, expected_investor1);
+    assert_eq!(stablecoin_client.balance(&investor1), expected_investor1);
+    assert_eq!(client.see_claimable_amount(&investor1), 0);
+    assert_eq!(client.see_claimed_amount(&investor1), expected_investor1);
+
+    // CHECK: Investor2 share is left in the contract until claimed
+    assert_eq!(stablecoin_client.balance(&contract_id), expected_investor2);
+
+    client.claim(&investor2);
+
+    assert_eq!(stablecoin_client.balance(&investor2), expected_investor2);
+    assert_eq!(stablecoin_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_claim_twice() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    client.start_chronometer();
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let payer_funding = 1_000_000 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &payer, &payer_funding);
+    stablecoin_client.approve(&payer, &contract_id, &payer_funding, &1000);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+    client.release_distribution(&owner);
+
+    client.claim(&investor);
+    assert_eq!(
+        client.try_claim(&investor),
+        Err(Ok(MinahError::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_claim_before_any_release() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    assert_eq!(
+        client.try_claim(&investor),
+        Err(Ok(MinahError::NothingToClaim))
+    );
+}
@@ -0,0 +1,469 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Vec,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, setup_trading_offering, token_range, TradingOffering,
+        DISTRIBUTION_INTERVALS, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT, PRICE, ROI_PERCENTAGES,
+        TOTAL_SUPPLY, USDC_DECIMALS,
+    },
+    ComplianceError, ConfigChange, ConfigParameter, InvestorProfile, InvestorTier,
+    MarketplaceError, MinahError,
+};
+
+#[test]
+fn test_frozen_investor_entitlement_held_in_escrow() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    client.freeze_investor(&owner, &investor1);
+    assert!(client.is_investor_frozen(&investor1));
+    assert_eq!(
+        client.try_freeze_investor(&owner, &investor1),
+        Err(Ok(ComplianceError::InvestorFrozen))
+    );
+
+    // --- Release the first stage ---
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+    client.release_distribution(&owner);
+
+    let per_nft = (ROI_PERCENTAGES[0] * client.get_nft_price()) / 100;
+
+    // CHECK: The entitlement of the frozen investor is escrowed, the other one is unaffected
+    assert_eq!(client.see_claimable_amount(&investor1), 0);
+    assert_eq!(client.get_frozen_amount(&investor1), 100 * per_nft);
+    assert_eq!(client.see_claimable_amount(&investor2), 50 * per_nft);
+
+    // CHECK: The frozen investor can neither claim nor trade
+    let frozen = Some(Ok(MinahError::InvestorFrozen));
+    assert_eq!(client.try_claim(&investor1).err(), frozen);
+    assert_eq!(
+        client
+            .try_buy_tokens(&investor1, &investor2, &token_range(&env, 0, 10))
+            .err(),
+        frozen
+    );
+    assert_eq!(
+        client
+            .try_sell_tokens(&investor1, &investor2, &token_range(&env, 0, 10))
+            .err(),
+        frozen
+    );
+
+    // --- Unfreeze releases the escrow ---
+    assert_eq!(client.unfreeze_investor(&owner, &investor1), 100 * per_nft);
+    assert_eq!(client.get_frozen_amount(&investor1), 0);
+    assert_eq!(client.claim(&investor1), 100 * per_nft);
+    assert_eq!(
+        client.try_unfreeze_investor(&owner, &investor1),
+        Err(Ok(ComplianceError::InvestorNotFrozen))
+    );
+}
+
+#[test]
+fn test_remove_investor_keeps_index_consistent() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let investor3 = Address::generate(&env);
+    client.create_investor(&owner, &investor3);
+    assert_eq!(client.get_investors_array_length(), 3);
+
+    // CHECK: An investor holding NFTs needs a custodian
+    assert_eq!(
+        client.try_remove_investor(&owner, &investor1, &None, &Vec::new(&env)),
+        Err(Ok(ComplianceError::InvestorHasBalance))
+    );
+
+    // CHECK: Only registered investors can be removed or take the NFTs
+    assert_eq!(
+        client.try_remove_investor(&owner, &Address::generate(&env), &None, &Vec::new(&env)),
+        Err(Ok(ComplianceError::UserNotAnInvestor))
+    );
+    assert_eq!(
+        client.try_remove_investor(
+            &owner,
+            &investor1,
+            &Some(Address::generate(&env)),
+            &token_range(&env, 0, 100)
+        ),
+        Err(Ok(ComplianceError::UserNotAnInvestor))
+    );
+
+    // --- Remove the first investor, who holds nothing ---
+    client.remove_investor(&owner, &investor3, &None, &Vec::new(&env));
+    assert!(!client.is_investor(&investor3));
+    assert_eq!(client.get_investors_array_length(), 2);
+
+    // --- Force investor1 out to investor2 ---
+    client.remove_investor(
+        &owner,
+        &investor1,
+        &Some(investor2.clone()),
+        &token_range(&env, 0, 100),
+    );
+    assert!(!client.is_investor(&investor1));
+    assert_eq!(client.get_investors_array_length(), 1);
+    assert_eq!(client.balance(&investor1), 0);
+    assert_eq!(client.balance(&investor2), 150);
+
+    // CHECK: The remaining investor receives the whole stage
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+    client.release_distribution(&owner);
+
+    let per_nft = (ROI_PERCENTAGES[0] * client.get_nft_price()) / 100;
+    assert_eq!(client.see_claimable_amount(&investor2), 150 * per_nft);
+    assert_eq!(client.see_claimable_amount(&investor1), 0);
+}
+
+#[test]
+fn test_remove_investor_waits_for_due_release() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let investor3 = Address::generate(&env);
+    client.create_investor(&owner, &investor3);
+
+    // CHECK: The first stage is due, investor1 cannot leave before it is released
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+    assert_eq!(
+        client.try_remove_investor(
+            &owner,
+            &investor1,
+            &Some(investor3.clone()),
+            &token_range(&env, 0, 100)
+        ),
+        Err(Ok(ComplianceError::ReleasePending))
+    );
+
+    client.release_distribution(&owner);
+
+    // --- Force investor1 out, investor3 takes its slot in the index ---
+    client.remove_investor(
+        &owner,
+        &investor1,
+        &Some(investor3.clone()),
+        &token_range(&env, 0, 100),
+    );
+
+    // --- Force investor3 out from that slot, investor2 takes it ---
+    client.remove_investor(
+        &owner,
+        &investor3,
+        &Some(investor2.clone()),
+        &token_range(&env, 0, 100),
+    );
+    assert_eq!(client.get_investors_array_length(), 1);
+
+    // CHECK: investor1 kept the stage it held the NFTs for, investor2 receives the next one
+    let price = client.get_nft_price();
+    let stage_0_per_nft = (ROI_PERCENTAGES[0] * price) / 100;
+    let stage_1_per_nft = (ROI_PERCENTAGES[1] * price) / 100;
+    assert_eq!(
+        client.see_claimable_amount(&investor1),
+        100 * stage_0_per_nft
+    );
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[1]);
+    client.release_distribution(&owner);
+
+    assert_eq!(
+        client.see_claimable_amount(&investor2),
+        50 * stage_0_per_nft + 150 * stage_1_per_nft
+    );
+    assert_eq!(client.see_claimable_amount(&investor3), 0);
+}
+
+#[test]
+fn test_tier_caps_limit_mint() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_tier_cap(&InvestorTier::Institutional, &2_000);
+
+    // CHECK: Tiers without a cap of their own use MaxNFTsPerInvestor
+    assert_eq!(
+        client.get_tier_cap(&InvestorTier::Retail),
+        MAX_NFTS_PER_INVESTOR
+    );
+    assert_eq!(client.get_tier_cap(&InvestorTier::Institutional), 2_000);
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        MAX_NFTS_PER_INVESTOR,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    // --- A retail investor is capped ---
+    assert_eq!(
+        client.try_mint(&investor, &MIN_NFTS_TO_MINT),
+        Err(Ok(MinahError::MaximumNftsPerInvestorExceeded))
+    );
+
+    // --- Once institutional they can hold more ---
+    client.set_investor_kyc(&owner, &investor, &InvestorTier::Institutional, &u64::MAX);
+    assert_eq!(
+        client.get_investor_profile(&investor),
+        InvestorProfile {
+            tier: InvestorTier::Institutional,
+            kyc_expires_at: u64::MAX,
+        }
+    );
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let amount = PRICE * MIN_NFTS_TO_MINT as i128 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &investor, &amount);
+    stablecoin_client.approve(&investor, &contract_id, &amount, &100);
+
+    client.mint(&investor, &MIN_NFTS_TO_MINT);
+    assert_eq!(
+        client.balance(&investor),
+        MAX_NFTS_PER_INVESTOR + MIN_NFTS_TO_MINT
+    );
+}
+
+#[test]
+fn test_kyc_expiry_and_caps_on_marketplace() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    // CHECK: The cap applies to the receiving side of the marketplace
+    client.set_tier_cap(&InvestorTier::Retail, &120);
+    assert_eq!(
+        client.try_buy_tokens(&investor1, &investor2, &token_range(&env, 0, 80)),
+        Err(Ok(MinahError::MaximumNftsPerInvestorExceeded))
+    );
+    assert_eq!(
+        client.try_sell_tokens(&investor1, &investor2, &token_range(&env, 0, 80)),
+        Err(Ok(MinahError::MaximumNftsPerInvestorExceeded))
+    );
+    client.buy_tokens(&investor1, &investor2, &token_range(&env, 0, 70));
+    assert_eq!(client.balance(&investor2), 120);
+
+    // investor1 buys back with the proceeds of the sale
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &client.get_stablecoin());
+    stablecoin_client.approve(
+        &investor1,
+        &client.address,
+        &(70 * PRICE * 10i128.pow(USDC_DECIMALS)),
+        &1000,
+    );
+    client.approve_for_all(&investor2, &client.address, &1000);
+
+    // --- The KYC of investor1 expires ---
+    let now = env.ledger().timestamp();
+    client.set_investor_kyc(&owner, &investor1, &InvestorTier::Retail, &(now + 100));
+    env.ledger().set_timestamp(now + 100);
+
+    assert_eq!(
+        client.try_buy_tokens(&investor2, &investor1, &token_range(&env, 0, 10)),
+        Err(Ok(MinahError::KycExpired))
+    );
+
+    // --- Renewal ---
+    client.set_investor_kyc(&owner, &investor1, &InvestorTier::Retail, &(now + 1_000));
+    client.buy_tokens(&investor2, &investor1, &token_range(&env, 0, 10));
+    assert_eq!(client.balance(&investor1), 40);
+}
+
+#[test]
+fn test_peer_to_peer_transfers_follow_trading_rules() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let restricted = Err(Ok(ComplianceError::TransferRestricted.into()));
+
+    // --- Between investors ---
+    client.transfer(&investor1, &investor2, &0);
+    assert_eq!(client.balance(&investor1), 99);
+    assert_eq!(client.balance(&investor2), 51);
+    assert_eq!(client.owner_of(&0), investor2);
+
+    // --- Through an approved spender ---
+    let spender = Address::generate(&env);
+    client.approve(&investor1, &spender, &1, &1000);
+    client.transfer_from(&spender, &investor1, &investor2, &1);
+    assert_eq!(client.owner_of(&1), investor2);
+
+    // CHECK: The recipient must be an investor
+    assert_eq!(
+        client.try_transfer(&investor1, &Address::generate(&env), &2),
+        restricted
+    );
+
+    // CHECK: The recipient must stay within the cap of their tier
+    client.set_tier_cap(&InvestorTier::Retail, &52);
+    assert_eq!(client.try_transfer(&investor1, &investor2, &2), restricted);
+    client.set_tier_cap(&InvestorTier::Retail, &MAX_NFTS_PER_INVESTOR);
+
+    // CHECK: Neither side can be frozen
+    client.freeze_investor(&owner, &investor2);
+    assert_eq!(client.try_transfer(&investor1, &investor2, &2), restricted);
+    assert_eq!(client.try_transfer(&investor2, &investor1, &0), restricted);
+    client.unfreeze_investor(&owner, &investor2);
+
+    client.transfer(&investor2, &investor1, &0);
+    assert_eq!(client.balance(&investor1), 99);
+}
+
+#[test]
+fn test_lock_up_blocks_trades_and_transfers() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    // --- Offering lock-up of 1000 seconds ---
+    let executable_at = client.propose_change(&ConfigChange::LockUpPeriod(1_000));
+    env.ledger().set_timestamp(executable_at);
+    client.execute_change(&ConfigParameter::LockUpPeriod);
+    assert_eq!(client.get_lock_up_period(), 1_000);
+
+    let investor1 = Address::generate(&env);
+    let investor2 = Address::generate(&env);
+
+    mint_nft(
+        &env,
+        &client,
+        &investor1,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    mint_nft(
+        &env,
+        &client,
+        &investor2,
+        50,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    // --- investor2 is locked up for 5000 seconds from their next mint ---
+    client.set_investor_lock_up(&owner, &investor2, &5_000);
+    assert_eq!(client.get_investor_lock_up(&investor2), 5_000);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let amount = PRICE * MIN_NFTS_TO_MINT as i128 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &investor2, &amount);
+    stablecoin_client.approve(&investor2, &contract_id, &amount, &100);
+    client.mint(&investor2, &MIN_NFTS_TO_MINT);
+
+    assert_eq!(client.transferable_after(&investor1), u64::MAX);
+
+    client.start_chronometer();
+    let begin_date = client.get_begin_date();
+
+    assert_eq!(client.transferable_after(&investor1), begin_date + 1_000);
+    assert_eq!(client.transferable_after(&investor2), begin_date + 5_000);
+
+    // CHECK: Nothing changes hands during the offering lock-up
+    assert_eq!(
+        client.try_buy_tokens(&investor1, &investor2, &token_range(&env, 0, 10)),
+        Err(Ok(MinahError::LockedUp))
+    );
+    assert_eq!(
+        client.try_transfer(&investor1, &investor2, &0),
+        Err(Ok(ComplianceError::TransferRestricted.into()))
+    );
+
+    // --- After the offering lock-up only investor2 is still locked up ---
+    env.ledger().set_timestamp(begin_date + 1_000);
+    client.transfer(&investor1, &investor2, &0);
+    assert_eq!(
+        client.try_transfer(&investor2, &investor1, &0),
+        Err(Ok(ComplianceError::TransferRestricted.into()))
+    );
+    assert_eq!(
+        client.try_list(
+            &investor2,
+            &token_range(&env, 100, 110),
+            &1,
+            &(begin_date + 10_000)
+        ),
+        Err(Ok(MarketplaceError::LockedUp))
+    );
+
+    env.ledger().set_timestamp(begin_date + 5_000);
+    client.transfer(&investor2, &investor1, &0);
+    assert_eq!(client.balance(&investor1), 100);
+}
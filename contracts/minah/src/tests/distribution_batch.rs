@@ -0,0 +1,251 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Vec,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, DISTRIBUTION_INTERVALS, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT,
+        PRICE, ROI_PERCENTAGES, TOTAL_SUPPLY, USDC_DECIMALS,
+    },
+    InvestmentStatus, MinahClient, MinahError,
+};
+
+/// Starts an offering with three investors (100, 50 and 25 NFTs) and a funded payer.
+fn setup_distributing_offering<'a>(
+    env: &Env,
+) -> (MinahClient<'a>, Address, Address, Address, Vec<Address>) {
+    let owner = Address::generate(env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(env);
+    let payer = Address::generate(env);
+
+    let (client, contract_id) = create_client(
+        env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(env),
+        roi_percentages_vec(env),
+    );
+
+    let mut investors: Vec<Address> = Vec::new(env);
+    for amount in [100, 50, 25] {
+        let investor = Address::generate(env);
+        mint_nft(
+            env,
+            &client,
+            &investor,
+            amount,
+            &owner,
+            &stablecoin_address,
+            &contract_id,
+        );
+        investors.push_back(investor);
+    }
+
+    client.start_chronometer();
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(env, &stablecoin_address);
+    let payer_funding = 1_000_000 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &payer, &payer_funding);
+    stablecoin_client.approve(&payer, &contract_id, &payer_funding, &1000);
+
+    (client, contract_id, stablecoin_address, owner, investors)
+}
+
+#[test]
+fn test_release_distribution_in_batches() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, owner, investors) =
+        setup_distributing_offering(&env);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+
+    let amount_per_nft = (ROI_PERCENTAGES[0] * client.get_nft_price()) / 100;
+
+    // --- First page: investors 0 and 1 ---
+    assert!(!client.release_distribution_batch(&owner, &2));
+
+    let progress = client.get_distribution_progress().unwrap();
+    assert_eq!(progress.stage, 0);
+    assert_eq!(progress.next_investor, 2);
+    assert_eq!(progress.investors_count, 3);
+    assert_eq!(progress.distributed_nfts, 150);
+    assert_eq!(progress.distributed_amount, 150 * amount_per_nft);
+
+    // CHECK: The stage is neither funded nor completed yet
+    assert_eq!(
+        client.get_current_state(),
+        InvestmentStatus::Distributing(0)
+    );
+    assert_eq!(stablecoin_client.balance(&contract_id), 0);
+    assert_eq!(
+        client.see_claimable_amount(&investors.get(0).unwrap()),
+        100 * amount_per_nft
+    );
+    assert_eq!(client.see_claimable_amount(&investors.get(2).unwrap()), 0);
+
+    // --- Last page: investor 2 ---
+    assert!(client.release_distribution_batch(&owner, &2));
+
+    assert_eq!(client.get_distribution_progress(), None);
+    assert_eq!(
+        client.get_current_state(),
+        InvestmentStatus::Distributing(1)
+    );
+    assert_eq!(
+        client.see_claimable_amount(&investors.get(2).unwrap()),
+        25 * amount_per_nft
+    );
+    assert_eq!(
+        stablecoin_client.balance(&contract_id),
+        175 * amount_per_nft
+    );
+}
+
+#[test]
+fn test_release_distribution_batch_single_page() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, owner, investors) =
+        setup_distributing_offering(&env);
+
+    // Two stages are ready, each batch call only releases one of them
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[1]);
+
+    assert!(client.release_distribution_batch(&owner, &10));
+    assert_eq!(
+        client.get_current_state(),
+        InvestmentStatus::Distributing(1)
+    );
+
+    assert!(client.release_distribution_batch(&owner, &10));
+    assert_eq!(
+        client.get_current_state(),
+        InvestmentStatus::Distributing(2)
+    );
+
+    let price = client.get_nft_price();
+    let expected = 100 * ((ROI_PERCENTAGES[0] * price) / 100 + (ROI_PERCENTAGES[1] * price) / 100);
+    assert_eq!(
+        client.see_claimable_amount(&investors.get(0).unwrap()),
+        expected
+    );
+}
+
+#[test]
+fn test_release_distribution_while_batch_in_progress() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, owner, investors) =
+        setup_distributing_offering(&env);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+
+    client.release_distribution_batch(&owner, &1);
+
+    assert_eq!(
+        client.try_release_distribution(&owner),
+        Err(Ok(MinahError::DistributionBatchInProgress))
+    );
+}
+
+#[test]
+fn test_claim_blocked_while_batch_in_progress() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, owner, investors) =
+        setup_distributing_offering(&env);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let price = client.get_nft_price();
+    let per_nft_0 = (ROI_PERCENTAGES[0] * price) / 100;
+    let per_nft_1 = (ROI_PERCENTAGES[1] * price) / 100;
+
+    // --- Stage 0 is released and funded, nobody claims it ---
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+    assert!(client.release_distribution_batch(&owner, &10));
+
+    // --- Stage 1 is credited to investor 0 only, it is not funded yet ---
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[1]);
+    assert!(!client.release_distribution_batch(&owner, &1));
+
+    let investor0 = investors.get(0).unwrap();
+    assert_eq!(
+        client.see_claimable_amount(&investor0),
+        100 * (per_nft_0 + per_nft_1)
+    );
+
+    // CHECK: The unfunded credit cannot be paid with the stage 0 funds of the other investors
+    assert_eq!(
+        client.try_claim(&investor0),
+        Err(Ok(MinahError::DistributionBatchInProgress))
+    );
+    assert_eq!(stablecoin_client.balance(&contract_id), 175 * per_nft_0);
+
+    // --- The last page funds stage 1, everybody can claim ---
+    assert!(client.release_distribution_batch(&owner, &10));
+
+    assert_eq!(client.claim(&investor0), 100 * (per_nft_0 + per_nft_1));
+    assert_eq!(
+        client.claim(&investors.get(1).unwrap()),
+        50 * (per_nft_0 + per_nft_1)
+    );
+    assert_eq!(
+        client.claim(&investors.get(2).unwrap()),
+        25 * (per_nft_0 + per_nft_1)
+    );
+}
+
+#[test]
+fn test_trading_blocked_while_batch_in_progress() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, owner, investors) =
+        setup_distributing_offering(&env);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+
+    client.release_distribution_batch(&owner, &1);
+
+    let seller = investors.get(0).unwrap();
+    let buyer = investors.get(1).unwrap();
+
+    let mut token_ids: Vec<u32> = Vec::new(&env);
+    for i in 0..10 {
+        token_ids.push_back(i);
+    }
+
+    client.approve_for_all(&seller, &contract_id, &1000);
+    assert_eq!(
+        client.try_sell_tokens(&seller, &buyer, &token_ids),
+        Err(Ok(MinahError::DistributionBatchInProgress))
+    );
+}
+
+#[test]
+fn test_release_distribution_batch_not_ready() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, owner, investors) =
+        setup_distributing_offering(&env);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0] - 1);
+
+    assert_eq!(
+        client.try_release_distribution_batch(&owner, &10),
+        Err(Ok(MinahError::DistributionNotReadyYet))
+    );
+}
@@ -0,0 +1,136 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, mint_nft, MAX_NFTS_PER_INVESTOR,
+        MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY, USDC_DECIMALS,
+    },
+    MinahClient, MinahError,
+};
+
+const INTERVALS: [u64; 2] = [60, 120];
+// 3.3333333% does not divide evenly between 10, 10 and 11 NFTs
+const ROI_PERCENTAGE: i128 = 33_333_333;
+
+/// Starts an offering with three investors holding 10, 10 and 11 NFTs.
+fn setup_uneven_offering<'a>(env: &Env) -> (MinahClient<'a>, Address, Address, Address, Address) {
+    let owner = Address::generate(env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(env);
+    let payer = Address::generate(env);
+
+    let (client, contract_id) = create_client(
+        env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        vec![env, INTERVALS[0], INTERVALS[1]],
+        vec![env, ROI_PERCENTAGE, ROI_PERCENTAGE],
+    );
+
+    for amount in [10, 10, 11] {
+        let investor = Address::generate(env);
+        mint_nft(
+            env,
+            &client,
+            &investor,
+            amount,
+            &owner,
+            &stablecoin_address,
+            &contract_id,
+        );
+    }
+
+    client.start_chronometer();
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(env, &stablecoin_address);
+    let funding = 1_000_000 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &payer, &funding);
+    stablecoin_client.approve(&payer, &contract_id, &funding, &1000);
+
+    (client, contract_id, stablecoin_address, owner, payer)
+}
+
+#[test]
+fn test_stage_remainder_recorded_as_dust() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, owner, payer) = setup_uneven_offering(&env);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + INTERVALS[0]);
+    client.release_distribution(&owner);
+
+    // floor(10 * 333333.33) * 2 + floor(11 * 333333.33) = 10_333_332
+    // floor(31 * 333333.33) = 10_333_333
+    let stage_amount = client.calculate_amount_to_release(&ROI_PERCENTAGE);
+    assert_eq!(stage_amount, 10_333_333);
+    assert_eq!(client.get_stage_remainder(&0), 1);
+    assert_eq!(client.get_dust_balance(), 1);
+
+    // CHECK: the whole stage amount is held by the contract
+    assert_eq!(stablecoin_client.balance(&contract_id), stage_amount);
+
+    // --- Sweep the dust back to the payer ---
+    let payer_balance_before = stablecoin_client.balance(&payer);
+    client.sweep_dust(&owner, &payer);
+
+    assert_eq!(client.get_dust_balance(), 0);
+    assert_eq!(stablecoin_client.balance(&payer), payer_balance_before + 1);
+    assert_eq!(stablecoin_client.balance(&contract_id), stage_amount - 1);
+}
+
+#[test]
+fn test_roll_dust_into_next_stage() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, owner, payer) = setup_uneven_offering(&env);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + INTERVALS[0]);
+    client.release_distribution(&owner);
+
+    client.roll_dust(&owner);
+
+    assert_eq!(client.get_dust_balance(), 0);
+    assert_eq!(client.get_rolled_dust(), 1);
+
+    let payer_balance_before = stablecoin_client.balance(&payer);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + INTERVALS[1]);
+    client.release_distribution(&owner);
+
+    // CHECK: the payer is charged the stage amount minus the rolled dust
+    let stage_amount = client.calculate_amount_to_release(&ROI_PERCENTAGE);
+    assert_eq!(
+        stablecoin_client.balance(&payer),
+        payer_balance_before - (stage_amount - 1)
+    );
+    assert_eq!(client.get_rolled_dust(), 0);
+
+    // The second stage leaves its own remainder
+    assert_eq!(client.get_dust_balance(), 1);
+}
+
+#[test]
+fn test_sweep_without_dust() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, owner, payer) = setup_uneven_offering(&env);
+
+    assert_eq!(
+        client.try_sweep_dust(&owner, &payer),
+        Err(Ok(MinahError::NoDust))
+    );
+}
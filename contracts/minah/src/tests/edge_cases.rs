@@ -332,7 +332,7 @@ fn test_state_progression_through_releases() {
     client.start_chronometer();
     assert_eq!(
         client.get_current_state(),
-        crate::InvestmentStatus::BeforeFirstRelease
+        crate::InvestmentStatus::Distributing(0)
     );
 }
 
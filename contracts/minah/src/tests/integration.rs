@@ -55,7 +55,7 @@ fn test_full_investment_lifecycle() {
     client.start_chronometer();
     let start_time = env.ledger().timestamp();
 
-    assert_eq!(client.get_current_state(), crate::InvestmentStatus::BeforeFirstRelease);
+    assert_eq!(client.get_current_state(), crate::InvestmentStatus::Distributing(0));
     assert_eq!(client.get_current_supply(), TOTAL_SUPPLY); // Owner gets remaining
     assert_eq!(client.get_nft_buying_phase_supply(), 175);
 
@@ -64,9 +64,9 @@ fn test_full_investment_lifecycle() {
     let percent_0 = ROI_PERCENTAGES[0];
     let amount_0 = client.calculate_amount_to_release(&percent_0);
     stablecoin_client.approve(&payer, &contract_id, &amount_0, &100);
-    client.release_distribution();
+    client.release_distribution(&owner);
 
-    assert_eq!(client.get_current_state(), crate::InvestmentStatus::Release1);
+    assert_eq!(client.get_current_state(), crate::InvestmentStatus::Distributing(1));
 
     // Phase 4: Trading between investors
     let mut token_ids: Vec<u32> = Vec::new(&env);
@@ -90,17 +90,17 @@ fn test_full_investment_lifecycle() {
     let percent_1 = ROI_PERCENTAGES[1];
     let amount_1 = client.calculate_amount_to_release(&percent_1);
     stablecoin_client.approve(&payer, &contract_id, &amount_1, &100);
-    client.release_distribution();
+    client.release_distribution(&owner);
 
-    assert_eq!(client.get_current_state(), crate::InvestmentStatus::Release2);
+    assert_eq!(client.get_current_state(), crate::InvestmentStatus::Distributing(2));
 
-    // Verify claimed amounts reflect both distributions
+    // Verify claimable amounts reflect both distributions
     let price = client.get_nft_price();
     let expected_investor1_stage0 = (100 * price * percent_0) / 100;
     let expected_investor1_stage1 = (90 * price * percent_1) / 100; // After selling 10
     let total_investor1 = expected_investor1_stage0 + expected_investor1_stage1;
 
-    assert_eq!(client.see_claimed_amount(&investor1), total_investor1);
+    assert_eq!(client.see_claimable_amount(&investor1), total_investor1);
 }
 
 /// Test with maximum investors and full supply
@@ -192,13 +192,13 @@ fn test_distribution_after_investor_sells_all() {
     let percent_0 = ROI_PERCENTAGES[0];
     let amount_0 = client.calculate_amount_to_release(&percent_0);
     stablecoin_client.approve(&payer, &contract_id, &amount_0, &100);
-    client.release_distribution();
+    client.release_distribution(&owner);
 
-    let claimed_after_first = client.see_claimed_amount(&seller);
-    assert!(claimed_after_first > 0);
+    let claimable_after_first = client.see_claimable_amount(&seller);
+    assert!(claimable_after_first > 0);
 
     // Seller sells all NFTs to buyer
-    client.create_investor(&buyer);
+    client.create_investor(&owner, &buyer);
     let mut token_ids: Vec<u32> = Vec::new(&env);
     for i in 0..50 {
         token_ids.push_back(i);
@@ -220,15 +220,15 @@ fn test_distribution_after_investor_sells_all() {
     let percent_1 = ROI_PERCENTAGES[1];
     let amount_1 = client.calculate_amount_to_release(&percent_1);
     stablecoin_client.approve(&payer, &contract_id, &amount_1, &100);
-    client.release_distribution();
+    client.release_distribution(&owner);
 
-    // Seller's claimed amount should not increase (has 0 NFTs)
-    assert_eq!(client.see_claimed_amount(&seller), claimed_after_first);
+    // Seller's claimable amount should not increase (has 0 NFTs)
+    assert_eq!(client.see_claimable_amount(&seller), claimable_after_first);
 
     // Buyer should have received distribution for their 50 NFTs
     let price = client.get_nft_price();
     let expected_buyer = (50 * price * percent_1) / 100;
-    assert_eq!(client.see_claimed_amount(&buyer), expected_buyer);
+    assert_eq!(client.see_claimable_amount(&buyer), expected_buyer);
 }
 
 /// Test complex trading scenario with multiple swaps
@@ -0,0 +1,232 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+use crate::{
+    tests::utils::{
+        setup_trading_offering, token_range, TradingOffering, DISTRIBUTION_INTERVALS,
+        ROI_PERCENTAGES, TOTAL_SUPPLY, USDC_DECIMALS,
+    },
+    ContinuousOffering, MarketplaceError, PausableFunction,
+};
+
+#[test]
+fn test_buy_from_inventory() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        receiver,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &client.get_stablecoin());
+
+    // CHECK: The inventory is tracked apart from the buying phase supply
+    assert_eq!(client.get_buying_phase_nft_supply(), 150);
+    assert_eq!(client.get_inventory_supply(), TOTAL_SUPPLY - 150);
+
+    let unit_price = 2 * 10i128.pow(USDC_DECIMALS);
+
+    assert_eq!(
+        client.try_buy_from_inventory(&investor2, &10, &unit_price),
+        Err(Ok(MarketplaceError::ContinuousOfferingClosed))
+    );
+
+    client.set_continuous_offering(&Some(ContinuousOffering {
+        unit_price,
+        deduct_paid_roi: false,
+    }));
+
+    // CHECK: The buyer is protected against a higher price
+    assert_eq!(
+        client.try_buy_from_inventory(&investor2, &10, &(unit_price - 1)),
+        Err(Ok(MarketplaceError::PriceAboveLimit))
+    );
+
+    // CHECK: Only investors can buy
+    assert_eq!(
+        client
+            .try_buy_from_inventory(&Address::generate(&env), &10, &unit_price)
+            .err(),
+        Some(Ok(MarketplaceError::UserNotAnInvestor))
+    );
+
+    let receiver_balance = stablecoin_client.balance(&receiver);
+    client.buy_from_inventory(&investor2, &10, &unit_price);
+
+    assert_eq!(client.balance(&investor2), 60);
+    assert_eq!(client.owner_of(&150), investor2);
+    assert_eq!(client.owner_of(&159), investor2);
+    assert_eq!(client.get_inventory_supply(), TOTAL_SUPPLY - 160);
+    assert_eq!(
+        stablecoin_client.balance(&receiver),
+        receiver_balance + 10 * unit_price
+    );
+
+    // CHECK: The inventory sold counts towards the next release, the rest of the inventory does not
+    assert_eq!(
+        client.calculate_amount_to_release(&ROI_PERCENTAGES[0]),
+        160 * client.get_nft_price() * ROI_PERCENTAGES[0] / 100
+    );
+
+    // --- Closing the offering ---
+    client.set_continuous_offering(&None);
+    assert_eq!(client.get_continuous_offering(), None);
+    assert_eq!(
+        client.try_buy_from_inventory(&investor2, &10, &unit_price),
+        Err(Ok(MarketplaceError::ContinuousOfferingClosed))
+    );
+    assert_eq!(client.balance(&owner), TOTAL_SUPPLY - 160);
+}
+
+#[test]
+fn test_inventory_price_and_owner_trades() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        receiver,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let unit_price = 10i128.pow(USDC_DECIMALS);
+    client.set_continuous_offering(&Some(ContinuousOffering {
+        unit_price,
+        deduct_paid_roi: true,
+    }));
+    assert_eq!(client.get_inventory_price(), unit_price);
+
+    // CHECK: The ROI already paid is taken off the price
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+    client.release_distribution(&owner);
+
+    let per_nft = (ROI_PERCENTAGES[0] * client.get_nft_price()) / 100;
+    assert_eq!(client.get_inventory_price(), unit_price - per_nft);
+
+    // --- The owner lists some inventory and sells some to investor2 ---
+    client.list(
+        &owner,
+        &token_range(&env, 150, 152),
+        &unit_price,
+        &(env.ledger().timestamp() + 1_000),
+    );
+    client.approve_for_all(&owner, &client.address, &1000);
+    client.buy_tokens(&owner, &investor2, &token_range(&env, 152, 154));
+    assert_eq!(client.get_inventory_supply(), TOTAL_SUPPLY - 152);
+
+    // CHECK: Listed and sold tokens are skipped, listed ones stay in the inventory
+    client.buy_from_inventory(&investor2, &3, &unit_price);
+    assert_eq!(client.owner_of(&154), investor2);
+    assert_eq!(client.owner_of(&156), investor2);
+    assert_eq!(client.owner_of(&150), owner);
+    assert_eq!(client.get_inventory_supply(), TOTAL_SUPPLY - 155);
+    assert_eq!(client.balance(&investor2), 55);
+}
+
+#[test]
+fn test_cancelled_inventory_listing_sells_again() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let unit_price = 10i128.pow(USDC_DECIMALS);
+    client.set_continuous_offering(&Some(ContinuousOffering {
+        unit_price,
+        deduct_paid_roi: false,
+    }));
+
+    // --- The owner lists the first inventory tokens, the offering sells the next ones ---
+    let listing_id = client.list(
+        &owner,
+        &token_range(&env, 150, 152),
+        &unit_price,
+        &(env.ledger().timestamp() + 1_000),
+    );
+    client.buy_from_inventory(&investor2, &2, &unit_price);
+    assert_eq!(client.owner_of(&152), investor2);
+    assert_eq!(client.owner_of(&153), investor2);
+    assert_eq!(client.get_inventory_supply(), TOTAL_SUPPLY - 152);
+
+    // CHECK: Once the listing is cancelled, the offering sells the tokens it skipped first
+    client.cancel_listing(&listing_id);
+    client.buy_from_inventory(&investor2, &3, &unit_price);
+    assert_eq!(client.owner_of(&150), investor2);
+    assert_eq!(client.owner_of(&151), investor2);
+    assert_eq!(client.owner_of(&154), investor2);
+    assert_eq!(client.get_inventory_supply(), TOTAL_SUPPLY - 155);
+    assert_eq!(client.balance(&owner), TOTAL_SUPPLY - 155);
+}
+
+#[test]
+fn test_buy_from_inventory_paused() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let unit_price = 10i128.pow(USDC_DECIMALS);
+    client.set_continuous_offering(&Some(ContinuousOffering {
+        unit_price,
+        deduct_paid_roi: false,
+    }));
+
+    // CHECK: Pausing the mint leaves the inventory sales open
+    client.pause_function(&owner, &PausableFunction::Mint);
+    client.buy_from_inventory(&investor2, &1, &unit_price);
+    assert_eq!(client.owner_of(&150), investor2);
+
+    // CHECK: Inventory sales have a pause of their own
+    client.pause_function(&owner, &PausableFunction::BuyFromInventory);
+    assert_eq!(
+        client.try_buy_from_inventory(&investor2, &1, &unit_price),
+        Err(Ok(MarketplaceError::FunctionPaused))
+    );
+
+    client.unpause_function(&owner, &PausableFunction::BuyFromInventory);
+    client.buy_from_inventory(&investor2, &1, &unit_price);
+    assert_eq!(client.owner_of(&151), investor2);
+}
+
+#[test]
+fn test_buy_from_inventory_blocked_while_batch_in_progress() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let unit_price = 10i128.pow(USDC_DECIMALS);
+    client.set_continuous_offering(&Some(ContinuousOffering {
+        unit_price,
+        deduct_paid_roi: false,
+    }));
+
+    // --- Stage 0 is released for the first holder only ---
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+    assert!(!client.release_distribution_batch(&owner, &1));
+
+    // CHECK: The inventory cannot change hands until every holder is credited
+    assert_eq!(
+        client.try_buy_from_inventory(&investor2, &1, &unit_price),
+        Err(Ok(MarketplaceError::DistributionBatchInProgress))
+    );
+
+    assert!(client.release_distribution_batch(&owner, &10));
+    client.buy_from_inventory(&investor2, &1, &unit_price);
+    assert_eq!(client.owner_of(&150), investor2);
+}
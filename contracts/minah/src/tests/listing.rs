@@ -0,0 +1,134 @@
+use soroban_sdk::{testutils::Ledger, Env};
+
+use crate::{
+    tests::utils::{setup_trading_offering, token_range, TradingOffering, PRICE, USDC_DECIMALS},
+    Listing, MarketplaceError, MinahError,
+};
+
+#[test]
+fn test_list_and_fill_listing() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &client.get_stablecoin());
+
+    // --- investor1 lists 20 NFTs at a discount ---
+    let unit_price = PRICE * 10i128.pow(USDC_DECIMALS) / 2;
+    let expiry = env.ledger().timestamp() + 1_000;
+    let listing_id = client.list(&investor1, &token_range(&env, 0, 20), &unit_price, &expiry);
+
+    assert_eq!(
+        client.get_listing(&listing_id),
+        Some(Listing {
+            seller: investor1.clone(),
+            token_ids: token_range(&env, 0, 20),
+            unit_price,
+            expiry,
+        })
+    );
+    assert_eq!(client.get_token_listing(&0), Some(listing_id));
+
+    // CHECK: Listed tokens are locked
+    assert_eq!(
+        client.try_sell_tokens(&investor1, &investor2, &token_range(&env, 0, 10)),
+        Err(Ok(MinahError::TokenListed))
+    );
+    assert_eq!(
+        client.try_list(&investor1, &token_range(&env, 10, 30), &unit_price, &expiry),
+        Err(Ok(MarketplaceError::TokenListed))
+    );
+
+    // --- investor2 buys part of the listing ---
+    let seller_balance_before = stablecoin_client.balance(&investor1);
+    client.fill_listing(&investor2, &listing_id, &5);
+
+    assert_eq!(client.balance(&investor1), 95);
+    assert_eq!(client.balance(&investor2), 55);
+    assert_eq!(client.owner_of(&0), investor2);
+    assert_eq!(
+        stablecoin_client.balance(&investor1),
+        seller_balance_before + 5 * unit_price
+    );
+    assert_eq!(client.get_token_listing(&0), None);
+    assert_eq!(
+        client.get_listing(&listing_id).unwrap().token_ids,
+        token_range(&env, 5, 20)
+    );
+
+    // CHECK: The listing is removed once sold out
+    assert_eq!(
+        client.try_fill_listing(&investor2, &listing_id, &16),
+        Err(Ok(MarketplaceError::InvalidQuantity))
+    );
+    client.fill_listing(&investor2, &listing_id, &15);
+    assert_eq!(client.get_listing(&listing_id), None);
+    assert_eq!(client.balance(&investor2), 70);
+}
+
+#[test]
+fn test_cancel_and_expired_listing() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let unit_price = PRICE * 10i128.pow(USDC_DECIMALS);
+    let now = env.ledger().timestamp();
+
+    // CHECK: Invalid listings are rejected
+    assert_eq!(
+        client.try_list(&investor1, &token_range(&env, 0, 10), &0, &(now + 100)),
+        Err(Ok(MarketplaceError::InvalidListing))
+    );
+    assert_eq!(
+        client.try_list(&investor1, &token_range(&env, 0, 10), &unit_price, &now),
+        Err(Ok(MarketplaceError::InvalidListing))
+    );
+    assert_eq!(
+        client
+            .try_list(
+                &investor1,
+                &token_range(&env, 100, 110),
+                &unit_price,
+                &(now + 100)
+            )
+            .err(),
+        Some(Ok(MarketplaceError::IncorrectOwner))
+    );
+
+    let listing_id = client.list(
+        &investor1,
+        &token_range(&env, 0, 10),
+        &unit_price,
+        &(now + 100),
+    );
+
+    // --- The listing expires ---
+    env.ledger().set_timestamp(now + 100);
+    assert_eq!(
+        client.try_fill_listing(&investor2, &listing_id, &1),
+        Err(Ok(MarketplaceError::ListingExpired))
+    );
+
+    // CHECK: Cancelling unlocks the tokens
+    client.cancel_listing(&listing_id);
+    assert_eq!(client.get_listing(&listing_id), None);
+    assert_eq!(client.get_token_listing(&0), None);
+    assert_eq!(
+        client.try_cancel_listing(&listing_id),
+        Err(Ok(MarketplaceError::ListingNotFound))
+    );
+
+    client.sell_tokens(&investor1, &investor2, &token_range(&env, 0, 10));
+    assert_eq!(client.balance(&investor2), 60);
+}
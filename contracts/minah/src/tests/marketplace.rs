@@ -5,9 +5,9 @@ use crate::tests::utils::{
     roi_percentages_vec, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY,
     USDC_DECIMALS,
 };
+use crate::{ComplianceError, MinahError};
 
 #[test]
-#[should_panic(expected = "TRANSFERS_DISABLED_FOR_MINAH_NFTS")]
 fn test_transfer_nft() {
     let env = Env::default();
 
@@ -53,7 +53,10 @@ fn test_transfer_nft() {
     let normal_user = Address::generate(&env);
 
     // Transfer 1 NFTs from investor to normal_user
-    client.transfer(&investor, &normal_user, &0);
+    assert_eq!(
+        client.try_transfer(&investor, &normal_user, &0),
+        Err(Ok(ComplianceError::TransferRestricted.into()))
+    );
 }
 
 #[test]
@@ -249,8 +252,7 @@ fn test_sell_tokens() {
 }
 
 #[test]
-#[should_panic(expected = "NFT_TRANSFERS_NOT_ALLOWED_DURING_BUYING_PHASE")]
-fn test_buy_tokens_in_buying_phase_should_panic() {
+fn test_buy_tokens_in_buying_phase() {
     let env = Env::default();
     let owner = Address::generate(&env);
     let stablecoin_address =
@@ -300,7 +302,7 @@ fn test_buy_tokens_in_buying_phase_should_panic() {
     );
 
     // Do NOT start chronometer -> still in BuyingPhase
-    // Attempt to buy tokens while in buying phase should panic
+    // Attempt to buy tokens while in buying phase
     let mut tokens_to_buy_from_investor1: Vec<u32> = Vec::new(&env);
     for i in 0..10 {
         tokens_to_buy_from_investor1.push_back(i);
@@ -311,13 +313,15 @@ fn test_buy_tokens_in_buying_phase_should_panic() {
     stablecoin_client.transfer(&owner, &investor2, &total_price_in_stablecoin);
     stablecoin_client.approve(&investor2, &contract_id, &total_price_in_stablecoin, &100);
 
-    // Attempt the buy - should panic early with NFT_TRANSFERS_NOT_ALLOWED_DURING_BUYING_PHASE
-    client.buy_tokens(&investor1, &investor2, &tokens_to_buy_from_investor1);
+    // Attempt the buy - should fail early with NftTransfersNotAllowedDuringBuyingPhase
+    assert_eq!(
+        client.try_buy_tokens(&investor1, &investor2, &tokens_to_buy_from_investor1),
+        Err(Ok(MinahError::NftTransfersNotAllowedDuringBuyingPhase))
+    );
 }
 
 #[test]
-#[should_panic(expected = "SPENDER_NOT_APPROVED_FOR_ALL")]
-fn test_buy_tokens_without_from_approval_should_panic() {
+fn test_buy_tokens_without_from_approval() {
     let env = Env::default();
     let owner = Address::generate(&env);
     let stablecoin_address =
@@ -379,14 +383,16 @@ fn test_buy_tokens_without_from_approval_should_panic() {
     stablecoin_client.transfer(&owner, &investor2, &total_price_in_stablecoin);
     stablecoin_client.approve(&investor2, &contract_id, &total_price_in_stablecoin, &100);
 
-    // NOTE: Do NOT call approve_for_all on investor1 - this should trigger SPENDER_NOT_APPROVED_FOR_ALL
+    // NOTE: Do NOT call approve_for_all on investor1 - this should trigger SpenderNotApprovedForAll
 
-    // Attempt buy should panic when batch transfer checks for approval for all
-    client.buy_tokens(&investor1, &investor2, &tokens_to_buy_from_investor1);
+    // Attempt buy should fail when batch transfer checks for approval for all
+    assert_eq!(
+        client.try_buy_tokens(&investor1, &investor2, &tokens_to_buy_from_investor1),
+        Err(Ok(MinahError::SpenderNotApprovedForAll))
+    );
 }
 
 #[test]
-#[should_panic(expected = "FROM_ADDRESS_NOT_INVESTOR_OR_OWNER")]
 fn test_buy_tokens_from_not_investor_nor_owner() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -432,11 +438,13 @@ fn test_buy_tokens_from_not_investor_nor_owner() {
         token_ids.push_back(i);
     }
 
-    client.buy_tokens(&normal_user, &investor, &token_ids);
+    assert_eq!(
+        client.try_buy_tokens(&normal_user, &investor, &token_ids),
+        Err(Ok(MinahError::NotInvestorOrOwner))
+    );
 }
 
 #[test]
-#[should_panic(expected = "TO_ADDRESS_NOT_INVESTOR_OR_OWNER")]
 fn test_buy_tokens_to_not_investor_nor_owner() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -481,12 +489,14 @@ fn test_buy_tokens_to_not_investor_nor_owner() {
         token_ids.push_back(i);
     }
 
-    // Attempt to buy should panic with TO_ADDRESS_NOT_INVESTOR_OR_OWNER
-    client.buy_tokens(&seller, &non_investor_buyer, &token_ids);
+    // Attempt to buy should fail with NotInvestorOrOwner
+    assert_eq!(
+        client.try_buy_tokens(&seller, &non_investor_buyer, &token_ids),
+        Err(Ok(MinahError::NotInvestorOrOwner))
+    );
 }
 
 #[test]
-#[should_panic(expected = "INSUFFICIENT_BALANCE_TO_BUY_NFTS")]
 fn test_buy_tokens_insufficient_balance() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -515,7 +525,7 @@ fn test_buy_tokens_insufficient_balance() {
     let buyer = Address::generate(&env);
 
     // Create buyer as investor
-    client.create_investor(&buyer);
+    client.create_investor(&owner, &buyer);
 
     // Mint to seller and start chronometer
     mint_nft(
@@ -538,12 +548,14 @@ fn test_buy_tokens_insufficient_balance() {
     // Approve for all for seller so we reach the balance check for buyer
     client.approve_for_all(&seller, &contract_id, &100);
 
-    // Attempt to buy should panic due to insufficient stablecoin balance
-    client.buy_tokens(&seller, &buyer, &token_ids);
+    // Attempt to buy should fail due to insufficient stablecoin balance
+    assert_eq!(
+        client.try_buy_tokens(&seller, &buyer, &token_ids),
+        Err(Ok(MinahError::InsufficientBalance))
+    );
 }
 
 #[test]
-#[should_panic(expected = "INSUFFICIENT_ALLOWANCE_TO_BUY_NFTS")]
 fn test_buy_tokens_insufficient_allowance() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -572,7 +584,7 @@ fn test_buy_tokens_insufficient_allowance() {
     let buyer = Address::generate(&env);
 
     // Create buyer as investor
-    client.create_investor(&buyer);
+    client.create_investor(&owner, &buyer);
 
     // Mint to seller and start chronometer
     mint_nft(
@@ -601,12 +613,14 @@ fn test_buy_tokens_insufficient_allowance() {
     // Seller approves NFTs for transfer
     client.approve_for_all(&seller, &contract_id, &100);
 
-    // Attempt to buy should panic due to insufficient allowance
-    client.buy_tokens(&seller, &buyer, &token_ids);
+    // Attempt to buy should fail due to insufficient allowance
+    assert_eq!(
+        client.try_buy_tokens(&seller, &buyer, &token_ids),
+        Err(Ok(MinahError::InsufficientAllowance))
+    );
 }
 
 #[test]
-#[should_panic(expected = "TO_ADDRESS_NOT_INVESTOR_OR_OWNER")]
 fn test_sell_tokens_to_not_investor_nor_owner() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -660,12 +674,14 @@ fn test_sell_tokens_to_not_investor_nor_owner() {
     // Seller approves NFTs for transfer
     client.approve_for_all(&seller, &contract_id, &100);
 
-    // Attempt to sell should panic due to TO not investor nor owner
-    client.sell_tokens(&seller, &buyer, &token_ids);
+    // Attempt to sell should fail due to TO not investor nor owner
+    assert_eq!(
+        client.try_sell_tokens(&seller, &buyer, &token_ids),
+        Err(Ok(MinahError::NotInvestorOrOwner))
+    );
 }
 
 #[test]
-#[should_panic(expected = "INSUFFICIENT_BALANCE_TO_SELL_NFTS")]
 fn test_sell_tokens_insufficient_balance() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -692,7 +708,7 @@ fn test_sell_tokens_insufficient_balance() {
     let buyer = Address::generate(&env);
 
     // Create buyer as investor
-    client.create_investor(&buyer);
+    client.create_investor(&owner, &buyer);
 
     // Mint to seller and start chronometer
     mint_nft(
@@ -706,7 +722,7 @@ fn test_sell_tokens_insufficient_balance() {
     );
     client.start_chronometer();
 
-    // Not funding buyer with stablecoin should trigger balance panic
+    // Not funding buyer with stablecoin should fail with InsufficientBalance
     let mut token_ids: Vec<u32> = Vec::new(&env);
     for i in 0..5 {
         token_ids.push_back(i);
@@ -715,11 +731,13 @@ fn test_sell_tokens_insufficient_balance() {
     // Seller approves NFTs for transfer
     client.approve_for_all(&seller, &contract_id, &100);
 
-    client.sell_tokens(&seller, &buyer, &token_ids);
+    assert_eq!(
+        client.try_sell_tokens(&seller, &buyer, &token_ids),
+        Err(Ok(MinahError::InsufficientBalance))
+    );
 }
 
 #[test]
-#[should_panic(expected = "INSUFFICIENT_ALLOWANCE_TO_SELL_NFTS")]
 fn test_sell_tokens_insufficient_allowance() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -748,7 +766,7 @@ fn test_sell_tokens_insufficient_allowance() {
     let buyer = Address::generate(&env);
 
     // Create buyer as investor
-    client.create_investor(&buyer);
+    client.create_investor(&owner, &buyer);
 
     // Mint to seller and start chronometer
     mint_nft(
@@ -776,12 +794,14 @@ fn test_sell_tokens_insufficient_allowance() {
     // Seller approves NFTs for transfer
     client.approve_for_all(&seller, &contract_id, &100);
 
-    // Attempt to sell should panic due to insufficient allowance
-    client.sell_tokens(&seller, &buyer, &token_ids);
+    // Attempt to sell should fail due to insufficient allowance
+    assert_eq!(
+        client.try_sell_tokens(&seller, &buyer, &token_ids),
+        Err(Ok(MinahError::InsufficientAllowance))
+    );
 }
 
 #[test]
-#[should_panic(expected = "NFT_TRANSFERS_NOT_ALLOWED_DURING_BUYING_PHASE")]
 fn test_buy_tokens_during_buying_phase() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -819,17 +839,19 @@ fn test_buy_tokens_during_buying_phase() {
     );
 
     // Create buyer as investor
-    client.create_investor(&buyer);
+    client.create_investor(&owner, &buyer);
 
     let mut token_ids: Vec<u32> = Vec::new(&env);
     token_ids.push_back(0);
 
-    // Attempt to buy during buying phase - should panic
-    client.buy_tokens(&seller, &buyer, &token_ids);
+    // Attempt to buy during buying phase - should fail
+    assert_eq!(
+        client.try_buy_tokens(&seller, &buyer, &token_ids),
+        Err(Ok(MinahError::NftTransfersNotAllowedDuringBuyingPhase))
+    );
 }
 
 #[test]
-#[should_panic(expected = "NFT_TRANSFERS_NOT_ALLOWED_DURING_BUYING_PHASE")]
 fn test_sell_tokens_during_buying_phase() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -867,17 +889,19 @@ fn test_sell_tokens_during_buying_phase() {
     );
 
     // Create buyer as investor
-    client.create_investor(&buyer);
+    client.create_investor(&owner, &buyer);
 
     let mut token_ids: Vec<u32> = Vec::new(&env);
     token_ids.push_back(0);
 
-    // Attempt to sell during buying phase - should panic
-    client.sell_tokens(&seller, &buyer, &token_ids);
+    // Attempt to sell during buying phase - should fail
+    assert_eq!(
+        client.try_sell_tokens(&seller, &buyer, &token_ids),
+        Err(Ok(MinahError::NftTransfersNotAllowedDuringBuyingPhase))
+    );
 }
 
 #[test]
-#[should_panic(expected = "FROM_ADDRESS_NOT_INVESTOR_OR_OWNER")]
 fn test_buy_tokens_from_non_investor() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -924,7 +948,7 @@ fn test_buy_tokens_from_non_investor() {
     token_ids.push_back(0);
 
     // Create buyer as investor
-    client.create_investor(&buyer);
+    client.create_investor(&owner, &buyer);
 
     // Fund buyer
     let total_price = PRICE * 10i128.pow(USDC_DECIMALS);
@@ -934,12 +958,14 @@ fn test_buy_tokens_from_non_investor() {
     // Approve NFTs for transfer
     client.approve_for_all(&non_investor, &contract_id, &100);
 
-    // Attempt to buy from non-investor - should panic
-    client.buy_tokens(&non_investor, &buyer, &token_ids);
+    // Attempt to buy from non-investor - should fail
+    assert_eq!(
+        client.try_buy_tokens(&non_investor, &buyer, &token_ids),
+        Err(Ok(MinahError::NotInvestorOrOwner))
+    );
 }
 
 #[test]
-#[should_panic(expected = "TO_ADDRESS_NOT_INVESTOR_OR_OWNER")]
 fn test_buy_tokens_to_non_investor() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -992,8 +1018,11 @@ fn test_buy_tokens_to_non_investor() {
     // Approve NFTs for transfer
     client.approve_for_all(&seller, &contract_id, &100);
 
-    // Attempt to buy to non-investor - should panic
-    client.buy_tokens(&seller, &non_investor, &token_ids);
+    // Attempt to buy to non-investor - should fail
+    assert_eq!(
+        client.try_buy_tokens(&seller, &non_investor, &token_ids),
+        Err(Ok(MinahError::NotInvestorOrOwner))
+    );
 }
 
 #[test]
@@ -6,11 +6,10 @@ use crate::{
         roi_percentages_vec, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY,
         USDC_DECIMALS,
     },
-    InvestmentStatus, STABLECOIN_DECIMALS,
+    InvestmentStatus, MinahError, STABLECOIN_DECIMALS,
 };
 
 #[test]
-#[should_panic(expected = "USER_NOT_AN_INVESTOR")]
 fn test_mint_nft_to_non_investor() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -34,11 +33,13 @@ fn test_mint_nft_to_non_investor() {
 
     let nft_receiver = Address::generate(&env);
 
-    client.mint(&nft_receiver, &40);
+    assert_eq!(
+        client.try_mint(&nft_receiver, &40),
+        Err(Ok(MinahError::UserNotAnInvestor))
+    );
 }
 
 #[test]
-#[should_panic(expected = "INSUFFICIENT_BALANCE")]
 fn test_mint_nft_insufficient_balance() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -62,17 +63,19 @@ fn test_mint_nft_insufficient_balance() {
     let nft_receiver = Address::generate(&env);
 
     // Create An investor before minting
-    client.create_investor(&nft_receiver);
+    client.create_investor(&owner, &nft_receiver);
 
     // CHECK: Is the investor created successfully?
     let is_investor = client.is_investor(&nft_receiver);
     assert!(is_investor);
 
-    client.mint(&nft_receiver, &40);
+    assert_eq!(
+        client.try_mint(&nft_receiver, &40),
+        Err(Ok(MinahError::InsufficientBalance))
+    );
 }
 
 #[test]
-#[should_panic(expected = "INSUFFICIENT_ALLOWANCE")]
 fn test_mint_nft_insufficient_allowance() {
     let env = Env::default();
 
@@ -102,7 +105,7 @@ fn test_mint_nft_insufficient_allowance() {
     let nft_amount: u32 = 40;
 
     // Create An investor before minting
-    client.create_investor(&nft_receiver);
+    client.create_investor(&owner, &nft_receiver);
 
     // CHECK: Is the investor created successfully?
     let is_investor = client.is_investor(&nft_receiver);
@@ -122,7 +125,10 @@ fn test_mint_nft_insufficient_allowance() {
     let nft_receiver_balance = stablecoin_client.balance(&nft_receiver);
     assert_eq!(nft_receiver_balance, total_amount);
 
-    client.mint(&nft_receiver, &nft_amount);
+    assert_eq!(
+        client.try_mint(&nft_receiver, &nft_amount),
+        Err(Ok(MinahError::InsufficientAllowance))
+    );
 }
 
 #[test]
@@ -156,7 +162,7 @@ fn test_mint_nft() {
     let nft_amount: u32 = 40;
 
     // Create An investor before minting
-    client.create_investor(&nft_receiver);
+    client.create_investor(&owner, &nft_receiver);
 
     // CHECK: Is the investor created successfully?
     let is_investor = client.is_investor(&nft_receiver);
@@ -252,7 +258,6 @@ fn test_start_chronometer() {
 }
 
 #[test]
-#[should_panic(expected = "MAXIMUM_NFTS_PER_INVESTOR_EXCEEDED")]
 fn test_exceed_max_nfts_per_investor() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -279,20 +284,15 @@ fn test_exceed_max_nfts_per_investor() {
 
     let nft_amount = MAX_NFTS_PER_INVESTOR + 1;
 
-    // First mint exactly the maximum allowed
-    mint_nft(
-        &env,
-        &client,
-        &investor,
-        nft_amount,
-        &owner,
-        &stablecoin_address,
-        &contract_id,
+    // Mint one more than the maximum allowed
+    client.create_investor(&owner, &investor);
+    assert_eq!(
+        client.try_mint(&investor, &nft_amount),
+        Err(Ok(MinahError::MaximumNftsPerInvestorExceeded))
     );
 }
 
 #[test]
-#[should_panic(expected = "MINIMUM_INVESTMENT_NOT_MET")]
 fn test_minimum_investment_not_met() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -314,15 +314,17 @@ fn test_minimum_investment_not_met() {
     );
 
     let investor = Address::generate(&env);
-    client.create_investor(&investor);
+    client.create_investor(&owner, &investor);
 
     // Try to mint less than minimum required
     let below_min = MIN_NFTS_TO_MINT - 1;
-    client.mint(&investor, &below_min);
+    assert_eq!(
+        client.try_mint(&investor, &below_min),
+        Err(Ok(MinahError::MinimumInvestmentNotMet))
+    );
 }
 
 #[test]
-#[should_panic(expected = "MAXIMUM_SUPPLY_EXCEEDED")]
 fn test_exceed_total_supply() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -362,14 +364,10 @@ fn test_exceed_total_supply() {
 
     // Now try to mint one more NFT which should exceed total supply
     let extra_investor = Address::generate(&env);
-    mint_nft(
-        &env,
-        &client,
-        &extra_investor,
-        MIN_NFTS_TO_MINT,
-        &owner,
-        &stablecoin_address,
-        &contract_id,
+    client.create_investor(&owner, &extra_investor);
+    assert_eq!(
+        client.try_mint(&extra_investor, &MIN_NFTS_TO_MINT),
+        Err(Ok(MinahError::MaximumSupplyExceeded))
     );
 }
 
@@ -495,7 +493,6 @@ fn test_exact_max_nfts_per_investor_boundary() {
 }
 
 #[test]
-#[should_panic(expected = "INVESTMENT_NOT_IN_BUYING_PHASE")]
 fn test_mint_in_wrong_state() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -535,18 +532,14 @@ fn test_mint_in_wrong_state() {
 
     // Verify state has changed
     let state = client.get_current_state();
-    assert_eq!(state, InvestmentStatus::BeforeFirstRelease);
+    assert_eq!(state, InvestmentStatus::Distributing(0));
 
-    // Now try to mint - should panic
+    // Now try to mint
     let new_investor = Address::generate(&env);
-    mint_nft(
-        &env,
-        &client,
-        &new_investor,
-        50,
-        &owner,
-        &stablecoin_address,
-        &contract_id,
+    client.create_investor(&owner, &new_investor);
+    assert_eq!(
+        client.try_mint(&new_investor, &50),
+        Err(Ok(MinahError::InvestmentNotInBuyingPhase))
     );
 }
 
@@ -606,7 +599,6 @@ fn test_start_chronometer_mints_remaining_to_owner() {
 }
 
 #[test]
-#[should_panic(expected = "CHRONOMETER_ALREADY_STARTED")]
 fn test_double_chronometer_start() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -644,8 +636,11 @@ fn test_double_chronometer_start() {
     // Start chronometer
     client.start_chronometer();
 
-    // Try to start again - should panic
-    client.start_chronometer();
+    // Try to start again - should fail
+    assert_eq!(
+        client.try_start_chronometer(),
+        Err(Ok(MinahError::ChronometerAlreadyStarted))
+    );
 }
 
 #[test]
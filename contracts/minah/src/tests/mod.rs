@@ -1,9 +1,28 @@
 #![allow(unused_variables)]
 
 mod basic;
+mod claim;
+mod compliance;
+mod distribution_batch;
+mod dust;
 mod edge_cases;
 mod integration;
+mod inventory;
+mod listing;
 mod marketplace;
 mod mint;
+mod offer;
+mod pause;
+mod pricing;
+mod redemption;
+mod refund;
 mod release;
+mod roles;
+mod sale_window;
+mod snapshot;
+mod storage;
+mod timelock;
+mod token_index;
+mod trading_fee;
+mod upgrade;
 mod utils;
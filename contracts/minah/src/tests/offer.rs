@@ -0,0 +1,132 @@
+use soroban_sdk::{testutils::Ledger, Env, Vec};
+
+use crate::{
+    tests::utils::{setup_trading_offering, token_range, TradingOffering, USDC_DECIMALS},
+    ConfigChange, ConfigParameter, MarketplaceError,
+};
+
+#[test]
+fn test_offer_for_token_ids() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        receiver,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &client.get_stablecoin());
+    let unit_price = 3 * 10i128.pow(USDC_DECIMALS);
+    let buyer_balance = stablecoin_client.balance(&investor2);
+    let receiver_balance = stablecoin_client.balance(&receiver);
+
+    let offer_id = client.make_offer(
+        &investor2,
+        &token_range(&env, 0, 10),
+        &10,
+        &unit_price,
+        &(env.ledger().timestamp() + 1_000),
+    );
+
+    // CHECK: The price is escrowed in the contract
+    assert_eq!(
+        stablecoin_client.balance(&investor2),
+        buyer_balance - 10 * unit_price
+    );
+
+    // CHECK: Only the tokens of the offer can be sold to it
+    assert_eq!(
+        client.try_accept_offer(&investor1, &offer_id, &token_range(&env, 10, 12)),
+        Err(Ok(MarketplaceError::InvalidOffer))
+    );
+
+    // --- investor1 sells part of the tokens, then the rest ---
+    client.accept_offer(&investor1, &offer_id, &token_range(&env, 0, 4));
+
+    let offer = client.get_offer(&offer_id).unwrap();
+    assert_eq!(offer.quantity, 6);
+    assert_eq!(offer.token_ids, token_range(&env, 4, 10));
+
+    client.accept_offer(&investor1, &offer_id, &token_range(&env, 4, 10));
+
+    assert_eq!(client.get_offer(&offer_id), None);
+    assert_eq!(client.balance(&investor1), 90);
+    assert_eq!(client.balance(&investor2), 60);
+    assert_eq!(stablecoin_client.balance(&investor1), 10 * unit_price);
+    assert_eq!(stablecoin_client.balance(&receiver), receiver_balance);
+
+    assert_eq!(
+        client.try_reclaim_offer(&offer_id),
+        Err(Ok(MarketplaceError::OfferNotFound))
+    );
+}
+
+#[test]
+fn test_quantity_offer_with_fee_expires_and_reclaims() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        receiver,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &client.get_stablecoin());
+
+    let executable_at = client.propose_change(&ConfigChange::TradingFee(100));
+    env.ledger().set_timestamp(executable_at);
+    client.execute_change(&ConfigParameter::TradingFee);
+
+    let unit_price = 10i128.pow(USDC_DECIMALS);
+    let fee = 20 * unit_price / 100;
+    let buyer_balance = stablecoin_client.balance(&investor2);
+    let receiver_balance = stablecoin_client.balance(&receiver);
+    let expiry = env.ledger().timestamp() + 1_000;
+
+    // CHECK: A quantity offer does not name its tokens
+    assert_eq!(
+        client.try_make_offer(
+            &investor2,
+            &token_range(&env, 0, 5),
+            &20,
+            &unit_price,
+            &expiry
+        ),
+        Err(Ok(MarketplaceError::InvalidQuantity))
+    );
+
+    let offer_id = client.make_offer(&investor2, &Vec::new(&env), &20, &unit_price, &expiry);
+    assert_eq!(
+        stablecoin_client.balance(&investor2),
+        buyer_balance - 20 * unit_price - fee
+    );
+
+    // --- Any 5 tokens fill a quarter of the offer ---
+    client.accept_offer(&investor1, &offer_id, &token_range(&env, 50, 55));
+    assert_eq!(stablecoin_client.balance(&investor1), 5 * unit_price);
+    assert_eq!(
+        stablecoin_client.balance(&receiver),
+        receiver_balance + fee / 4
+    );
+    assert_eq!(client.balance(&investor2), 55);
+
+    // CHECK: The offer cannot be accepted once expired
+    env.ledger().set_timestamp(expiry);
+    assert_eq!(
+        client.try_accept_offer(&investor1, &offer_id, &token_range(&env, 55, 60)),
+        Err(Ok(MarketplaceError::OfferExpired))
+    );
+
+    // --- The rest of the escrow goes back to the buyer ---
+    assert_eq!(
+        client.reclaim_offer(&offer_id),
+        15 * unit_price + fee * 3 / 4
+    );
+    assert_eq!(client.get_offer(&offer_id), None);
+    assert_eq!(
+        stablecoin_client.balance(&investor2),
+        buyer_balance - 5 * unit_price - fee / 4
+    );
+}
@@ -0,0 +1,165 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Vec,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, setup_trading_offering, TradingOffering, DISTRIBUTION_INTERVALS,
+        MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY, USDC_DECIMALS,
+    },
+    MinahError, PausableFunction,
+};
+
+#[test]
+fn test_pause_blocks_mint() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    client.pause(&owner);
+
+    assert!(client.paused());
+    assert!(client.is_function_paused(&PausableFunction::Mint));
+    assert_eq!(
+        client.try_mint(&investor, &MIN_NFTS_TO_MINT),
+        Err(Ok(MinahError::FunctionPaused))
+    );
+
+    // CHECK: Getters keep working while paused
+    assert_eq!(client.get_current_supply(), 100);
+    assert_eq!(client.balance(&investor), 100);
+
+    client.unpause(&owner);
+
+    let investor2 = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor2,
+        50,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+    assert_eq!(client.get_current_supply(), 150);
+}
+
+#[test]
+fn test_pause_blocks_marketplace_and_releases() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+
+    let mut token_ids: Vec<u32> = Vec::new(&env);
+    for i in 0..10 {
+        token_ids.push_back(i);
+    }
+
+    client.pause(&owner);
+
+    assert_eq!(
+        client.try_buy_tokens(&investor1, &investor2, &token_ids),
+        Err(Ok(MinahError::FunctionPaused))
+    );
+    assert_eq!(
+        client.try_sell_tokens(&investor1, &investor2, &token_ids),
+        Err(Ok(MinahError::FunctionPaused))
+    );
+    assert_eq!(
+        client.try_release_distribution(&owner),
+        Err(Ok(MinahError::FunctionPaused))
+    );
+    assert_eq!(
+        client.try_release_distribution_batch(&owner, &10),
+        Err(Ok(MinahError::FunctionPaused))
+    );
+
+    // CHECK: Unpausing a single function does not lift the contract pause
+    client.unpause_function(&owner, &PausableFunction::ReleaseDistribution);
+    assert!(client.is_function_paused(&PausableFunction::ReleaseDistribution));
+
+    client.unpause(&owner);
+
+    client.release_distribution(&owner);
+    client.buy_tokens(&investor1, &investor2, &token_ids);
+    assert_eq!(client.balance(&investor2), 60);
+}
+
+#[test]
+fn test_pause_single_function() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let mut token_ids: Vec<u32> = Vec::new(&env);
+    for i in 0..10 {
+        token_ids.push_back(i);
+    }
+
+    client.pause_function(&owner, &PausableFunction::BuyTokens);
+
+    assert!(!client.paused());
+    assert!(client.is_function_paused(&PausableFunction::BuyTokens));
+    assert!(!client.is_function_paused(&PausableFunction::SellTokens));
+
+    assert_eq!(
+        client.try_buy_tokens(&investor1, &investor2, &token_ids),
+        Err(Ok(MinahError::FunctionPaused))
+    );
+
+    // CHECK: Selling is still allowed
+    client.sell_tokens(&investor1, &investor2, &token_ids);
+    assert_eq!(client.balance(&investor2), 60);
+
+    client.unpause_function(&owner, &PausableFunction::BuyTokens);
+
+    let mut more_token_ids: Vec<u32> = Vec::new(&env);
+    for i in 10..20 {
+        more_token_ids.push_back(i);
+    }
+    client.buy_tokens(&investor1, &investor2, &more_token_ids);
+    assert_eq!(client.balance(&investor2), 70);
+}
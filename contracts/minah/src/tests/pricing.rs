@@ -0,0 +1,104 @@
+use soroban_sdk::{testutils::Ledger, Env};
+
+use crate::{
+    tests::utils::{
+        setup_trading_offering, token_range, TradingOffering, DISTRIBUTION_INTERVALS, PRICE,
+        ROI_PERCENTAGES, USDC_DECIMALS,
+    },
+    ConfigChange, ConfigParameter,
+};
+
+#[test]
+fn test_quote_price_accrues_current_stage() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let principal = PRICE * 10i128.pow(USDC_DECIMALS);
+    let begin_date = client.get_begin_date();
+    let stage_0_roi = (ROI_PERCENTAGES[0] * PRICE) / 100;
+    let stage_1_roi = (ROI_PERCENTAGES[1] * PRICE) / 100;
+
+    // CHECK: Nothing is accrued at the start
+    assert_eq!(client.quote_price(&10), 10 * principal);
+
+    // CHECK: Half way through the first stage half its ROI is accrued
+    env.ledger()
+        .set_timestamp(begin_date + DISTRIBUTION_INTERVALS[0] / 2);
+    assert_eq!(client.quote_price(&1), principal + stage_0_roi / 2);
+
+    // CHECK: Once the first stage is due, the next one accrues from its due date, released or not
+    let elapsed_in_stage = 10;
+    let stage_1_length = DISTRIBUTION_INTERVALS[1] - DISTRIBUTION_INTERVALS[0];
+    let stage_1_quote = principal + stage_1_roi * elapsed_in_stage / stage_1_length as i128;
+
+    env.ledger()
+        .set_timestamp(begin_date + DISTRIBUTION_INTERVALS[0] + elapsed_in_stage as u64);
+    assert_eq!(client.quote_price(&1), stage_1_quote);
+
+    client.release_distribution(&owner);
+    assert_eq!(client.quote_price(&1), stage_1_quote);
+
+    // CHECK: Nothing is accrued once every stage is due
+    env.ledger()
+        .set_timestamp(begin_date + DISTRIBUTION_INTERVALS[DISTRIBUTION_INTERVALS.len() - 1]);
+    assert_eq!(client.quote_price(&1), principal);
+}
+
+#[test]
+fn test_accrued_roi_pricing_on_trades() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        owner,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, false);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &client.get_stablecoin());
+    assert!(!client.is_accrued_roi_pricing());
+
+    let executable_at = client.propose_change(&ConfigChange::AccruedRoiPricing(true));
+    env.ledger().set_timestamp(executable_at);
+    client.execute_change(&ConfigParameter::AccruedRoiPricing);
+    assert!(client.is_accrued_roi_pricing());
+
+    client.start_chronometer();
+
+    // The first stage is due but not released, a third of the second one has accrued
+    let stage_1_length = DISTRIBUTION_INTERVALS[1] - DISTRIBUTION_INTERVALS[0];
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0] + stage_1_length / 3);
+
+    let quote = client.quote_price(&10);
+    let stage_1_roi = (ROI_PERCENTAGES[1] * PRICE) / 100;
+    assert_eq!(
+        quote,
+        10 * (PRICE * 10i128.pow(USDC_DECIMALS) + stage_1_roi / 3)
+    );
+
+    let seller_balance = stablecoin_client.balance(&investor1);
+    client.buy_tokens(&investor1, &investor2, &token_range(&env, 0, 10));
+
+    // CHECK: The seller is paid the quoted price
+    assert_eq!(
+        stablecoin_client.balance(&investor1),
+        seller_balance + quote
+    );
+
+    // CHECK: The due stage is still paid to the seller for the NFTs it sold
+    client.release_distribution(&owner);
+
+    let amount_per_nft = client.get_stage_amount_per_nft(&0);
+    assert_eq!(
+        client.see_claimable_amount(&investor1),
+        100 * amount_per_nft
+    );
+    assert_eq!(client.see_claimable_amount(&investor2), 50 * amount_per_nft);
+}
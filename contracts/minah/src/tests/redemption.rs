@@ -0,0 +1,237 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, mint_nft, token_range, MAX_NFTS_PER_INVESTOR,
+        MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY, USDC_DECIMALS,
+    },
+    InvestmentStatus, MinahClient, MinahError,
+};
+
+const INTERVAL: u64 = 60;
+const ROI_PERCENTAGE: i128 = 40_000_000;
+
+/// Runs a single stage offering with two investors (100 and 50 NFTs) until it ends.
+fn setup_ended_offering<'a>(
+    env: &Env,
+) -> (MinahClient<'a>, Address, Address, Address, Address, Address) {
+    let owner = Address::generate(env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(env);
+    let payer = Address::generate(env);
+
+    let (client, contract_id) = create_client(
+        env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        vec![env, INTERVAL],
+        vec![env, ROI_PERCENTAGE],
+    );
+
+    let investor1 = Address::generate(env);
+    let investor2 = Address::generate(env);
+
+    mint_nft(
+        env,
+        &client,
+        &investor1,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    mint_nft(
+        env,
+        &client,
+        &investor2,
+        50,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    client.start_chronometer();
+
+    // Fund the payer for the ROI and the principal
+    let stablecoin_client = stablecoin::StablecoinClient::new(env, &stablecoin_address);
+    let payer_funding = 1_000_000 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &payer, &payer_funding);
+    stablecoin_client.approve(&payer, &contract_id, &payer_funding, &1000);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + INTERVAL);
+    client.release_distribution(&owner);
+
+    assert_eq!(client.get_current_state(), InvestmentStatus::Ended);
+
+    (
+        client,
+        contract_id,
+        stablecoin_address,
+        payer,
+        investor1,
+        investor2,
+    )
+}
+
+#[test]
+fn test_fund_redemption_and_redeem() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, payer, investor1, investor2) =
+        setup_ended_offering(&env);
+    let owner = client.get_owner().unwrap();
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let scale = 10i128.pow(USDC_DECIMALS);
+
+    let payer_balance_before = stablecoin_client.balance(&payer);
+    // The contract already holds the unclaimed ROI of the released stage
+    let contract_balance_before = stablecoin_client.balance(&contract_id);
+
+    client.fund_redemption(&owner);
+
+    // CHECK: Only the 150 NFTs held by investors are funded, not the owner inventory
+    let funded_amount = 150 * PRICE * scale;
+    assert!(client.is_redemption_funded());
+    assert_eq!(
+        stablecoin_client.balance(&contract_id),
+        contract_balance_before + funded_amount
+    );
+    assert_eq!(
+        stablecoin_client.balance(&payer),
+        payer_balance_before - funded_amount
+    );
+
+    let investor1_balance_before = stablecoin_client.balance(&investor1);
+    let claimed_before = client.see_claimed_amount(&investor1);
+
+    // --- Investor1 redeems part of their NFTs ---
+    client.redeem(&investor1, &token_range(&env, 0, 40));
+
+    assert_eq!(client.balance(&investor1), 60);
+    assert_eq!(client.see_redeemed_amount(&investor1), 40 * PRICE * scale);
+    assert_eq!(
+        stablecoin_client.balance(&investor1),
+        investor1_balance_before + 40 * PRICE * scale
+    );
+
+    // CHECK: ROI claimed amount is left untouched
+    assert_eq!(client.see_claimed_amount(&investor1), claimed_before);
+
+    // --- Investor1 redeems the rest and investor2 everything ---
+    client.redeem(&investor1, &token_range(&env, 40, 100));
+    client.redeem(&investor2, &token_range(&env, 100, 150));
+
+    assert_eq!(client.balance(&investor1), 0);
+    assert_eq!(client.balance(&investor2), 0);
+    assert_eq!(client.see_redeemed_amount(&investor1), 100 * PRICE * scale);
+    assert_eq!(client.see_redeemed_amount(&investor2), 50 * PRICE * scale);
+    assert_eq!(client.get_redeemed_supply(), 150);
+    assert_eq!(client.get_current_supply(), TOTAL_SUPPLY - 150);
+
+    // CHECK: The whole funded principal has been paid out
+    assert_eq!(
+        stablecoin_client.balance(&contract_id),
+        contract_balance_before
+    );
+}
+
+#[test]
+fn test_fund_redemption_before_end() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        vec![&env, INTERVAL],
+        vec![&env, ROI_PERCENTAGE],
+    );
+
+    client.start_chronometer();
+
+    assert_eq!(
+        client.try_fund_redemption(&owner),
+        Err(Ok(MinahError::InvestmentNotEnded))
+    );
+}
+
+#[test]
+fn test_redeem_before_funding() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, payer, investor1, investor2) =
+        setup_ended_offering(&env);
+
+    assert_eq!(
+        client.try_redeem(&investor1, &token_range(&env, 0, 100)),
+        Err(Ok(MinahError::RedemptionNotFunded))
+    );
+}
+
+#[test]
+fn test_owner_cannot_redeem_inventory() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, payer, investor1, investor2) =
+        setup_ended_offering(&env);
+    let owner = client.get_owner().unwrap();
+
+    client.fund_redemption(&owner);
+
+    assert_eq!(
+        client.try_redeem(&owner, &token_range(&env, 150, 160)),
+        Err(Ok(MinahError::OwnerCannotRedeem))
+    );
+}
+
+#[test]
+fn test_fund_redemption_twice() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, payer, investor1, investor2) =
+        setup_ended_offering(&env);
+    let owner = client.get_owner().unwrap();
+
+    client.fund_redemption(&owner);
+    assert_eq!(
+        client.try_fund_redemption(&owner),
+        Err(Ok(MinahError::RedemptionAlreadyFunded))
+    );
+}
+
+#[test]
+fn test_trading_closed_once_redemption_funded() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, payer, investor1, investor2) =
+        setup_ended_offering(&env);
+    let owner = client.get_owner().unwrap();
+
+    client.fund_redemption(&owner);
+
+    client.approve_for_all(&investor1, &contract_id, &1000);
+
+    assert_eq!(
+        client.try_sell_tokens(&investor1, &investor2, &token_range(&env, 0, 10)),
+        Err(Ok(MinahError::TradingClosedForRedemption))
+    );
+}
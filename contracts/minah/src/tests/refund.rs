@@ -0,0 +1,480 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Vec,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY,
+        USDC_DECIMALS,
+    },
+    ConfigChange, ConfigError, ConfigParameter, InvestmentStatus, MinahError,
+};
+
+const SOFT_CAP: u32 = 200;
+const REFUND_DEADLINE: u64 = 1_000;
+
+#[test]
+fn test_mint_proceeds_escrowed_until_soft_cap() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_soft_cap(&SOFT_CAP, &REFUND_DEADLINE);
+
+    assert_eq!(client.get_soft_cap(), SOFT_CAP);
+    assert_eq!(client.get_refund_deadline(), REFUND_DEADLINE);
+    assert!(!client.is_soft_cap_reached());
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let scale = 10i128.pow(USDC_DECIMALS);
+
+    // --- First investor does not reach the soft cap ---
+    let investor1 = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor1,
+        150,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    // CHECK: proceeds stay in the contract
+    assert_eq!(stablecoin_client.balance(&receiver), 0);
+    assert_eq!(stablecoin_client.balance(&contract_id), 150 * PRICE * scale);
+    assert_eq!(client.get_escrowed_amount(), 150 * PRICE * scale);
+    assert_eq!(client.get_paid_amount(&investor1), 150 * PRICE * scale);
+
+    // --- Second investor crosses the soft cap ---
+    let investor2 = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor2,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    // CHECK: all proceeds are released to the receiver
+    assert!(client.is_soft_cap_reached());
+    assert_eq!(client.get_escrowed_amount(), 0);
+    assert_eq!(stablecoin_client.balance(&contract_id), 0);
+    assert_eq!(stablecoin_client.balance(&receiver), 250 * PRICE * scale);
+
+    // --- Mints after the soft cap go straight to the receiver ---
+    let investor3 = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor3,
+        50,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    assert_eq!(stablecoin_client.balance(&contract_id), 0);
+    assert_eq!(stablecoin_client.balance(&receiver), 300 * PRICE * scale);
+
+    // The chronometer can be started once the soft cap is reached
+    client.start_chronometer();
+    assert_eq!(
+        client.get_current_state(),
+        InvestmentStatus::Distributing(0)
+    );
+}
+
+#[test]
+fn test_cancel_offering_and_refund() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_soft_cap(&SOFT_CAP, &REFUND_DEADLINE);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let scale = 10i128.pow(USDC_DECIMALS);
+
+    let investor1 = Address::generate(&env);
+    let investor2 = Address::generate(&env);
+
+    mint_nft(
+        &env,
+        &client,
+        &investor1,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    mint_nft(
+        &env,
+        &client,
+        &investor2,
+        50,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    // Pass the refund deadline without reaching the soft cap
+    env.ledger().set_timestamp(REFUND_DEADLINE);
+
+    client.cancel_offering();
+
+    assert_eq!(client.get_current_state(), InvestmentStatus::Cancelled);
+
+    // --- Refund investor1 (token ids 0..100) ---
+    let mut investor1_tokens: Vec<u32> = Vec::new(&env);
+    for i in 0..100 {
+        investor1_tokens.push_back(i);
+    }
+
+    client.refund(&investor1, &investor1_tokens);
+
+    assert_eq!(stablecoin_client.balance(&investor1), 100 * PRICE * scale);
+    assert_eq!(client.balance(&investor1), 0);
+    assert_eq!(client.get_paid_amount(&investor1), 0);
+    assert_eq!(client.get_current_supply(), 50);
+    assert_eq!(client.get_escrowed_amount(), 50 * PRICE * scale);
+
+    // --- Refund investor2 (token ids 100..150) ---
+    let mut investor2_tokens: Vec<u32> = Vec::new(&env);
+    for i in 100..150 {
+        investor2_tokens.push_back(i);
+    }
+
+    client.refund(&investor2, &investor2_tokens);
+
+    assert_eq!(stablecoin_client.balance(&investor2), 50 * PRICE * scale);
+    assert_eq!(client.balance(&investor2), 0);
+    assert_eq!(client.get_current_supply(), 0);
+    assert_eq!(client.get_escrowed_amount(), 0);
+
+    // CHECK: nothing was ever sent to the receiver
+    assert_eq!(stablecoin_client.balance(&receiver), 0);
+    assert_eq!(stablecoin_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_cancel_offering_before_deadline() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_soft_cap(&SOFT_CAP, &REFUND_DEADLINE);
+
+    env.ledger().set_timestamp(REFUND_DEADLINE - 1);
+
+    assert_eq!(
+        client.try_cancel_offering(),
+        Err(Ok(MinahError::RefundDeadlineNotReached))
+    );
+}
+
+#[test]
+fn test_cancel_offering_after_soft_cap_reached() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_soft_cap(&SOFT_CAP, &REFUND_DEADLINE);
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        SOFT_CAP,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    env.ledger().set_timestamp(REFUND_DEADLINE);
+
+    assert_eq!(
+        client.try_cancel_offering(),
+        Err(Ok(MinahError::SoftCapAlreadyReached))
+    );
+}
+
+#[test]
+fn test_start_chronometer_before_soft_cap() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_soft_cap(&SOFT_CAP, &REFUND_DEADLINE);
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    assert_eq!(
+        client.try_start_chronometer(),
+        Err(Ok(MinahError::SoftCapNotReached))
+    );
+}
+
+#[test]
+fn test_partial_refund() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_soft_cap(&SOFT_CAP, &REFUND_DEADLINE);
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    env.ledger().set_timestamp(REFUND_DEADLINE);
+    client.cancel_offering();
+
+    // Only half of the investor tokens
+    let mut token_ids: Vec<u32> = Vec::new(&env);
+    for i in 0..50 {
+        token_ids.push_back(i);
+    }
+
+    assert_eq!(
+        client.try_refund(&investor, &token_ids),
+        Err(Ok(MinahError::RefundMustBurnAllNfts))
+    );
+}
+
+#[test]
+fn test_set_soft_cap_after_first_mint() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    assert_eq!(
+        client.try_set_soft_cap(&SOFT_CAP, &REFUND_DEADLINE),
+        Err(Ok(MinahError::ConfigLockedAfterFirstMint))
+    );
+}
+
+#[test]
+fn test_stablecoin_change_rejected_while_escrowed() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let other_stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_soft_cap(&SOFT_CAP, &REFUND_DEADLINE);
+
+    // A stablecoin change proposed before the sale
+    let executable_at =
+        client.propose_change(&ConfigChange::StableCoin(other_stablecoin_address.clone()));
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    let scale = 10i128.pow(USDC_DECIMALS);
+    assert_eq!(client.get_escrowed_amount(), 100 * PRICE * scale);
+
+    // CHECK: The escrowed proceeds pin the stablecoin
+    env.ledger().set_timestamp(executable_at);
+    assert_eq!(
+        client.try_execute_change(&ConfigParameter::StableCoin),
+        Err(Ok(ConfigError::ConfigLockedAfterFirstMint))
+    );
+    assert_eq!(
+        client.try_propose_change(&ConfigChange::StableCoin(other_stablecoin_address)),
+        Err(Ok(ConfigError::ConfigLockedAfterFirstMint))
+    );
+    assert_eq!(client.get_stablecoin(), stablecoin_address);
+
+    // CHECK: The refund is paid in the stablecoin the investor paid with
+    env.ledger()
+        .set_timestamp(REFUND_DEADLINE.max(executable_at));
+    client.cancel_offering();
+
+    let mut token_ids: Vec<u32> = Vec::new(&env);
+    for i in 0..100 {
+        token_ids.push_back(i);
+    }
+    client.refund(&investor, &token_ids);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    assert_eq!(stablecoin_client.balance(&investor), 100 * PRICE * scale);
+    assert_eq!(client.get_escrowed_amount(), 0);
+}
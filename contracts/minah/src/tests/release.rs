@@ -1,7 +1,7 @@
 use soroban_sdk::{
     log,
     testutils::{Address as _, Ledger},
-    Address, Env,
+    vec, Address, Env,
 };
 
 use crate::{
@@ -10,7 +10,7 @@ use crate::{
         roi_percentages_vec, DISTRIBUTION_INTERVALS, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT,
         PRICE, ROI_PERCENTAGES, TOTAL_SUPPLY, USDC_DECIMALS,
     },
-    InvestmentStatus,
+    InvestmentStatus, MinahError,
 };
 
 #[test]
@@ -103,7 +103,6 @@ fn test_calculate_amount_to_release() {
 }
 
 #[test]
-#[should_panic(expected = "DISTRIBUTION_NOT_READY_YET")]
 fn test_release_distribution_before_time_pass() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -158,8 +157,11 @@ fn test_release_distribution_before_time_pass() {
     let current_time = env.ledger().timestamp();
     assert_eq!(start_time, current_time);
 
-    // --- Attempt to release distribution immediately (should panic) ---
-    client.release_distribution();
+    // --- Attempt to release distribution immediately (should fail) ---
+    assert_eq!(
+        client.try_release_distribution(&owner),
+        Err(Ok(MinahError::DistributionNotReadyYet))
+    );
 }
 
 #[test]
@@ -237,9 +239,9 @@ fn test_release_distribution() {
 
     stablecoin_client.transfer(&owner, &payer, &max_roi_amount);
 
-    // Initial check on claimed amount
-    let initial_claimed = client.see_claimed_amount(&investor1);
-    assert_eq!(initial_claimed, 0);
+    // Initial check on claimable amount
+    let initial_claimable = client.see_claimable_amount(&investor1);
+    assert_eq!(initial_claimable, 0);
 
     // --- Simulate Time Passage and Distribution ---
     let timestamp = env.ledger().timestamp();
@@ -279,15 +281,15 @@ fn test_release_distribution() {
     stablecoin_client.approve(&payer, &contract_id, &amount_to_release_0, &100);
 
     // Release distribution for the first interval
-    client.release_distribution();
+    client.release_distribution(&owner);
 
-    // CHECK: claimed amount should be updated correctly
-    let claimed_after_first = client.see_claimed_amount(&investor1);
-    assert_eq!(claimed_after_first, amount_to_release_0);
+    // CHECK: claimable amount should be updated correctly
+    let claimable_after_first = client.see_claimable_amount(&investor1);
+    assert_eq!(claimable_after_first, amount_to_release_0);
 
     // CHECK: state should be updated correctly
     let state_after_first = client.get_current_state();
-    assert_eq!(state_after_first, InvestmentStatus::Release1);
+    assert_eq!(state_after_first, InvestmentStatus::Distributing(1));
 
     // Pass to the next intervals similarly...
     let new_timestamp = timestamp + DISTRIBUTION_INTERVALS[1]; // Ten months in seconds
@@ -310,33 +312,33 @@ fn test_release_distribution() {
     stablecoin_client.approve(&payer, &contract_id, &amount_to_release_1, &100);
 
     // Release distribution for the second interval
-    client.release_distribution();
+    client.release_distribution(&owner);
 
-    // CHECK: claimed amount should be updated correctly
-    let claimed_after_second = client.see_claimed_amount(&investor1);
+    // CHECK: claimable amount should be updated correctly
+    let claimable_after_second = client.see_claimable_amount(&investor1);
     assert_eq!(
-        claimed_after_second,
+        claimable_after_second,
         amount_to_release_0 + amount_to_release_1
     );
 
     // CHECK: state should be updated correctly
     let state_after_second = client.get_current_state();
-    assert_eq!(state_after_second, InvestmentStatus::Release2);
+    assert_eq!(state_after_second, InvestmentStatus::Distributing(2));
 
     // PASS the rest of the intervals by passing the timestamp to the end directly
     let final_timestamp = timestamp + DISTRIBUTION_INTERVALS.last().unwrap();
     env.ledger().set_timestamp(final_timestamp);
 
-    let approve_amount: i128 = max_roi_amount - (claimed_after_second); // Approve the remaining amount
+    let approve_amount: i128 = max_roi_amount - (claimable_after_second); // Approve the remaining amount
 
     stablecoin_client.approve(&payer, &contract_id, &approve_amount, &100);
 
     // Release distribution for the final interval
-    client.release_distribution();
+    client.release_distribution(&owner);
 
-    // CHECK: claimed amount should be updated correctly
-    let claimed_after_final = client.see_claimed_amount(&investor1);
-    assert_eq!(claimed_after_final, max_roi_amount);
+    // CHECK: claimable amount should be updated correctly
+    let claimable_after_final = client.see_claimable_amount(&investor1);
+    assert_eq!(claimable_after_final, max_roi_amount);
 
     // CHECK: state should be updated correctly
     let state_after_final = client.get_current_state();
@@ -344,7 +346,6 @@ fn test_release_distribution() {
 }
 
 #[test]
-#[should_panic(expected = "COUNDOWN_NOT_STARTED")]
 fn test_release_distribution_without_chronometer() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -366,8 +367,11 @@ fn test_release_distribution_without_chronometer() {
         roi_percentages_vec(&env),
     );
 
-    // Try to release distribution without starting chronometer - should panic
-    client.release_distribution();
+    // Try to release distribution without starting chronometer - should fail
+    assert_eq!(
+        client.try_release_distribution(&owner),
+        Err(Ok(MinahError::CountdownNotStarted))
+    );
 }
 
 #[test]
@@ -409,10 +413,10 @@ fn test_state_progression_through_distributions() {
     client.start_chronometer();
     let start_time = env.ledger().timestamp();
 
-    // State should be BeforeFirstRelease
+    // State should be waiting for the first release
     assert_eq!(
         client.get_current_state(),
-        InvestmentStatus::BeforeFirstRelease
+        InvestmentStatus::Distributing(0)
     );
 
     // Setup payer with sufficient funds for all distributions
@@ -424,16 +428,16 @@ fn test_state_progression_through_distributions() {
 
     // Test progression through each state
     let expected_states = [
-        InvestmentStatus::Release1,
-        InvestmentStatus::Release2,
-        InvestmentStatus::Release3,
-        InvestmentStatus::Release4,
-        InvestmentStatus::Release5,
-        InvestmentStatus::Release6,
-        InvestmentStatus::Release7,
-        InvestmentStatus::Release8,
-        InvestmentStatus::Release9,
-        InvestmentStatus::Release10,
+        InvestmentStatus::Distributing(1),
+        InvestmentStatus::Distributing(2),
+        InvestmentStatus::Distributing(3),
+        InvestmentStatus::Distributing(4),
+        InvestmentStatus::Distributing(5),
+        InvestmentStatus::Distributing(6),
+        InvestmentStatus::Distributing(7),
+        InvestmentStatus::Distributing(8),
+        InvestmentStatus::Distributing(9),
+        InvestmentStatus::Ended,
     ];
 
     for (i, expected_state) in expected_states.iter().enumerate() {
@@ -442,7 +446,7 @@ fn test_state_progression_through_distributions() {
         env.ledger().set_timestamp(new_timestamp);
 
         // Release distribution
-        client.release_distribution();
+        client.release_distribution(&owner);
 
         // Check state has progressed correctly
         let current_state = client.get_current_state();
@@ -545,19 +549,19 @@ fn test_multiple_distributions_across_all_stages() {
         stablecoin_client.approve(&payer, &contract_id, &amount_to_release, &100);
 
         // Release distribution
-        client.release_distribution();
+        client.release_distribution(&owner);
 
         // Verify state progression
         let expected_state = match stage {
-            0 => crate::InvestmentStatus::Release1,
-            1 => crate::InvestmentStatus::Release2,
-            2 => crate::InvestmentStatus::Release3,
-            3 => crate::InvestmentStatus::Release4,
-            4 => crate::InvestmentStatus::Release5,
-            5 => crate::InvestmentStatus::Release6,
-            6 => crate::InvestmentStatus::Release7,
-            7 => crate::InvestmentStatus::Release8,
-            8 => crate::InvestmentStatus::Release9,
+            0 => crate::InvestmentStatus::Distributing(1),
+            1 => crate::InvestmentStatus::Distributing(2),
+            2 => crate::InvestmentStatus::Distributing(3),
+            3 => crate::InvestmentStatus::Distributing(4),
+            4 => crate::InvestmentStatus::Distributing(5),
+            5 => crate::InvestmentStatus::Distributing(6),
+            6 => crate::InvestmentStatus::Distributing(7),
+            7 => crate::InvestmentStatus::Distributing(8),
+            8 => crate::InvestmentStatus::Distributing(9),
             9 => crate::InvestmentStatus::Ended,
             _ => panic!("Invalid stage"),
         };
@@ -650,7 +654,7 @@ fn test_distribution_with_multiple_investors_different_balances() {
     stablecoin_client.approve(&payer, &contract_id, &amount_to_release, &100);
 
     // Release first distribution
-    client.release_distribution();
+    client.release_distribution(&owner);
 
     // Calculate expected amounts for each investor
     let price = client.get_nft_price();
@@ -658,14 +662,13 @@ fn test_distribution_with_multiple_investors_different_balances() {
     let expected_investor2 = (nft_amount_2 as i128 * price * percent_0) / 100;
     let expected_investor3 = (nft_amount_3 as i128 * price * percent_0) / 100;
 
-    // Verify claimed amounts
-    assert_eq!(client.see_claimed_amount(&investor1), expected_investor1);
-    assert_eq!(client.see_claimed_amount(&investor2), expected_investor2);
-    assert_eq!(client.see_claimed_amount(&investor3), expected_investor3);
+    // Verify claimable amounts
+    assert_eq!(client.see_claimable_amount(&investor1), expected_investor1);
+    assert_eq!(client.see_claimable_amount(&investor2), expected_investor2);
+    assert_eq!(client.see_claimable_amount(&investor3), expected_investor3);
 }
 
 #[test]
-#[should_panic(expected = "DISTRIBUTION_NOT_READY_YET")]
 fn test_cannot_release_same_stage_twice() {
     let env = Env::default();
     let owner = Address::generate(&env);
@@ -717,10 +720,13 @@ fn test_cannot_release_same_stage_twice() {
     stablecoin_client.approve(&payer, &contract_id, &double_amount, &100);
 
     // Release first distribution
-    client.release_distribution();
+    client.release_distribution(&owner);
 
-    // Try to release again without advancing time - should panic
-    client.release_distribution();
+    // Try to release again without advancing time - should fail
+    assert_eq!(
+        client.try_release_distribution(&owner),
+        Err(Ok(MinahError::DistributionNotReadyYet))
+    );
 }
 
 #[test]
@@ -772,24 +778,156 @@ fn test_skip_multiple_stages_at_once() {
 
     // Calculate total amount for stages 0, 1, and 2
     let mut total_amount = 0i128;
-    for i in 0..3 {
-        let percent = ROI_PERCENTAGES[i];
-        total_amount += client.calculate_amount_to_release(&percent);
+    for percent in ROI_PERCENTAGES.iter().take(3) {
+        total_amount += client.calculate_amount_to_release(percent);
     }
 
     stablecoin_client.approve(&payer, &contract_id, &total_amount, &100);
 
     // Release should process all 3 stages at once
-    client.release_distribution();
+    client.release_distribution(&owner);
 
-    // Verify we're now at Release3 state
-    assert_eq!(client.get_current_state(), crate::InvestmentStatus::Release3);
+    // Verify we're now waiting for the 4th stage (index 3)
+    assert_eq!(client.get_current_state(), crate::InvestmentStatus::Distributing(3));
 
-    // Verify claimed amount is sum of all 3 stages
+    // Verify claimable amount is sum of all 3 stages
     let price = client.get_nft_price();
-    let mut expected_claimed = 0i128;
-    for i in 0..3 {
-        expected_claimed += (nft_amount as i128 * price * ROI_PERCENTAGES[i]) / 100;
+    let mut expected_claimable = 0i128;
+    for percent in ROI_PERCENTAGES.iter().take(3) {
+        expected_claimable += (nft_amount as i128 * price * percent) / 100;
+    }
+    assert_eq!(client.see_claimable_amount(&investor), expected_claimable);
+}
+
+#[test]
+fn test_release_distribution_with_quarterly_schedule() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address = deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(6));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    // 4 quarterly payouts of 2% each
+    let quarter: u64 = 7_884_000;
+    let intervals = vec![&env, quarter, 2 * quarter, 3 * quarter, 4 * quarter];
+    let percentages = vec![
+        &env,
+        20_000_000i128,
+        20_000_000i128,
+        20_000_000i128,
+        20_000_000i128,
+    ];
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        intervals.clone(),
+        percentages.clone(),
+    );
+
+    assert_eq!(client.get_stage_count(), 4);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+
+    // Fund payer with enough stablecoin
+    let payer_funding = 10_000_000 * 10i128.pow(6);
+    stablecoin_client.transfer(&owner, &payer, &payer_funding);
+
+    let investor = Address::generate(&env);
+    let nft_amount = 100u32;
+
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        nft_amount,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    client.start_chronometer();
+    let start_time = env.ledger().timestamp();
+
+    let mut expected_claimable = 0i128;
+
+    for stage in 0..4u32 {
+        assert_eq!(
+            client.get_current_state(),
+            InvestmentStatus::Distributing(stage)
+        );
+
+        env.ledger()
+            .set_timestamp(start_time + intervals.get(stage).unwrap());
+
+        let percent = percentages.get(stage).unwrap();
+        let amount_to_release = client.calculate_amount_to_release(&percent);
+        stablecoin_client.approve(&payer, &contract_id, &amount_to_release, &100);
+
+        client.release_distribution(&owner);
+
+        expected_claimable += amount_to_release;
+        assert_eq!(client.see_claimable_amount(&investor), expected_claimable);
     }
-    assert_eq!(client.see_claimed_amount(&investor), expected_claimed);
+
+    // The schedule only has 4 stages so the investment ends after the 4th release
+    assert_eq!(client.get_current_state(), InvestmentStatus::Ended);
+}
+
+#[test]
+fn test_release_distribution_after_schedule_ended() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address = deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(6));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        vec![&env, 60u64],
+        vec![&env, 40_000_000i128],
+    );
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    stablecoin_client.transfer(&owner, &payer, &(10_000_000 * 10i128.pow(6)));
+    stablecoin_client.approve(&payer, &contract_id, &(10_000_000 * 10i128.pow(6)), &100);
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    client.start_chronometer();
+    let start_time = env.ledger().timestamp();
+
+    // Single stage schedule ends right after the first release
+    env.ledger().set_timestamp(start_time + 60);
+    client.release_distribution(&owner);
+    assert_eq!(client.get_current_state(), InvestmentStatus::Ended);
+
+    assert_eq!(
+        client.try_release_distribution(&owner),
+        Err(Ok(MinahError::DistributionAlreadyEnded))
+    );
 }
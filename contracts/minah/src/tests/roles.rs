@@ -0,0 +1,205 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, InvokeError, Symbol,
+};
+use stellar_access::access_control::AccessControlError;
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, DISTRIBUTION_INTERVALS, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT,
+        PRICE, TOTAL_SUPPLY, USDC_DECIMALS,
+    },
+    ConfigChange, ConfigParameter, InvestmentStatus,
+};
+
+#[test]
+fn test_owner_holds_every_role_at_deploy() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    assert_eq!(client.get_admin(), Some(owner.clone()));
+
+    for role in ["compliance", "treasury", "pauser"] {
+        let role = Symbol::new(&env, role);
+        assert!(client.has_role(&owner, &role).is_some());
+        assert_eq!(client.get_role_member_count(&role), 1);
+    }
+}
+
+#[test]
+fn test_compliance_role_onboards_investors() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let compliance = Symbol::new(&env, "compliance");
+    let officer = Address::generate(&env);
+    let investor = Address::generate(&env);
+
+    // CHECK: An account without the role cannot onboard investors
+    assert_eq!(
+        client.try_create_investor(&officer, &investor),
+        Err(Err(InvokeError::Contract(
+            AccessControlError::Unauthorized as u32
+        )))
+    );
+
+    client.grant_role(&owner, &officer, &compliance);
+    client.create_investor(&officer, &investor);
+    assert!(client.is_investor(&investor));
+
+    // CHECK: A revoked account loses the permission
+    client.revoke_role(&owner, &officer, &compliance);
+    assert_eq!(
+        client.try_create_investor(&officer, &Address::generate(&env)),
+        Err(Err(InvokeError::Contract(
+            AccessControlError::Unauthorized as u32
+        )))
+    );
+}
+
+#[test]
+fn test_treasury_role_triggers_releases() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    client.start_chronometer();
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let payer_funding = 1_000_000 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &payer, &payer_funding);
+    stablecoin_client.approve(&payer, &contract_id, &payer_funding, &1000);
+
+    // --- Hand the treasury role over ---
+    let treasury = Symbol::new(&env, "treasury");
+    let treasurer = Address::generate(&env);
+    client.grant_role(&owner, &treasurer, &treasury);
+    client.renounce_role(&owner, &treasury);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+
+    assert_eq!(
+        client.try_release_distribution(&owner),
+        Err(Err(InvokeError::Contract(
+            AccessControlError::Unauthorized as u32
+        )))
+    );
+
+    client.release_distribution(&treasurer);
+    assert_eq!(
+        client.get_current_state(),
+        InvestmentStatus::Distributing(1)
+    );
+}
+
+#[test]
+fn test_only_admin_changes_addresses() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    // --- Transfer the admin role ---
+    let new_admin = Address::generate(&env);
+    client.transfer_admin_role(&new_admin, &1000);
+    client.accept_admin_transfer();
+
+    assert_eq!(client.get_admin(), Some(new_admin.clone()));
+
+    let new_payer = Address::generate(&env);
+    client.propose_change(&ConfigChange::Payer(new_payer.clone()));
+
+    // CHECK: The new admin, not the owner, had to authorize the change
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, new_admin);
+    assert_eq!(
+        client
+            .get_pending_change(&ConfigParameter::Payer)
+            .unwrap()
+            .change,
+        ConfigChange::Payer(new_payer)
+    );
+}
@@ -0,0 +1,283 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY,
+        USDC_DECIMALS,
+    },
+    InvestmentStatus, MinahError,
+};
+
+const SALE_START: u64 = 1_000;
+const SALE_END: u64 = 2_000;
+
+#[test]
+fn test_sale_window_setter_getter() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    // By default the sale is open until the chronometer starts
+    assert_eq!(client.get_sale_start(), 0);
+    assert_eq!(client.get_sale_end(), u64::MAX);
+
+    client.set_sale_window(&SALE_START, &SALE_END);
+
+    assert_eq!(client.get_sale_start(), SALE_START);
+    assert_eq!(client.get_sale_end(), SALE_END);
+}
+
+#[test]
+fn test_mint_before_sale_start() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_sale_window(&SALE_START, &SALE_END);
+
+    env.ledger().set_timestamp(SALE_START - 1);
+
+    let investor = Address::generate(&env);
+    client.create_investor(&owner, &investor);
+    assert_eq!(
+        client.try_mint(&investor, &100),
+        Err(Ok(MinahError::SaleNotOpen))
+    );
+}
+
+#[test]
+fn test_mint_after_sale_end() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_sale_window(&SALE_START, &SALE_END);
+
+    env.ledger().set_timestamp(SALE_END);
+
+    let investor = Address::generate(&env);
+    client.create_investor(&owner, &investor);
+    assert_eq!(
+        client.try_mint(&investor, &100),
+        Err(Ok(MinahError::SaleClosed))
+    );
+}
+
+#[test]
+fn test_start_chronometer_permissionless_after_sale_end() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_sale_window(&SALE_START, &SALE_END);
+
+    env.ledger().set_timestamp(SALE_START);
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    env.ledger().set_timestamp(SALE_END);
+
+    client.start_chronometer();
+
+    // CHECK: No authorization was required to start the chronometer
+    assert!(env.auths().is_empty());
+
+    assert_eq!(
+        client.get_current_state(),
+        InvestmentStatus::Distributing(0)
+    );
+    assert_eq!(client.get_begin_date(), SALE_END);
+    assert_eq!(client.get_nft_buying_phase_supply(), 100);
+}
+
+#[test]
+fn test_start_chronometer_requires_owner_while_sale_open() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_sale_window(&SALE_START, &SALE_END);
+
+    env.ledger().set_timestamp(SALE_START);
+
+    client.start_chronometer();
+
+    // CHECK: The owner had to authorize the early start
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, owner);
+}
+
+#[test]
+fn test_start_chronometer_permissionless_when_sold_out() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let total_supply: u32 = 100;
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        total_supply,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    client.set_sale_window(&SALE_START, &SALE_END);
+
+    env.ledger().set_timestamp(SALE_START);
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        total_supply,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    // The sale is still open but nothing is left to sell
+    client.start_chronometer();
+
+    assert!(env.auths().is_empty());
+    assert!(client.is_chronometer_started());
+}
+
+#[test]
+fn test_set_invalid_sale_window() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    assert_eq!(
+        client.try_set_sale_window(&SALE_END, &SALE_START),
+        Err(Ok(MinahError::InvalidSaleWindow))
+    );
+}
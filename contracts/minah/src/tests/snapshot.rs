@@ -0,0 +1,143 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Vec,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, DISTRIBUTION_INTERVALS, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT,
+        PRICE, ROI_PERCENTAGES, TOTAL_SUPPLY, USDC_DECIMALS,
+    },
+    MinahClient,
+};
+
+/// Starts an offering where investor1 holds tokens 0..100 and investor2 tokens 100..150.
+fn setup_two_investors<'a>(env: &Env) -> (MinahClient<'a>, Address, Address, Address, Address) {
+    let owner = Address::generate(env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(env);
+    let payer = Address::generate(env);
+
+    let (client, contract_id) = create_client(
+        env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(env),
+        roi_percentages_vec(env),
+    );
+
+    let investor1 = Address::generate(env);
+    let investor2 = Address::generate(env);
+
+    mint_nft(
+        env,
+        &client,
+        &investor1,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    mint_nft(
+        env,
+        &client,
+        &investor2,
+        50,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    client.start_chronometer();
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(env, &stablecoin_address);
+    let funding = 1_000_000 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &payer, &funding);
+    stablecoin_client.approve(&payer, &contract_id, &funding, &1000);
+
+    // Investor2 can pay for marketplace purchases
+    stablecoin_client.transfer(&owner, &investor2, &funding);
+    stablecoin_client.approve(&investor2, &contract_id, &funding, &1000);
+    client.approve_for_all(&investor1, &contract_id, &1000);
+
+    (
+        client,
+        contract_id,
+        stablecoin_address,
+        investor1,
+        investor2,
+    )
+}
+
+#[test]
+fn test_purchase_after_due_date_does_not_earn_stage() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, investor1, investor2) = setup_two_investors(&env);
+    let owner = client.get_owner().unwrap();
+
+    // Stage 0 is due, but not released yet
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+
+    // Investor2 buys 50 NFTs right before the release
+    let mut token_ids: Vec<u32> = Vec::new(&env);
+    for i in 0..50 {
+        token_ids.push_back(i);
+    }
+    client.buy_tokens(&investor1, &investor2, &token_ids);
+
+    assert_eq!(client.balance(&investor2), 100);
+    assert_eq!(client.balance_at_stage(&investor1, &0), 100);
+    assert_eq!(client.balance_at_stage(&investor2, &0), 50);
+
+    // CHECK: The purchase counts for the next stage
+    assert_eq!(client.balance_at_stage(&investor1, &1), 50);
+    assert_eq!(client.balance_at_stage(&investor2, &1), 100);
+
+    client.release_distribution(&owner);
+
+    let amount_per_nft = client.get_stage_amount_per_nft(&0);
+    assert_eq!(
+        client.see_claimable_amount(&investor1),
+        100 * amount_per_nft
+    );
+    assert_eq!(client.see_claimable_amount(&investor2), 50 * amount_per_nft);
+}
+
+#[test]
+fn test_purchase_before_due_date_earns_stage() {
+    let env = Env::default();
+    let (client, contract_id, stablecoin_address, investor1, investor2) = setup_two_investors(&env);
+    let owner = client.get_owner().unwrap();
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0] - 1);
+
+    let mut token_ids: Vec<u32> = Vec::new(&env);
+    for i in 0..50 {
+        token_ids.push_back(i);
+    }
+    client.buy_tokens(&investor1, &investor2, &token_ids);
+
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[1]);
+
+    client.release_distribution(&owner);
+
+    assert_eq!(client.balance_at_stage(&investor1, &0), 50);
+    assert_eq!(client.balance_at_stage(&investor2, &0), 100);
+
+    let price = client.get_nft_price();
+    let per_nft = (ROI_PERCENTAGES[0] * price) / 100 + (ROI_PERCENTAGES[1] * price) / 100;
+    assert_eq!(client.see_claimable_amount(&investor1), 50 * per_nft);
+    assert_eq!(client.see_claimable_amount(&investor2), 100 * per_nft);
+}
@@ -0,0 +1,138 @@
+use soroban_sdk::{
+    testutils::{
+        storage::{Instance as _, Persistent as _},
+        Address as _, Ledger,
+    },
+    Address, Env, Symbol,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, setup_trading_offering, TradingOffering, MAX_NFTS_PER_INVESTOR,
+        MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY, USDC_DECIMALS,
+    },
+    ConfigChange, ConfigParameter, DataKey, InvestorTier, PausableFunction, DAY_IN_LEDGERS,
+    INSTANCE_EXTEND_AMOUNT, PERSISTENT_EXTEND_AMOUNT,
+};
+
+#[test]
+fn test_investor_records_in_persistent_storage() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    assert_eq!(client.get_investors_array_length(), 1);
+
+    env.as_contract(&contract_id, || {
+        let keys = [
+            DataKey::Investor(investor.clone()),
+            DataKey::InvestorAt(0),
+            DataKey::InvestorIndex(investor.clone()),
+            DataKey::PaidAmount(investor.clone()),
+            DataKey::BalanceCheckpoints(investor.clone()),
+        ];
+
+        for key in keys.iter() {
+            // CHECK: The record is persistent and its TTL was extended
+            assert!(!env.storage().instance().has(key));
+            assert!(env.storage().persistent().has(key));
+            assert_eq!(
+                env.storage().persistent().get_ttl(key),
+                PERSISTENT_EXTEND_AMOUNT
+            );
+        }
+    });
+}
+
+#[test]
+fn test_instance_ttl_extended_by_entry_points() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        contract_id,
+        ..
+    } = setup_trading_offering(&env, false);
+
+    let instance_ttl = || env.as_contract(&contract_id, || env.storage().instance().get_ttl());
+    assert_eq!(instance_ttl(), INSTANCE_EXTEND_AMOUNT);
+
+    // --- Two weeks later the instance has burnt part of its TTL ---
+    env.ledger()
+        .with_mut(|li| li.sequence_number += 14 * DAY_IN_LEDGERS);
+    assert_eq!(instance_ttl(), INSTANCE_EXTEND_AMOUNT - 14 * DAY_IN_LEDGERS);
+
+    // CHECK: Any state-changing call brings it back to ~30 days
+    client.start_chronometer();
+    assert_eq!(instance_ttl(), INSTANCE_EXTEND_AMOUNT);
+}
+
+#[test]
+fn test_instance_kept_alive_by_admin_entry_points() {
+    let env = Env::default();
+    // Role records are written with the minimum persistent TTL, 120 days on mainnet
+    env.ledger()
+        .with_mut(|li| li.min_persistent_entry_ttl = 120 * DAY_IN_LEDGERS);
+    let TradingOffering {
+        client,
+        contract_id,
+        owner,
+        ..
+    } = setup_trading_offering(&env, false);
+
+    let instance_ttl = || env.as_contract(&contract_id, || env.storage().instance().get_ttl());
+    let three_weeks_later = || {
+        env.ledger()
+            .with_mut(|li| li.sequence_number += 21 * DAY_IN_LEDGERS)
+    };
+
+    // --- Only configuration, pause and role calls for five months, past the TTL of the instance ---
+    three_weeks_later();
+    let pauser = Address::generate(&env);
+    client.grant_role(&owner, &pauser, &Symbol::new(&env, "pauser"));
+    client.pause(&pauser);
+    client.unpause(&pauser);
+    client.revoke_role(&owner, &pauser, &Symbol::new(&env, "pauser"));
+    client.set_continuous_offering(&None);
+
+    for step in 1..=6 {
+        three_weeks_later();
+        client.propose_change(&ConfigChange::TradingFee(50));
+        client.cancel_change(&ConfigParameter::TradingFee);
+        client.set_tier_cap(&InvestorTier::Retail, &(100 * step));
+        client.pause_function(&owner, &PausableFunction::Mint);
+        client.unpause_function(&owner, &PausableFunction::Mint);
+    }
+
+    // CHECK: The instance is still live with a full TTL
+    assert_eq!(instance_ttl(), INSTANCE_EXTEND_AMOUNT);
+    assert_eq!(client.get_tier_cap(&InvestorTier::Retail), 600);
+}
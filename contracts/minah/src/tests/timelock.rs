@@ -0,0 +1,290 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, Vec,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY,
+        USDC_DECIMALS,
+    },
+    ConfigChange, ConfigError, ConfigParameter, PendingChange,
+};
+
+#[test]
+fn test_change_applies_only_after_delay() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    env.ledger().set_timestamp(1_000);
+    let delay = client.get_change_delay();
+    let new_receiver = Address::generate(&env);
+
+    let executable_at = client.propose_change(&ConfigChange::Receiver(new_receiver.clone()));
+
+    assert_eq!(executable_at, 1_000 + delay);
+    assert_eq!(
+        client.get_pending_change(&ConfigParameter::Receiver),
+        Some(PendingChange {
+            change: ConfigChange::Receiver(new_receiver.clone()),
+            executable_at,
+        })
+    );
+
+    // CHECK: The receiver is unchanged while the change is pending
+    env.ledger().set_timestamp(executable_at - 1);
+    assert_eq!(
+        client.try_execute_change(&ConfigParameter::Receiver),
+        Err(Ok(ConfigError::ChangeNotReady))
+    );
+    assert_eq!(client.get_receiver(), receiver);
+
+    env.ledger().set_timestamp(executable_at);
+    client.execute_change(&ConfigParameter::Receiver);
+
+    assert_eq!(client.get_receiver(), new_receiver);
+    assert_eq!(client.get_pending_change(&ConfigParameter::Receiver), None);
+    assert_eq!(
+        client.try_execute_change(&ConfigParameter::Receiver),
+        Err(Ok(ConfigError::NoPendingChange))
+    );
+}
+
+#[test]
+fn test_cancel_change() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let executable_at = client.propose_change(&ConfigChange::StableCoin(Address::generate(&env)));
+    client.cancel_change(&ConfigParameter::StableCoin);
+
+    env.ledger().set_timestamp(executable_at);
+    assert_eq!(
+        client.try_execute_change(&ConfigParameter::StableCoin),
+        Err(Ok(ConfigError::NoPendingChange))
+    );
+    assert_eq!(
+        client.try_cancel_change(&ConfigParameter::StableCoin),
+        Err(Ok(ConfigError::NoPendingChange))
+    );
+    assert_eq!(client.get_stablecoin(), stablecoin_address);
+}
+
+#[test]
+fn test_change_delay_is_timelocked() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let delay = client.get_change_delay();
+
+    // --- Dropping the delay has to wait for the current one ---
+    let executable_at = client.propose_change(&ConfigChange::ChangeDelay(0));
+    assert_eq!(
+        client.try_execute_change(&ConfigParameter::ChangeDelay),
+        Err(Ok(ConfigError::ChangeNotReady))
+    );
+    assert_eq!(client.get_change_delay(), delay);
+
+    env.ledger().set_timestamp(executable_at);
+    client.execute_change(&ConfigParameter::ChangeDelay);
+    assert_eq!(client.get_change_delay(), 0);
+
+    // CHECK: Changes proposed afterwards use the new delay
+    let new_payer = Address::generate(&env);
+    client.propose_change(&ConfigChange::Payer(new_payer.clone()));
+    client.execute_change(&ConfigParameter::Payer);
+    assert_eq!(client.get_payer(), new_payer);
+}
+
+#[test]
+fn test_price_and_schedule_change_before_sale() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    assert_eq!(client.get_locked_parameters(), Vec::new(&env));
+
+    // CHECK: An invalid schedule is rejected when proposed
+    assert_eq!(
+        client.try_propose_change(&ConfigChange::ReleaseSchedule(
+            vec![&env, 60u64, 30u64],
+            vec![&env, 1i128, 2i128]
+        )),
+        Err(Ok(ConfigError::InvalidReleaseSchedule))
+    );
+
+    let intervals = vec![&env, 60u64, 120u64];
+    let roi_percentages = vec![&env, 10_000_000i128, 20_000_000i128];
+    client.propose_change(&ConfigChange::Price(PRICE * 2));
+    let executable_at = client.propose_change(&ConfigChange::ReleaseSchedule(
+        intervals.clone(),
+        roi_percentages.clone(),
+    ));
+
+    env.ledger().set_timestamp(executable_at);
+    client.execute_change(&ConfigParameter::Price);
+    client.execute_change(&ConfigParameter::ReleaseSchedule);
+
+    assert_eq!(client.get_nft_price(), PRICE * 2);
+    assert_eq!(client.get_distribution_intervals(), intervals);
+    assert_eq!(client.get_roi_percentages(), roi_percentages);
+    assert_eq!(client.get_stage_count(), 2);
+}
+
+#[test]
+fn test_parameters_frozen_once_sale_starts() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    // A stablecoin change proposed during the buying phase
+    let executable_at = client.propose_change(&ConfigChange::StableCoin(Address::generate(&env)));
+
+    // --- First mint: the stablecoin, price and schedule are frozen ---
+    let investor = Address::generate(&env);
+    mint_nft(
+        &env,
+        &client,
+        &investor,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    assert_eq!(
+        client.get_locked_parameters(),
+        vec![
+            &env,
+            ConfigParameter::StableCoin,
+            ConfigParameter::Price,
+            ConfigParameter::ReleaseSchedule
+        ]
+    );
+    assert_eq!(
+        client.try_propose_change(&ConfigChange::Price(PRICE * 2)),
+        Err(Ok(ConfigError::ConfigLockedAfterFirstMint))
+    );
+
+    // CHECK: The change proposed before the first mint can no longer be executed
+    env.ledger().set_timestamp(executable_at);
+    assert_eq!(
+        client.try_execute_change(&ConfigParameter::StableCoin),
+        Err(Ok(ConfigError::ConfigLockedAfterFirstMint))
+    );
+    assert_eq!(client.get_stablecoin(), stablecoin_address);
+
+    // --- Chronometer start: the lock-up is frozen too ---
+    client.start_chronometer();
+
+    assert_eq!(
+        client.get_locked_parameters(),
+        vec![
+            &env,
+            ConfigParameter::StableCoin,
+            ConfigParameter::Price,
+            ConfigParameter::ReleaseSchedule,
+            ConfigParameter::LockUpPeriod
+        ]
+    );
+    assert_eq!(
+        client.try_propose_change(&ConfigChange::StableCoin(Address::generate(&env))),
+        Err(Ok(ConfigError::InvestmentNotInBuyingPhase))
+    );
+
+    // CHECK: The receiver can still be changed
+    let new_receiver = Address::generate(&env);
+    let executable_at = client.propose_change(&ConfigChange::Receiver(new_receiver.clone()));
+    env.ledger().set_timestamp(executable_at);
+    client.execute_change(&ConfigParameter::Receiver);
+    assert_eq!(client.get_receiver(), new_receiver);
+}
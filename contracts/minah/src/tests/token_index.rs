@@ -0,0 +1,171 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT, PRICE, TOTAL_SUPPLY,
+        USDC_DECIMALS,
+    },
+    MarketplaceError, MinahClient, TokenRange,
+};
+
+/// Mints tokens 0..100 to investor1 and 100..150 to investor2 and funds both to buy.
+fn setup_indexed_offering<'a>(env: &Env) -> (MinahClient<'a>, Address, Address, Address) {
+    let owner = Address::generate(env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(env);
+    let payer = Address::generate(env);
+
+    let (client, contract_id) = create_client(
+        env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(env),
+        roi_percentages_vec(env),
+    );
+
+    let investor1 = Address::generate(env);
+    let investor2 = Address::generate(env);
+
+    mint_nft(
+        env,
+        &client,
+        &investor1,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    mint_nft(
+        env,
+        &client,
+        &investor2,
+        50,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(env, &stablecoin_address);
+    let funding = 1_000_000 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &investor2, &funding);
+    stablecoin_client.approve(&investor2, &contract_id, &funding, &1000);
+    stablecoin_client.transfer(&owner, &investor1, &funding);
+    stablecoin_client.approve(&investor1, &contract_id, &funding, &1000);
+
+    (client, owner, investor1, investor2)
+}
+
+fn ranges(env: &Env, bounds: &[(u32, u32)]) -> Vec<TokenRange> {
+    let mut ranges: Vec<TokenRange> = Vec::new(env);
+    for (start, end) in bounds {
+        ranges.push_back(TokenRange {
+            start: *start,
+            end: *end,
+        });
+    }
+    ranges
+}
+
+#[test]
+fn test_token_index_follows_holders() {
+    let env = Env::default();
+    let (client, owner, investor1, investor2) = setup_indexed_offering(&env);
+
+    assert_eq!(
+        client.get_token_ranges(&investor1),
+        ranges(&env, &[(0, 100)])
+    );
+    assert_eq!(
+        client.get_token_ranges(&investor2),
+        ranges(&env, &[(100, 150)])
+    );
+
+    client.start_chronometer();
+
+    // CHECK: The owner inventory is indexed when it is minted
+    assert_eq!(
+        client.get_token_ranges(&owner),
+        ranges(&env, &[(150, TOTAL_SUPPLY)])
+    );
+
+    // --- Investor1 sells tokens out of the middle of its range ---
+    client.approve_for_all(&investor1, &client.address, &1000);
+    client.buy_tokens(&investor1, &investor2, &vec![&env, 10, 11, 99]);
+
+    assert_eq!(
+        client.get_token_ranges(&investor1),
+        ranges(&env, &[(0, 10), (12, 99)])
+    );
+    assert_eq!(
+        client.get_token_ranges(&investor2),
+        ranges(&env, &[(10, 12), (99, 150)])
+    );
+
+    // --- Investor2 sells them back, the ranges merge again ---
+    client.approve_for_all(&investor2, &client.address, &1000);
+    client.buy_tokens(&investor2, &investor1, &vec![&env, 10, 11, 99]);
+
+    assert_eq!(
+        client.get_token_ranges(&investor1),
+        ranges(&env, &[(0, 100)])
+    );
+    assert_eq!(
+        client.get_token_ranges(&investor2),
+        ranges(&env, &[(100, 150)])
+    );
+}
+
+#[test]
+fn test_buy_quantity() {
+    let env = Env::default();
+    let (client, owner, investor1, investor2) = setup_indexed_offering(&env);
+
+    client.start_chronometer();
+    client.approve_for_all(&investor1, &client.address, &1000);
+
+    let unit_price = PRICE * 10i128.pow(USDC_DECIMALS);
+
+    assert_eq!(
+        client.try_buy_quantity(&investor1, &investor2, &0, &unit_price),
+        Err(Ok(MarketplaceError::InvalidQuantity))
+    );
+
+    // CHECK: The buyer is protected against a higher price
+    assert_eq!(
+        client.try_buy_quantity(&investor1, &investor2, &5, &(unit_price - 1)),
+        Err(Ok(MarketplaceError::PriceAboveLimit))
+    );
+
+    // --- Investor1 lists its first tokens, they are skipped ---
+    client.list(
+        &investor1,
+        &vec![&env, 0, 1],
+        &unit_price,
+        &(env.ledger().timestamp() + 1_000),
+    );
+
+    let bought = client.buy_quantity(&investor1, &investor2, &5, &unit_price);
+
+    assert_eq!(bought, vec![&env, 2, 3, 4, 5, 6]);
+    assert_eq!(client.balance(&investor1), 95);
+    assert_eq!(client.balance(&investor2), 55);
+    assert_eq!(client.owner_of(&2), investor2);
+    assert_eq!(client.owner_of(&0), investor1);
+
+    // CHECK: The seller must hold enough unlisted tokens
+    assert_eq!(
+        client
+            .try_buy_quantity(&investor1, &investor2, &94, &unit_price)
+            .err(),
+        Some(Ok(MarketplaceError::InsufficientFromNftBalance))
+    );
+}
@@ -0,0 +1,123 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+use crate::{
+    tests::utils::{setup_trading_offering, token_range, TradingOffering, PRICE, USDC_DECIMALS},
+    ConfigChange, ConfigError, ConfigParameter, MinahClient, MinahError,
+};
+
+fn apply_change(env: &Env, client: &MinahClient, change: ConfigChange) {
+    let parameter = change.parameter();
+    let executable_at = client.propose_change(&change);
+    env.ledger().set_timestamp(executable_at);
+    client.execute_change(&parameter);
+}
+
+#[test]
+fn test_trading_fee_paid_by_buyer() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        receiver,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &client.get_stablecoin());
+
+    // 2.5% on secondary trades
+    apply_change(&env, &client, ConfigChange::TradingFee(250));
+    assert_eq!(client.get_trading_fee_bps(), 250);
+    assert_eq!(client.get_fee_recipient(), receiver);
+
+    let total_price = 10 * PRICE * 10i128.pow(USDC_DECIMALS);
+    let fee = total_price * 250 / 10_000;
+
+    // CHECK: The allowance must cover the fee as well
+    stablecoin_client.approve(&investor2, &client.address, &total_price, &1000);
+    assert_eq!(
+        client.try_buy_tokens(&investor1, &investor2, &token_range(&env, 0, 10)),
+        Err(Ok(MinahError::InsufficientAllowance))
+    );
+
+    stablecoin_client.approve(
+        &investor2,
+        &client.address,
+        &(2 * (total_price + fee)),
+        &1000,
+    );
+
+    let seller_balance = stablecoin_client.balance(&investor1);
+    let buyer_balance = stablecoin_client.balance(&investor2);
+    let receiver_balance = stablecoin_client.balance(&receiver);
+
+    client.buy_tokens(&investor1, &investor2, &token_range(&env, 0, 10));
+    client.sell_tokens(&investor1, &investor2, &token_range(&env, 10, 20));
+
+    // CHECK: The seller gets the full price, the buyer pays the fee to the receiver
+    assert_eq!(
+        stablecoin_client.balance(&investor1),
+        seller_balance + 2 * total_price
+    );
+    assert_eq!(
+        stablecoin_client.balance(&investor2),
+        buyer_balance - 2 * (total_price + fee)
+    );
+    assert_eq!(
+        stablecoin_client.balance(&receiver),
+        receiver_balance + 2 * fee
+    );
+}
+
+#[test]
+fn test_trading_fee_cap_and_recipient() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        receiver,
+        investor1,
+        investor2,
+        ..
+    } = setup_trading_offering(&env, true);
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &client.get_stablecoin());
+
+    // CHECK: The fee is capped at 10%
+    assert_eq!(
+        client.try_propose_change(&ConfigChange::TradingFee(1_001)),
+        Err(Ok(ConfigError::TradingFeeTooHigh))
+    );
+
+    let treasury = Address::generate(&env);
+    apply_change(&env, &client, ConfigChange::TradingFee(1_000));
+    apply_change(
+        &env,
+        &client,
+        ConfigChange::FeeRecipient(Some(treasury.clone())),
+    );
+    assert_eq!(client.get_fee_recipient(), treasury);
+
+    // --- Listings pay the fee too ---
+    let unit_price = 10i128.pow(USDC_DECIMALS);
+    let listing_id = client.list(
+        &investor1,
+        &token_range(&env, 0, 10),
+        &unit_price,
+        &(env.ledger().timestamp() + 1_000),
+    );
+    stablecoin_client.approve(&investor2, &client.address, &(11 * unit_price), &1000);
+    client.fill_listing(&investor2, &listing_id, &10);
+
+    assert_eq!(stablecoin_client.balance(&treasury), unit_price);
+
+    // CHECK: Clearing the recipient sends the fees to the receiver again
+    apply_change(&env, &client, ConfigChange::FeeRecipient(None));
+    assert_eq!(client.get_fee_recipient(), receiver);
+    assert_eq!(
+        client.get_pending_change(&ConfigParameter::FeeRecipient),
+        None
+    );
+}
@@ -0,0 +1,167 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN, Env,
+};
+
+use crate::{
+    tests::utils::{
+        create_client, deploy_stablecoin_contract, distribution_intervals_vec, mint_nft,
+        roi_percentages_vec, setup_trading_offering, TradingOffering, DISTRIBUTION_INTERVALS,
+        MAX_NFTS_PER_INVESTOR, MIN_NFTS_TO_MINT, PRICE, ROI_PERCENTAGES, TOTAL_SUPPLY,
+        USDC_DECIMALS,
+    },
+    DataKey, InvestmentStatus, MinahError, STORAGE_VERSION,
+};
+
+/// Hash the test environment uses for natively registered contracts.
+/// Upgrading to it swaps the contract code while still running the code under test.
+fn native_wasm_hash(env: &Env) -> BytesN<32> {
+    env.crypto().sha256(&Bytes::new(env)).into()
+}
+
+#[test]
+fn test_upgrade_mid_distribution_keeps_state() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    let investor1 = Address::generate(&env);
+    let investor2 = Address::generate(&env);
+
+    mint_nft(
+        &env,
+        &client,
+        &investor1,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    mint_nft(
+        &env,
+        &client,
+        &investor2,
+        50,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    client.start_chronometer();
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let payer_funding = 1_000_000 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &payer, &payer_funding);
+    stablecoin_client.approve(&payer, &contract_id, &payer_funding, &1000);
+
+    // --- Release the first stage, only investor1 claims ---
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[0]);
+    client.release_distribution(&owner);
+    let claimed = client.claim(&investor1);
+
+    // --- Upgrade ---
+    client.upgrade(&native_wasm_hash(&env));
+
+    // CHECK: Nothing is lost and nothing has to be migrated
+    assert_eq!(client.migrate(), STORAGE_VERSION);
+    assert_eq!(client.get_storage_version(), STORAGE_VERSION);
+    assert_eq!(
+        client.get_current_state(),
+        InvestmentStatus::Distributing(1)
+    );
+    assert_eq!(client.see_claimed_amount(&investor1), claimed);
+    assert_eq!(client.see_claimable_amount(&investor2), claimed / 2);
+    assert_eq!(client.get_investors_array_length(), 2);
+
+    // --- The distribution goes on with the new code ---
+    env.ledger()
+        .set_timestamp(client.get_begin_date() + DISTRIBUTION_INTERVALS[1]);
+    client.release_distribution(&owner);
+
+    let price = client.get_nft_price();
+    let per_nft_1 = (ROI_PERCENTAGES[1] * price) / 100;
+    assert_eq!(client.claim(&investor1), 100 * per_nft_1);
+    assert_eq!(
+        client.see_claimed_amount(&investor1),
+        claimed + 100 * per_nft_1
+    );
+    assert_eq!(
+        client.get_current_state(),
+        InvestmentStatus::Distributing(2)
+    );
+}
+
+#[test]
+fn test_migrate_rejects_baseline_storage() {
+    let env = Env::default();
+    let TradingOffering {
+        client,
+        contract_id,
+        ..
+    } = setup_trading_offering(&env, false);
+
+    // A deployment without a recorded version was made by the baseline contract
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&DataKey::StorageVersion);
+    });
+
+    assert_eq!(client.get_storage_version(), 1);
+    assert_eq!(
+        client.try_migrate(),
+        Err(Ok(MinahError::UnsupportedStorageVersion))
+    );
+}
+
+#[test]
+fn test_migrate_from_newer_storage_version() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(&env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let (client, contract_id) = create_client(
+        &env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(&env),
+        roi_percentages_vec(&env),
+    );
+
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::StorageVersion, &(STORAGE_VERSION + 1));
+    });
+
+    assert_eq!(
+        client.try_migrate(),
+        Err(Ok(MinahError::UnsupportedStorageVersion))
+    );
+}
@@ -1,8 +1,9 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{testutils::Address as _, Address, Env, Vec};
 use stablecoin::Stablecoin;
 
 use crate::{Minah, MinahClient};
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_client<'a>(
     env: &Env,
     owner: &Address,
@@ -40,9 +41,7 @@ pub fn create_client<'a>(
 }
 
 pub fn deploy_stablecoin_contract(env: &Env, user: &Address, premint_amount: i128) -> Address {
-    let contract_id = env.register(Stablecoin, (user, premint_amount));
-
-    contract_id
+    env.register(Stablecoin, (user, premint_amount))
 }
 
 pub fn mint_nft(
@@ -55,10 +54,10 @@ pub fn mint_nft(
     contract_id: &Address,
 ) {
     // Create An investor before minting
-    client.create_investor(&nft_receiver);
+    client.create_investor(owner, nft_receiver);
 
     // CHECK: Is the investor created successfully?
-    let is_investor = client.is_investor(&nft_receiver);
+    let is_investor = client.is_investor(nft_receiver);
     assert!(is_investor);
 
     // Transfer The Required Stablecoin Amount to the nft receiver so that the minting can proceed
@@ -66,32 +65,120 @@ pub fn mint_nft(
 
     let total_amount = nft_price * (nft_amount as i128) * 10i128.pow(USDC_DECIMALS);
 
-    let stablecoin_client = stablecoin::StablecoinClient::new(&env, &stablecoin_address);
+    let stablecoin_client = stablecoin::StablecoinClient::new(env, stablecoin_address);
 
     // Transfer stablecoin to the nft_receiver
-    stablecoin_client.transfer(&owner, &nft_receiver, &total_amount);
+    stablecoin_client.transfer(owner, nft_receiver, &total_amount);
 
     // CHECK: balance of nft_receiver should be equal to total_amount
-    let nft_receiver_balance = stablecoin_client.balance(&nft_receiver);
+    let nft_receiver_balance = stablecoin_client.balance(nft_receiver);
     assert_eq!(nft_receiver_balance, total_amount);
 
     // Increase Allowance for the payer to allow the contract to spend stablecoin on behalf of nft_receiver
-    stablecoin_client.approve(&nft_receiver, &contract_id, &total_amount, &100);
+    stablecoin_client.approve(nft_receiver, contract_id, &total_amount, &100);
 
-    let allowance = stablecoin_client.allowance(&nft_receiver, &contract_id);
+    let allowance = stablecoin_client.allowance(nft_receiver, contract_id);
 
     // CHECK: allowance should be equal to total_amount
     assert_eq!(allowance, total_amount);
 
     // Mint the NFTs
-    client.mint(&nft_receiver, &nft_amount);
+    client.mint(nft_receiver, &nft_amount);
 
     // CHECK: NFT balance of nft_receiver should be
-    let nft_balance = client.balance(&nft_receiver);
+    let nft_balance = client.balance(nft_receiver);
 
     assert_eq!(nft_balance, nft_amount);
 }
 
+/// Accounts of an offering set up by `setup_trading_offering`
+pub struct TradingOffering<'a> {
+    pub client: MinahClient<'a>,
+    pub contract_id: Address,
+    pub owner: Address,
+    pub receiver: Address,
+    pub investor1: Address,
+    pub investor2: Address,
+}
+
+/// Sets up an offering where investor1 holds tokens 0..100 and investor2 tokens 100..150.
+/// The payer and investor2 are funded and approved the contract on their stablecoins, investor1 on its NFTs.
+/// The chronometer is started, handing tokens 150.. to the owner, when `start_chronometer` is set.
+pub fn setup_trading_offering<'a>(env: &Env, start_chronometer: bool) -> TradingOffering<'a> {
+    let owner = Address::generate(env);
+    let stablecoin_address =
+        deploy_stablecoin_contract(env, &owner, 100_000_000 * 10i128.pow(USDC_DECIMALS));
+    let receiver = Address::generate(env);
+    let payer = Address::generate(env);
+
+    let (client, contract_id) = create_client(
+        env,
+        &owner,
+        &stablecoin_address,
+        &receiver,
+        &payer,
+        PRICE,
+        TOTAL_SUPPLY,
+        MIN_NFTS_TO_MINT,
+        MAX_NFTS_PER_INVESTOR,
+        distribution_intervals_vec(env),
+        roi_percentages_vec(env),
+    );
+
+    let investor1 = Address::generate(env);
+    let investor2 = Address::generate(env);
+
+    mint_nft(
+        env,
+        &client,
+        &investor1,
+        100,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    mint_nft(
+        env,
+        &client,
+        &investor2,
+        50,
+        &owner,
+        &stablecoin_address,
+        &contract_id,
+    );
+
+    if start_chronometer {
+        client.start_chronometer();
+    }
+
+    let stablecoin_client = stablecoin::StablecoinClient::new(env, &stablecoin_address);
+    let funding = 1_000_000 * 10i128.pow(USDC_DECIMALS);
+    stablecoin_client.transfer(&owner, &payer, &funding);
+    stablecoin_client.approve(&payer, &contract_id, &funding, &1000);
+    stablecoin_client.transfer(&owner, &investor2, &funding);
+    stablecoin_client.approve(&investor2, &contract_id, &funding, &1000);
+    client.approve_for_all(&investor1, &contract_id, &1000);
+
+    TradingOffering {
+        client,
+        contract_id,
+        owner,
+        receiver,
+        investor1,
+        investor2,
+    }
+}
+
+/// Token ids `start..end`
+pub fn token_range(env: &Env, start: u32, end: u32) -> Vec<u32> {
+    let mut token_ids: Vec<u32> = Vec::new(env);
+    for i in start..end {
+        token_ids.push_back(i);
+    }
+    token_ids
+}
+
 pub const USDC_DECIMALS: u32 = 7;
 pub const TOTAL_SUPPLY: u32 = 4500;
 pub const PRICE: i128 = 1; // in stablecoin units
@@ -3,9 +3,7 @@ use soroban_sdk::{testutils::Address as _, Address, Env, String};
 use crate::{Stablecoin, StablecoinClient};
 
 pub fn deploy_stablecoin_contract(env: &Env, user: &Address, premint_amount: i128) -> Address {
-    let contract_id = env.register(Stablecoin, (user, premint_amount));
-
-    contract_id
+    env.register(Stablecoin, (user, premint_amount))
 }
 
 #[test]